@@ -0,0 +1,178 @@
+//! A stable file format for saving cards long-term, such as a collection export. Archives record the schema version
+//! they were written with, and reading one migrates it to the current schema first, so files written by an older
+//! version of this crate still load after the card model changes.
+//!
+//! An archive is a JSON object holding the schema version and the cards, which are written with Scryfall's field
+//! names and an explicit `null` for every absent field.
+
+use crate::types::card::Card;
+use crate::types::parse::{self, ParseError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+/// The schema version that [`write()`] records. It's bumped, with a migration from the previous version, whenever a
+/// change to [`Card`] would stop older archives from parsing or change what their fields mean.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// An archive as it's written.
+#[derive(Serialize)]
+struct ArchiveRef<'a> {
+    schema: u32,
+    cards: &'a [Card],
+}
+
+/// An archive as it's read, once it's been migrated to the current schema.
+#[derive(Deserialize)]
+struct Archive {
+    cards: Vec<Card>,
+}
+
+/// An error reading an archive.
+#[derive(Debug)]
+pub enum ArchiveError {
+    /// The archive couldn't be read, or isn't valid JSON, or one of its cards doesn't parse.
+    Parse(ParseError),
+
+    /// The document is JSON but not an archive.
+    NotAnArchive,
+
+    /// The archive was written by a newer version of this crate, with the given schema version.
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArchiveError::Parse(err) => write!(f, "invalid archive: {}", err),
+            ArchiveError::NotAnArchive => f.write_str("not a card archive"),
+            ArchiveError::UnsupportedVersion(version) => write!(
+                f,
+                "archive schema version {} is newer than the supported version {}",
+                version, SCHEMA_VERSION
+            ),
+        }
+    }
+}
+
+impl error::Error for ArchiveError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ArchiveError::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for ArchiveError {
+    fn from(err: ParseError) -> Self {
+        ArchiveError::Parse(err)
+    }
+}
+
+/// Writes `cards` to `writer` as an archive at the current [`SCHEMA_VERSION`].
+pub fn write<W: Write>(writer: W, cards: &[Card]) -> io::Result<()> {
+    let archive = ArchiveRef {
+        schema: SCHEMA_VERSION,
+        cards,
+    };
+    serde_json::to_writer_pretty(writer, &archive)?;
+    Ok(())
+}
+
+/// Reads the cards from an archive written by any version of this crate up to the current one. A plain JSON array of
+/// Scryfall card objects, as saved from the API before archives existed, is read as schema version 0.
+pub fn read<R: Read>(reader: R) -> Result<Vec<Card>, ArchiveError> {
+    let document: Value = parse::from_reader(reader)?;
+    let (version, mut cards) = match document {
+        Value::Array(cards) => (0, cards),
+        Value::Object(mut archive) => {
+            let version = archive
+                .get("schema")
+                .and_then(Value::as_u64)
+                .ok_or(ArchiveError::NotAnArchive)?;
+            let version = u32::try_from(version).map_err(|_| ArchiveError::NotAnArchive)?;
+            match archive.remove("cards") {
+                Some(Value::Array(cards)) => (version, cards),
+                _ => return Err(ArchiveError::NotAnArchive),
+            }
+        }
+        _ => return Err(ArchiveError::NotAnArchive),
+    };
+    if version > SCHEMA_VERSION {
+        return Err(ArchiveError::UnsupportedVersion(version));
+    }
+
+    for from in version..SCHEMA_VERSION {
+        for card in &mut cards {
+            migrate(from, card);
+        }
+    }
+    let archive: Archive = parse::from_value(&serde_json::json!({ "cards": cards }))?;
+    Ok(archive.cards)
+}
+
+/// Upgrades `card`, written at schema version `from`, to version `from + 1`.
+fn migrate(from: u32, _card: &mut Value) {
+    match from {
+        // Version 1 writes cards with Scryfall's own field names, so a card saved straight from the API needs no
+        // changes.
+        0 => {}
+        _ => unreachable!("no migration from schema version {}", from),
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_round_trip() {
+        use super::{read, write};
+        use crate::testing;
+
+        let cards = vec![testing::card(), testing::double_faced_card()];
+        let mut bytes = Vec::new();
+        write(&mut bytes, &cards).unwrap();
+        assert_eq!(cards, read(bytes.as_slice()).unwrap());
+
+        let archive: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(1, archive["schema"]);
+        assert_eq!(
+            Some(&serde_json::Value::Null),
+            archive["cards"][0].get("arena_id")
+        );
+    }
+
+    #[test]
+    fn test_read_version_zero() {
+        use super::read;
+        use crate::testing;
+
+        let json = serde_json::json!([testing::card_json(serde_json::json!({}))]).to_string();
+        assert_eq!(vec![testing::card()], read(json.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn test_read_errors() {
+        use super::{read, ArchiveError};
+        use crate::testing;
+
+        let json = serde_json::json!({"schema": 99, "cards": []}).to_string();
+        assert!(matches!(
+            read(json.as_bytes()),
+            Err(ArchiveError::UnsupportedVersion(99))
+        ));
+        assert!(matches!(
+            read(&b"{\"cards\": []}"[..]),
+            Err(ArchiveError::NotAnArchive)
+        ));
+
+        let card = testing::card_json(serde_json::json!({"name": 7}));
+        let json = serde_json::json!({"schema": 1, "cards": [card]}).to_string();
+        match read(json.as_bytes()) {
+            Err(ArchiveError::Parse(err)) => assert_eq!("cards[0].name", err.path()),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+}
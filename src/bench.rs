@@ -0,0 +1,155 @@
+//! Throughput measurements for bulk parsing and index builds, enabled by the `bench` feature, so users can see how
+//! the crate performs on their own hardware and data before picking a loading strategy.
+//!
+//! ```no_run
+//! use scryfall::bench;
+//! use scryfall::bulk::OracleCards;
+//! use std::fs::File;
+//! use std::io::BufReader;
+//!
+//! let file = BufReader::new(File::open("oracle-cards.json").unwrap());
+//! println!("{}", bench::bulk_parse::<OracleCards, _>(file).unwrap());
+//! ```
+
+use crate::bulk::{self, BulkError, Dataset};
+use crate::names::{Autocomplete, FuzzyIndex};
+use crate::types::card::Card;
+use std::fmt;
+use std::io::{self, Read};
+use std::time::{Duration, Instant};
+
+/// How long a piece of work took, and how much it got through.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Measurement {
+    /// How many items were processed.
+    pub items: usize,
+
+    /// How many bytes were read, for measurements that read input.
+    pub bytes: Option<u64>,
+
+    /// How long the work took.
+    pub elapsed: Duration,
+}
+
+impl Measurement {
+    /// Items processed per second.
+    pub fn items_per_second(&self) -> f64 {
+        self.items as f64 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+
+    /// Bytes read per second, for measurements that read input.
+    pub fn bytes_per_second(&self) -> Option<f64> {
+        self.bytes
+            .map(|bytes| bytes as f64 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE))
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} items in {:.3}s ({:.0} items/s",
+            self.items,
+            self.elapsed.as_secs_f64(),
+            self.items_per_second()
+        )?;
+        if let Some(rate) = self.bytes_per_second() {
+            write!(f, ", {:.1} MB/s", rate / 1_000_000.0)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Times parsing every item of the bulk dataset `D` from `reader`. The items are dropped as they're parsed, so this
+/// measures parsing alone and runs in constant memory.
+pub fn bulk_parse<D: Dataset, R: Read>(reader: R) -> Result<Measurement, BulkError> {
+    let mut reader = CountingReader {
+        inner: reader,
+        bytes: 0,
+    };
+    let start = Instant::now();
+    let mut items = 0;
+    for item in bulk::read::<D, _>(&mut reader) {
+        item?;
+        items += 1;
+    }
+
+    Ok(Measurement {
+        items,
+        bytes: Some(reader.bytes),
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Times building a [`FuzzyIndex`] over `cards`.
+pub fn fuzzy_index(cards: &[Card]) -> Measurement {
+    time(cards.len(), || FuzzyIndex::from_cards(cards))
+}
+
+/// Times building an [`Autocomplete`] table over `cards`.
+pub fn autocomplete(cards: &[Card]) -> Measurement {
+    time(cards.len(), || Autocomplete::from_cards(cards))
+}
+
+/// Times `work`, which processes `items` items. Its result is dropped after the clock stops.
+fn time<T, F: FnOnce() -> T>(items: usize, work: F) -> Measurement {
+    let start = Instant::now();
+    let result = work();
+    let elapsed = start.elapsed();
+    drop(result);
+
+    Measurement {
+        items,
+        bytes: None,
+        elapsed,
+    }
+}
+
+/// A reader that counts the bytes read through it.
+struct CountingReader<R> {
+    inner: R,
+    bytes: u64,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.bytes += read as u64;
+        Ok(read)
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_bulk_parse() {
+        use super::bulk_parse;
+        use crate::bulk::DefaultCards;
+        use crate::testing;
+
+        let json = serde_json::json!([
+            testing::card_json(serde_json::json!({})),
+            testing::card_json(serde_json::json!({"name": "Chain Lightning"})),
+        ])
+        .to_string();
+        let measurement = bulk_parse::<DefaultCards, _>(json.as_bytes()).unwrap();
+        assert_eq!(2, measurement.items);
+        assert_eq!(Some(json.len() as u64), measurement.bytes);
+        assert!(measurement.items_per_second() > 0.0);
+    }
+
+    #[test]
+    fn test_display() {
+        use super::Measurement;
+        use std::time::Duration;
+
+        let measurement = Measurement {
+            items: 1000,
+            bytes: Some(4_000_000),
+            elapsed: Duration::from_millis(500),
+        };
+        assert_eq!(
+            "1000 items in 0.500s (2000 items/s, 8.0 MB/s)",
+            measurement.to_string()
+        );
+    }
+}
@@ -0,0 +1,481 @@
+//! `scryfall`, a command line tool for looking cards up on Scryfall, enabled by the `cli` feature. It's a thin layer
+//! over the [blocking client](scryfall::client::blocking::Client), so it doubles as an example of the client's API.
+//!
+//! The client is configured from the environment, as described on
+//! [`ClientBuilder::from_env`](scryfall::client::ClientBuilder::from_env).
+
+use clap::{Parser, Subcommand};
+use scryfall::bulk::BulkKind;
+use scryfall::client::blocking::Client;
+use scryfall::client::ClientBuilder;
+use scryfall::types::card::Card;
+use scryfall::types::price::Price;
+use scryfall::types::search::{SearchParams, SortDirection, SortOrder, UniqueMode};
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+
+/// Search and look up Magic: The Gathering cards on Scryfall.
+#[derive(Debug, Parser)]
+#[command(name = "scryfall", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Lists the cards matching a query in Scryfall's search syntax, one per line.
+    Search {
+        /// The query, e.g. `t:goblin c:r mv<=2`.
+        #[arg(required = true)]
+        query: Vec<String>,
+
+        /// How to sort the results.
+        #[arg(long, value_enum)]
+        order: Option<SortOrder>,
+
+        /// Which way to sort the results.
+        #[arg(long, value_enum)]
+        dir: Option<SortDirection>,
+
+        /// Which printings of each card to list.
+        #[arg(long, value_enum)]
+        unique: Option<UniqueMode>,
+
+        /// List at most this many cards.
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Print each card as a line of Scryfall JSON.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Shows a card, found by a name that may be misspelled or partial.
+    Card {
+        /// The card's name.
+        #[arg(required = true)]
+        name: Vec<String>,
+
+        /// Only match the name exactly.
+        #[arg(long)]
+        exact: bool,
+
+        /// Show the printing from the set with this code.
+        #[arg(long)]
+        set: Option<String>,
+
+        /// Print the card as Scryfall JSON.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Shows a set, found by its code.
+    Set {
+        /// The set's code, e.g. `blb`.
+        code: String,
+    },
+
+    /// Shows a random card.
+    Random {
+        /// Only pick from cards matching this query.
+        query: Vec<String>,
+
+        /// Print the card as Scryfall JSON.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Browses search results interactively, with each card's text beside them.
+    #[cfg(feature = "tui")]
+    Browse {
+        /// A query to search for right away.
+        query: Vec<String>,
+    },
+
+    /// Works with Scryfall's bulk data files.
+    Bulk {
+        #[command(subcommand)]
+        command: BulkCommand,
+    },
+
+    /// Shows a card's current prices, found by a name that may be misspelled or partial.
+    Price {
+        /// The card's name.
+        #[arg(required = true)]
+        name: Vec<String>,
+
+        /// Show the prices of the printing from the set with this code.
+        #[arg(long)]
+        set: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum BulkCommand {
+    /// Downloads a bulk file as is.
+    Download {
+        /// Which file to download.
+        #[arg(value_enum)]
+        kind: BulkKind,
+
+        /// Where to save the file. Defaults to the file's type in the current directory, e.g. `oracle_cards.json`.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let client = match ClientBuilder::from_env() {
+        Ok(builder) => builder.build_blocking(),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(2);
+        }
+    };
+
+    let stdout = io::stdout();
+    if let Err(err) = run(cli.command, &client, &mut stdout.lock()) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+/// Runs `command` with `client`, writing what it prints to `out`.
+fn run(command: Command, client: &Client, out: &mut dyn Write) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Search {
+            query,
+            order,
+            dir,
+            unique,
+            limit,
+            json,
+        } => {
+            let params = SearchParams {
+                order,
+                dir,
+                unique,
+                ..SearchParams::default()
+            };
+            let first = client.search(&query.join(" "), &params)?;
+            for card in client.paginate(first).take(limit.unwrap_or(usize::MAX)) {
+                let card = card?;
+                if json {
+                    writeln!(out, "{}", serde_json::to_string(&card)?)?;
+                } else {
+                    writeln!(
+                        out,
+                        "{}  {} #{}  {}",
+                        card.name,
+                        card.set.to_uppercase(),
+                        card.collector_number,
+                        card.mana_cost.as_deref().unwrap_or("")
+                    )?;
+                }
+            }
+        }
+        Command::Card {
+            name,
+            exact,
+            set,
+            json,
+        } => {
+            let name = name.join(" ");
+            let card = if exact {
+                client.card_named_exact(&name, set.as_deref())?
+            } else {
+                client.card_named_fuzzy(&name, set.as_deref())?
+            };
+            write_card(out, &card, json)?;
+        }
+        Command::Set { code } => {
+            let set = client.set_by_code(&code)?;
+            writeln!(out, "{} ({})", set.name, set.code.to_uppercase())?;
+            writeln!(out, "{}, {} cards", set.set_type, set.card_count)?;
+            if let Some(released_at) = set.released_at {
+                writeln!(out, "Released {}", released_at)?;
+            }
+        }
+        Command::Random { query, json } => {
+            let card = if query.is_empty() {
+                client.random_card()?
+            } else {
+                client.random_card_filtered(&query.join(" "))?
+            };
+            write_card(out, &card, json)?;
+        }
+        #[cfg(feature = "tui")]
+        Command::Browse { query } => {
+            let query = query.join(" ");
+            scryfall::tui::run(
+                client.clone(),
+                Some(query.as_str()).filter(|query| !query.is_empty()),
+            )?;
+        }
+        Command::Bulk {
+            command: BulkCommand::Download { kind, output },
+        } => {
+            let path = output.unwrap_or_else(|| format!("{}.json", kind.type_name()).into());
+            let mut response = client.open_bulk(kind)?;
+            let bytes = io::copy(&mut response, &mut File::create(&path)?)?;
+            writeln!(out, "Saved {} bytes to {}", bytes, path.display())?;
+        }
+        Command::Price { name, set } => {
+            let card = client.card_named_fuzzy(&name.join(" "), set.as_deref())?;
+            writeln!(
+                out,
+                "{} ({} #{})",
+                card.name,
+                card.set.to_uppercase(),
+                card.collector_number
+            )?;
+            let prices = &card.prices;
+            let rows = [
+                ("usd", &prices.usd),
+                ("usd_foil", &prices.usd_foil),
+                ("usd_etched", &prices.usd_etched),
+                ("eur", &prices.eur),
+                ("eur_foil", &prices.eur_foil),
+                ("tix", &prices.tix),
+            ];
+            for (currency, price) in rows.iter() {
+                if let Some(Price(price)) = price {
+                    writeln!(out, "{:<10} {:.2}", currency, price)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes `card` to `out`, as Scryfall JSON if `json` is set, or else as its name, cost, type and text, a face at a
+/// time for cards with several.
+fn write_card(out: &mut dyn Write, card: &Card, json: bool) -> io::Result<()> {
+    if json {
+        return writeln!(out, "{}", serde_json::to_string_pretty(card)?);
+    }
+
+    match &card.card_faces {
+        Some(faces) => {
+            for (i, face) in faces.iter().enumerate() {
+                if i > 0 {
+                    writeln!(out, "//")?;
+                }
+                write_face(
+                    out,
+                    &face.name,
+                    &face.mana_cost,
+                    &face.type_line,
+                    face.oracle_text.as_deref(),
+                )?;
+            }
+        }
+        None => write_face(
+            out,
+            &card.name,
+            card.mana_cost.as_deref().unwrap_or(""),
+            &card.type_line,
+            card.oracle_text.as_deref(),
+        )?,
+    }
+    writeln!(
+        out,
+        "{} #{}, {}",
+        card.set_name, card.collector_number, card.rarity
+    )
+}
+
+/// Writes one face of a card: its name and cost, its type line, then its text.
+fn write_face(
+    out: &mut dyn Write,
+    name: &str,
+    mana_cost: &str,
+    type_line: &str,
+    oracle_text: Option<&str>,
+) -> io::Result<()> {
+    writeln!(out, "{} {}", name, mana_cost)?;
+    writeln!(out, "{}", type_line)?;
+    if let Some(text) = oracle_text.filter(|text| !text.is_empty()) {
+        writeln!(out, "{}", text)?;
+    }
+    Ok(())
+}
+
+mod tests {
+    /// Runs the command line `args` against the Scryfall API at `base_url`, returning what it prints.
+    #[cfg(test)]
+    async fn run(base_url: String, args: &[&str]) -> String {
+        use super::Cli;
+        use clap::Parser;
+        use scryfall::client::ClientBuilder;
+
+        let cli = Cli::try_parse_from(args).unwrap();
+        // The blocking client can't run on the async test's runtime, so drive it from a blocking thread.
+        tokio::task::spawn_blocking(move || {
+            let client = ClientBuilder::new().base_url(&base_url).build_blocking();
+            let mut out = Vec::new();
+            super::run(cli.command, &client, &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        })
+        .await
+        .unwrap()
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_card() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/named"))
+            .and(query_param("fuzzy", "lightning bolt"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        assert_eq!(
+            "Lightning Bolt {R}\nInstant\nLightning Bolt deals 3 damage to any target.\nMagic 2010 #146, common\n",
+            run(server.uri(), &["scryfall", "card", "lightning", "bolt"]).await
+        );
+        assert_eq!(
+            "Lightning Bolt (M10 #146)\nusd        2.47\nusd_foil   14.91\neur        2.10\neur_foil   12.00\ntix        0.03\n",
+            run(server.uri(), &["scryfall", "price", "lightning", "bolt"]).await
+        );
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_search() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let bolt: serde_json::Value =
+            serde_json::from_str(include_str!("../../testdata/cards/lightning_bolt.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "t:instant c:r"))
+            .and(query_param("order", "usd"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 2,
+                "has_more": false,
+                "data": [bolt, bolt],
+            })))
+            .mount(&server)
+            .await;
+
+        let args = [
+            "scryfall",
+            "search",
+            "t:instant",
+            "c:r",
+            "--order",
+            "usd",
+            "--limit",
+            "1",
+        ];
+        assert_eq!(
+            "Lightning Bolt  M10 #146  {R}\n",
+            run(server.uri(), &args).await
+        );
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_bulk_download() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bulk-data/rulings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "bulk_data",
+                "id": "7d2a8c54-3e6b-4b5c-9b3b-2a6f0a5f5d1e",
+                "type": "rulings",
+                "updated_at": "2024-08-01T09:02:49.367+00:00",
+                "uri": format!("{}/bulk-data/7d2a8c54-3e6b-4b5c-9b3b-2a6f0a5f5d1e", server.uri()),
+                "name": "Rulings",
+                "description": "A JSON file containing all Rulings on Scryfall.",
+                "size": 2,
+                "download_uri": format!("{}/rulings.json", server.uri()),
+                "content_type": "application/json",
+                "content_encoding": "gzip",
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rulings.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+            .mount(&server)
+            .await;
+
+        let output =
+            std::env::temp_dir().join(format!("scryfall-cli-test-{}.json", std::process::id()));
+        let args = [
+            "scryfall",
+            "bulk",
+            "download",
+            "rulings",
+            "--output",
+            output.to_str().unwrap(),
+        ];
+        assert_eq!(
+            format!("Saved 2 bytes to {}\n", output.display()),
+            run(server.uri(), &args).await
+        );
+        assert_eq!("[]", std::fs::read_to_string(&output).unwrap());
+        std::fs::remove_file(&output).unwrap();
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_set_and_random() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/sets/blb"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/sets/bloomburrow.json")),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/random"))
+            .and(query_param("q", "t:instant c:r"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        assert_eq!(
+            "Bloomburrow (BLB)\nexpansion, 398 cards\nReleased 2024-08-02\n",
+            run(server.uri(), &["scryfall", "set", "blb"]).await
+        );
+        let random = run(
+            server.uri(),
+            &["scryfall", "random", "t:instant", "c:r", "--json"],
+        )
+        .await;
+        let card: serde_json::Value = serde_json::from_str(&random).unwrap();
+        assert_eq!("Lightning Bolt", card["name"]);
+    }
+}
@@ -0,0 +1,315 @@
+//! Reading Scryfall's bulk data files.
+//!
+//! Each bulk file is one large JSON array. The readers here decode it one entry at a time, so even the multi-gigabyte
+//! `all_cards` file can be processed without holding it in memory.
+//!
+//! ```no_run
+//! use scryfall::bulk::{self, OracleCards};
+//! use std::fs::File;
+//!
+//! for card in bulk::read::<OracleCards, _>(File::open("oracle-cards.json").unwrap()) {
+//!     println!("{}", card.unwrap().name);
+//! }
+//! ```
+
+pub mod rulings;
+mod split;
+
+use crate::types::card::Card;
+use crate::types::ruling::Ruling;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::error;
+use std::fmt;
+use std::io::{self, Read};
+use std::marker::PhantomData;
+
+pub use rulings::RulingsIndex;
+pub use split::ArraySplitter;
+
+/// The bulk files Scryfall publishes daily.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "snake_case")]
+pub enum BulkKind {
+    /// One card object per Oracle ID, using the most recognizable printing.
+    #[cfg_attr(feature = "cli", value(name = "oracle_cards"))]
+    OracleCards,
+
+    /// One card object per unique illustration.
+    #[cfg_attr(feature = "cli", value(name = "unique_artwork"))]
+    UniqueArtwork,
+
+    /// Every card object on Scryfall, in English or the printed language if the card only exists in one language.
+    #[cfg_attr(feature = "cli", value(name = "default_cards"))]
+    DefaultCards,
+
+    /// Every card object on Scryfall in every language.
+    #[cfg_attr(feature = "cli", value(name = "all_cards"))]
+    AllCards,
+
+    /// Every ruling, keyed by Oracle ID rather than attached to cards.
+    Rulings,
+}
+
+impl BulkKind {
+    /// The `type` Scryfall uses for this file, e.g. `oracle_cards`.
+    pub fn type_name(self) -> &'static str {
+        match self {
+            BulkKind::OracleCards => "oracle_cards",
+            BulkKind::UniqueArtwork => "unique_artwork",
+            BulkKind::DefaultCards => "default_cards",
+            BulkKind::AllCards => "all_cards",
+            BulkKind::Rulings => "rulings",
+        }
+    }
+}
+
+/// A bulk file chosen at compile time, along with what its entries decode into and what the file guarantees.
+pub trait Dataset {
+    /// Which bulk file this is.
+    const KIND: BulkKind;
+
+    /// Whether the file holds at most one entry per Oracle ID. Readers enforce this.
+    const UNIQUE_ORACLE_IDS: bool = false;
+
+    /// The type each entry decodes into by default.
+    type Item: DeserializeOwned;
+}
+
+/// The `oracle_cards` file: one `Card` per Oracle ID.
+pub struct OracleCards;
+
+impl Dataset for OracleCards {
+    const KIND: BulkKind = BulkKind::OracleCards;
+    const UNIQUE_ORACLE_IDS: bool = true;
+    type Item = Card;
+}
+
+/// The `unique_artwork` file: one `Card` per illustration.
+pub struct UniqueArtwork;
+
+impl Dataset for UniqueArtwork {
+    const KIND: BulkKind = BulkKind::UniqueArtwork;
+    type Item = Card;
+}
+
+/// The `default_cards` file: every printing, in English where one exists.
+pub struct DefaultCards;
+
+impl Dataset for DefaultCards {
+    const KIND: BulkKind = BulkKind::DefaultCards;
+    type Item = Card;
+}
+
+/// The `all_cards` file: every printing in every language.
+pub struct AllCards;
+
+impl Dataset for AllCards {
+    const KIND: BulkKind = BulkKind::AllCards;
+    type Item = Card;
+}
+
+/// The `rulings` file: every `Ruling`, with many per Oracle ID.
+pub struct Rulings;
+
+impl Dataset for Rulings {
+    const KIND: BulkKind = BulkKind::Rulings;
+    type Item = Ruling;
+}
+
+/// Ways reading a bulk file can fail.
+#[derive(Debug)]
+pub enum BulkError {
+    /// Reading from the underlying source failed.
+    Io(io::Error),
+
+    /// The file is not a JSON array.
+    Format(String),
+
+    /// An entry could not be decoded.
+    Json(serde_json::Error),
+
+    /// A file that guarantees unique Oracle IDs contained this one twice.
+    DuplicateOracleId(String),
+}
+
+impl fmt::Display for BulkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BulkError::Io(err) => write!(f, "failed to read bulk data: {}", err),
+            BulkError::Format(message) => write!(f, "malformed bulk data: {}", message),
+            BulkError::Json(err) => write!(f, "failed to decode bulk entry: {}", err),
+            BulkError::DuplicateOracleId(id) => {
+                write!(f, "oracle ID {} appears more than once", id)
+            }
+        }
+    }
+}
+
+impl error::Error for BulkError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            BulkError::Io(err) => Some(err),
+            BulkError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BulkError {
+    fn from(err: io::Error) -> Self {
+        BulkError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for BulkError {
+    fn from(err: serde_json::Error) -> Self {
+        BulkError::Json(err)
+    }
+}
+
+#[derive(Deserialize)]
+struct OracleIdOnly {
+    oracle_id: Option<String>,
+}
+
+/// An iterator over the entries of a bulk file, decoding each into `T`.
+pub struct BulkReader<T, R> {
+    entries: ArraySplitter<R>,
+    oracle_ids: Option<HashSet<String>>,
+    item: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned, R: Read> BulkReader<T, R> {
+    fn new(reader: R, unique_oracle_ids: bool) -> Self {
+        BulkReader {
+            entries: ArraySplitter::new(reader),
+            oracle_ids: if unique_oracle_ids {
+                Some(HashSet::new())
+            } else {
+                None
+            },
+            item: PhantomData,
+        }
+    }
+
+    fn decode(&mut self, entry: &[u8]) -> Result<T, BulkError> {
+        if let Some(seen) = &mut self.oracle_ids {
+            if let Some(id) = serde_json::from_slice::<OracleIdOnly>(entry)?.oracle_id {
+                if !seen.insert(id.clone()) {
+                    return Err(BulkError::DuplicateOracleId(id));
+                }
+            }
+        }
+
+        Ok(serde_json::from_slice(entry)?)
+    }
+}
+
+impl<T: DeserializeOwned, R: Read> Iterator for BulkReader<T, R> {
+    type Item = Result<T, BulkError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.entries.next()? {
+            Ok(entry) => Some(self.decode(&entry)),
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// Reads the bulk file `D` from `reader`, decoding entries into the dataset's own item type.
+pub fn read<D: Dataset, R: Read>(reader: R) -> BulkReader<D::Item, R> {
+    BulkReader::new(reader, D::UNIQUE_ORACLE_IDS)
+}
+
+/// Reads the bulk file `D` from `reader`, decoding entries into the projection `T` instead — typically a struct with
+/// just the fields the caller needs, which is much faster to decode than a full `Card`.
+pub fn read_as<D: Dataset, T: DeserializeOwned, R: Read>(reader: R) -> BulkReader<T, R> {
+    BulkReader::new(reader, D::UNIQUE_ORACLE_IDS)
+}
+
+mod tests {
+    #[cfg(test)]
+    fn bulk_file(cards: &[serde_json::Value]) -> Vec<u8> {
+        let mut file = b"[\n".to_vec();
+        for (i, card) in cards.iter().enumerate() {
+            if i > 0 {
+                file.extend_from_slice(b",\n");
+            }
+            file.extend_from_slice(card.to_string().as_bytes());
+        }
+        file.extend_from_slice(b"\n]\n");
+        file
+    }
+
+    #[test]
+    fn test_read() {
+        use super::{read, DefaultCards};
+        use crate::testing::card_json;
+        use serde_json::json;
+
+        let file = bulk_file(&[card_json(json!({})), card_json(json!({ "set": "m11" }))]);
+        let cards: Vec<_> = read::<DefaultCards, _>(&file[..])
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(2, cards.len());
+        assert_eq!("m11", cards[1].set);
+    }
+
+    #[test]
+    fn test_oracle_ids_unique() {
+        use super::{read, BulkError, OracleCards};
+        use crate::testing::card_json;
+        use serde_json::json;
+
+        let file = bulk_file(&[card_json(json!({})), card_json(json!({ "set": "m11" }))]);
+        let results: Vec<_> = read::<OracleCards, _>(&file[..]).collect();
+
+        assert!(results[0].is_ok());
+        match &results[1] {
+            Err(BulkError::DuplicateOracleId(id)) => {
+                assert_eq!("4457ed35-7c10-48c8-9776-456485fdf070", id)
+            }
+            other => panic!(
+                "expected a duplicate oracle ID, got {:?}",
+                other.as_ref().map(|card| &card.name)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_read_as() {
+        use super::{read_as, AllCards};
+        use crate::testing::card_json;
+        use serde::Deserialize;
+        use serde_json::json;
+
+        #[derive(Deserialize)]
+        struct NameAndLang {
+            name: String,
+            lang: String,
+        }
+
+        let file = bulk_file(&[card_json(json!({ "lang": "ja", "name": "稲妻" }))]);
+        let cards: Vec<NameAndLang> = read_as::<AllCards, _, _>(&file[..])
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!("稲妻", cards[0].name);
+        assert_eq!("ja", cards[0].lang);
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_bulk_kind_value_enum() {
+        use super::BulkKind;
+        use clap::ValueEnum;
+
+        for kind in BulkKind::value_variants() {
+            assert_eq!(Ok(*kind), BulkKind::from_str(kind.type_name(), false));
+        }
+    }
+}
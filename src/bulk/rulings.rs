@@ -0,0 +1,128 @@
+//! Attaching rulings from the rulings bulk file to cards, without any API calls.
+
+use super::{read, BulkError, Rulings};
+use crate::types::card::Card;
+use crate::types::ruling::Ruling;
+use crate::types::uuid::Uuid;
+use std::collections::HashMap;
+use std::io::Read;
+
+/// Rulings grouped by the Oracle ID they apply to. Since rulings are per Oracle ID, one index serves every printing of
+/// a card.
+#[derive(Clone, Debug, Default)]
+pub struct RulingsIndex {
+    rulings: HashMap<Uuid, Vec<Ruling>>,
+}
+
+impl RulingsIndex {
+    /// Streams the rulings bulk file from `reader` into an index.
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, BulkError> {
+        read::<Rulings, _>(reader)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from_rulings)
+    }
+
+    /// Builds an index from already decoded rulings. Each card's rulings are kept oldest first.
+    pub fn from_rulings<I: IntoIterator<Item = Ruling>>(rulings: I) -> Self {
+        let mut index = RulingsIndex::default();
+        for ruling in rulings {
+            index
+                .rulings
+                .entry(ruling.oracle_id.clone())
+                .or_default()
+                .push(ruling);
+        }
+
+        for rulings in index.rulings.values_mut() {
+            rulings.sort_by_key(|ruling| ruling.published_at);
+        }
+
+        index
+    }
+
+    /// The rulings for `oracle_id`, oldest first. Cards without rulings get an empty slice.
+    pub fn get(&self, oracle_id: &str) -> &[Ruling] {
+        self.rulings.get(oracle_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// The rulings for `card`.
+    pub fn for_card(&self, card: &Card) -> &[Ruling] {
+        card.oracle_identity()
+            .map_or(&[], |oracle_id| self.get(oracle_id))
+    }
+
+    /// Pairs each of `cards` with its rulings.
+    pub fn join<'a, I>(&'a self, cards: I) -> impl Iterator<Item = (&'a Card, &'a [Ruling])> + 'a
+    where
+        I: IntoIterator<Item = &'a Card>,
+        I::IntoIter: 'a,
+    {
+        cards
+            .into_iter()
+            .map(move |card| (card, self.for_card(card)))
+    }
+
+    /// How many Oracle IDs have at least one ruling.
+    pub fn len(&self) -> usize {
+        self.rulings.len()
+    }
+
+    /// Whether the index holds no rulings.
+    pub fn is_empty(&self) -> bool {
+        self.rulings.is_empty()
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_join() {
+        use super::RulingsIndex;
+        use crate::testing;
+        use serde_json::json;
+
+        let file = r#"[
+            {"object": "ruling", "oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070", "source": "wotc",
+             "published_at": "2020-08-07", "comment": "Second."},
+            {"object": "ruling", "oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070", "source": "scryfall",
+             "published_at": "2004-10-04", "comment": "First."},
+            {"object": "ruling", "oracle_id": "00000000-0000-0000-0000-000000000000", "source": "wotc",
+             "published_at": "2004-10-04", "comment": "Unrelated."}
+        ]"#;
+        let index = RulingsIndex::from_reader(file.as_bytes()).unwrap();
+        assert_eq!(2, index.len());
+
+        let cards = vec![
+            testing::card(),
+            testing::card_with(json!({ "oracle_id": "ffffffff-0000-0000-0000-000000000000" })),
+        ];
+        let joined: Vec<Vec<&str>> = index
+            .join(&cards)
+            .map(|(_, rulings)| {
+                rulings
+                    .iter()
+                    .map(|ruling| ruling.comment.as_str())
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(vec![vec!["First.", "Second."], vec![]], joined);
+    }
+
+    #[test]
+    fn test_reversible_card() {
+        use super::RulingsIndex;
+        use crate::testing;
+
+        let file = r#"[
+            {"object": "ruling", "oracle_id": "3ac8bdcc-3712-573d-837a-894f9a029618", "source": "wotc",
+             "published_at": "2004-10-04", "comment": "Sol Ring."}
+        ]"#;
+        let index = RulingsIndex::from_reader(file.as_bytes()).unwrap();
+        assert_eq!(1, index.for_card(&testing::reversible_card()).len());
+        assert!(index
+            .for_card(&testing::reversible_card_of(
+                "ffffffff-0000-0000-0000-000000000000"
+            ))
+            .is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+use super::BulkError;
+use std::io::{BufRead, BufReader, Read};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum State {
+    /// Before the opening `[`.
+    Start,
+
+    /// Between entries, expecting an entry, `,`, or `]`.
+    Between,
+
+    /// Inside an entry.
+    Entry,
+
+    /// After the closing `]`.
+    Done,
+}
+
+/// Splits a JSON array read from a stream into the raw bytes of each top-level element, without parsing the elements
+/// themselves.
+pub struct ArraySplitter<R> {
+    reader: BufReader<R>,
+    parser: Parser,
+}
+
+impl<R: Read> ArraySplitter<R> {
+    pub fn new(reader: R) -> Self {
+        ArraySplitter {
+            reader: BufReader::with_capacity(1 << 16, reader),
+            parser: Parser {
+                state: State::Start,
+                depth: 0,
+                in_string: false,
+                escaped: false,
+                entry: Vec::new(),
+            },
+        }
+    }
+}
+
+/// The splitter's state machine, kept apart from the reader so the reader's buffer can be fed through it in place.
+struct Parser {
+    state: State,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    entry: Vec<u8>,
+}
+
+impl Parser {
+    /// Feeds one byte through the state machine, returning whether it completed an entry.
+    fn feed(&mut self, byte: u8) -> Result<bool, BulkError> {
+        match self.state {
+            State::Start => match byte {
+                b'[' => self.state = State::Between,
+                b if b.is_ascii_whitespace() => {}
+                _ => return Err(BulkError::Format("expected a JSON array".to_string())),
+            },
+            State::Between => match byte {
+                b']' => self.state = State::Done,
+                b',' => {}
+                b if b.is_ascii_whitespace() => {}
+                _ => {
+                    self.state = State::Entry;
+                    return self.feed_entry(byte);
+                }
+            },
+            State::Entry => return self.feed_entry(byte),
+            State::Done => {
+                if !byte.is_ascii_whitespace() {
+                    return Err(BulkError::Format(
+                        "trailing data after the array".to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn feed_entry(&mut self, byte: u8) -> Result<bool, BulkError> {
+        if self.in_string {
+            self.entry.push(byte);
+            if self.escaped {
+                self.escaped = false;
+            } else if byte == b'\\' {
+                self.escaped = true;
+            } else if byte == b'"' {
+                self.in_string = false;
+            }
+            return Ok(false);
+        }
+
+        match byte {
+            b'"' => self.in_string = true,
+            b'{' | b'[' => self.depth += 1,
+            b'}' | b']' if self.depth > 0 => {
+                self.depth -= 1;
+                if self.depth == 0 {
+                    self.entry.push(byte);
+                    self.state = State::Between;
+                    return Ok(true);
+                }
+            }
+            b',' | b']' if self.depth == 0 => {
+                // The end of a scalar entry.
+                self.state = if byte == b']' {
+                    State::Done
+                } else {
+                    State::Between
+                };
+                return Ok(true);
+            }
+            _ => {}
+        }
+
+        self.entry.push(byte);
+        Ok(false)
+    }
+}
+
+impl<R: Read> Iterator for ArraySplitter<R> {
+    type Item = Result<Vec<u8>, BulkError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let buffer = match self.reader.fill_buf() {
+                Ok(buffer) => buffer,
+                Err(err) => return Some(Err(err.into())),
+            };
+
+            if buffer.is_empty() {
+                return match self.parser.state {
+                    State::Done => None,
+                    _ => {
+                        self.parser.state = State::Done;
+                        Some(Err(BulkError::Format("unexpected end of data".to_string())))
+                    }
+                };
+            }
+
+            let mut outcome = None;
+            let mut used = buffer.len();
+            for (i, byte) in buffer.iter().enumerate() {
+                match self.parser.feed(*byte) {
+                    Ok(false) => {}
+                    result => {
+                        outcome = Some(result);
+                        used = i + 1;
+                        break;
+                    }
+                }
+            }
+            self.reader.consume(used);
+
+            match outcome {
+                Some(Ok(_)) => {
+                    let mut entry = std::mem::take(&mut self.parser.entry);
+                    entry.truncate(entry.trim_ascii_end().len());
+                    return Some(Ok(entry));
+                }
+                Some(Err(err)) => {
+                    self.parser.state = State::Done;
+                    return Some(Err(err));
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_split() {
+        use super::ArraySplitter;
+
+        let json = br#" [ {"a": "}]\"", "b": [1, 2]}, 3 , "x,y", {"c": {}} ] "#;
+        let entries: Vec<String> = ArraySplitter::new(&json[..])
+            .map(|entry| String::from_utf8(entry.unwrap()).unwrap())
+            .collect();
+
+        assert_eq!(
+            vec![
+                r#"{"a": "}]\"", "b": [1, 2]}"#,
+                "3",
+                r#""x,y""#,
+                r#"{"c": {}}"#
+            ],
+            entries
+        );
+    }
+
+    #[test]
+    fn test_split_errors() {
+        use super::ArraySplitter;
+
+        assert!(ArraySplitter::new(&b"{}"[..]).next().unwrap().is_err());
+        assert!(ArraySplitter::new(&b"[{\"a\": 1}"[..])
+            .nth(1)
+            .unwrap()
+            .is_err());
+        assert!(ArraySplitter::new(&b"[]"[..]).next().is_none());
+    }
+
+    #[test]
+    fn test_split_across_reads() {
+        use super::ArraySplitter;
+
+        // Entries straddle the 64 KiB boundaries of the reader's buffer.
+        let entries: Vec<String> = (0..5000)
+            .map(|i| {
+                format!(
+                    "{{\"name\": \"card {}\", \"text\": \"{}\"}}",
+                    i,
+                    "x".repeat(i % 50)
+                )
+            })
+            .collect();
+        let json = format!("[{}]", entries.join(",\n  "));
+        assert!(json.len() > 3 * (1 << 16));
+
+        let split: Vec<String> = ArraySplitter::new(json.as_bytes())
+            .map(|entry| String::from_utf8(entry.unwrap()).unwrap())
+            .collect();
+        assert_eq!(entries, split);
+    }
+}
@@ -0,0 +1,301 @@
+//! Caching decoded search results, independent of any HTTP-level caching.
+
+use crate::storage::Storage;
+use crate::types::card::Card;
+use crate::types::search::SearchParams;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::fmt;
+use std::io;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Splits a query into terms, keeping quoted strings and parenthesized groups together.
+fn split_terms(query: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quoted = false;
+
+    for c in query.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted && depth > 0 => depth -= 1,
+            c if c.is_whitespace() && !quoted && depth == 0 => {
+                if !current.is_empty() {
+                    terms.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        terms.push(current);
+    }
+
+    terms
+}
+
+/// Normalizes a search query so that equivalent spellings share a cache entry: case is folded, whitespace collapsed,
+/// and — when the query is a plain conjunction with no top-level `or` — the terms are sorted, since their order
+/// doesn't affect the results.
+pub fn canonicalize(query: &str) -> String {
+    let mut terms: Vec<String> = split_terms(query)
+        .into_iter()
+        .map(|term| {
+            term.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase()
+        })
+        .collect();
+
+    if !terms.iter().any(|term| term == "or") {
+        terms.sort();
+    }
+
+    terms.join(" ")
+}
+
+/// The prefix of the storage keys the cache uses, followed by a hash of the canonical query and search options.
+const KEY_PREFIX: &str = "query/";
+
+/// The storage key for `query` searched with `params`.
+fn key(query: &str, params: &SearchParams) -> String {
+    let options = [
+        ("unique", params.unique.map(|unique| unique.to_string())),
+        ("order", params.order.map(|order| order.to_string())),
+        ("dir", params.dir.map(|dir| dir.to_string())),
+        ("include_extras", Some(params.include_extras.to_string())),
+        (
+            "include_multilingual",
+            Some(params.include_multilingual.to_string()),
+        ),
+        (
+            "include_variations",
+            Some(params.include_variations.to_string()),
+        ),
+        ("exclude_funny", Some(params.exclude_funny.to_string())),
+        ("page", params.page.map(|page| page.to_string())),
+    ];
+
+    let mut text = canonicalize(query);
+    for (name, value) in options.iter() {
+        if let Some(value) = value {
+            text.push_str(&format!("\n{}={}", name, value));
+        }
+    }
+    let digest = Sha1::digest(text.as_bytes());
+    let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}{}", KEY_PREFIX, hex)
+}
+
+/// What the cache stores for each search.
+#[derive(Deserialize, Serialize)]
+struct Entry {
+    /// When the entry expires, in milliseconds since the Unix epoch.
+    expires_at: u64,
+    cards: Vec<Card>,
+}
+
+/// Milliseconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as u64)
+}
+
+/// Decoded search results in a [`Storage`], keyed by canonical query and search options, each kept for a fixed time
+/// to live. Entries are stored as JSON under keys starting with `query/`, so a persistent store keeps them across
+/// runs and can be shared with other users of it. The cache can be shared between threads.
+///
+/// Give one to [`ClientBuilder::query_cache`](crate::client::ClientBuilder::query_cache) to have
+/// [`Client::search_cached`](crate::client::Client::search_cached) use it.
+pub struct QueryCache<S> {
+    storage: S,
+    ttl: Duration,
+}
+
+impl<S: Storage> QueryCache<S> {
+    /// A cache in `storage` whose entries expire `ttl` after they are stored.
+    pub fn new(storage: S, ttl: Duration) -> Self {
+        QueryCache { storage, ttl }
+    }
+
+    /// The results stored for `query` with `params`, if they haven't expired. Expired entries are removed.
+    pub fn get(&self, query: &str, params: &SearchParams) -> io::Result<Option<Vec<Card>>> {
+        let key = key(query, params);
+        let entry: Entry = match self.storage.get(&key)? {
+            Some(value) => serde_json::from_slice(&value)?,
+            None => return Ok(None),
+        };
+
+        if entry.expires_at > now() {
+            Ok(Some(entry.cards))
+        } else {
+            self.storage.remove(&key)?;
+            Ok(None)
+        }
+    }
+
+    /// Stores `cards` as the results of `query` with `params`.
+    pub fn insert(&self, query: &str, params: &SearchParams, cards: &[Card]) -> io::Result<()> {
+        let entry = Entry {
+            expires_at: now().saturating_add(self.ttl.as_millis() as u64),
+            cards: cards.to_vec(),
+        };
+        self.storage
+            .put(&key(query, params), &serde_json::to_vec(&entry)?)
+    }
+
+    /// Drops every expired entry, and any that can no longer be decoded, returning how many were dropped.
+    pub fn purge_expired(&self) -> io::Result<usize> {
+        let now = now();
+        let mut purged = 0;
+        for key in self.storage.scan(KEY_PREFIX)? {
+            let expired = match self.storage.get(&key)? {
+                Some(value) => serde_json::from_slice::<Entry>(&value)
+                    .map_or(true, |entry| entry.expires_at <= now),
+                None => false,
+            };
+            if expired && self.storage.remove(&key)? {
+                purged += 1;
+            }
+        }
+        Ok(purged)
+    }
+
+    /// Drops every entry.
+    pub fn clear(&self) -> io::Result<()> {
+        for key in self.storage.scan(KEY_PREFIX)? {
+            self.storage.remove(&key)?;
+        }
+        Ok(())
+    }
+
+    /// The number of entries, including expired ones that haven't been purged.
+    pub fn len(&self) -> io::Result<usize> {
+        Ok(self.storage.scan(KEY_PREFIX)?.len())
+    }
+
+    /// Whether the cache has no entries.
+    pub fn is_empty(&self) -> io::Result<bool> {
+        Ok(self.len()? == 0)
+    }
+}
+
+#[cfg(feature = "client")]
+impl<S: Storage + 'static> QueryCache<S> {
+    /// The same cache over type-erased storage.
+    pub(crate) fn boxed(self) -> QueryCache<Box<dyn Storage>> {
+        QueryCache {
+            storage: Box::new(self.storage),
+            ttl: self.ttl,
+        }
+    }
+}
+
+impl<S> fmt::Debug for QueryCache<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QueryCache")
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_canonicalize() {
+        use super::canonicalize;
+
+        assert_eq!(
+            "f:modern t:instant",
+            canonicalize("  T:Instant   f:modern ")
+        );
+        assert_eq!(
+            canonicalize("t:instant f:modern"),
+            canonicalize("f:modern t:instant")
+        );
+        assert_eq!(
+            "!\"lightning bolt\" set:m10",
+            canonicalize("set:m10 !\"Lightning  Bolt\"")
+        );
+        assert_eq!(
+            "(t:goblin or t:elf) c:r",
+            canonicalize("c:r (t:goblin OR t:elf)")
+        );
+        assert_eq!("t:goblin or t:elf", canonicalize("t:goblin or t:elf"));
+    }
+
+    #[test]
+    fn test_get_and_insert() {
+        use super::QueryCache;
+        use crate::storage::MemoryStorage;
+        use crate::testing;
+        use crate::types::search::{SearchParams, SortOrder, UniqueMode};
+        use std::time::Duration;
+
+        let cache = QueryCache::new(MemoryStorage::new(), Duration::from_secs(60));
+        let params = SearchParams {
+            order: Some(SortOrder::Name),
+            unique: Some(UniqueMode::Cards),
+            ..SearchParams::default()
+        };
+        assert_eq!(None, cache.get("t:instant", &params).unwrap());
+
+        cache
+            .insert("t:instant c:r", &params, &[testing::card()])
+            .unwrap();
+        let hit = cache.get("C:R  t:instant", &params).unwrap();
+        assert_eq!(Some(vec![testing::card()]), hit);
+
+        let by_cmc = SearchParams {
+            order: Some(SortOrder::Cmc),
+            ..params.clone()
+        };
+        assert_eq!(None, cache.get("t:instant c:r", &by_cmc).unwrap());
+        let second_page = SearchParams {
+            page: Some(2),
+            ..params
+        };
+        assert_eq!(None, cache.get("t:instant c:r", &second_page).unwrap());
+    }
+
+    #[test]
+    fn test_expiry() {
+        use super::QueryCache;
+        use crate::storage::{MemoryStorage, Storage};
+        use crate::testing;
+        use crate::types::search::SearchParams;
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let storage = Arc::new(MemoryStorage::new());
+        let params = SearchParams::default();
+        let cache = QueryCache::new(Arc::clone(&storage), Duration::from_secs(0));
+        cache
+            .insert("t:instant", &params, &[testing::card()])
+            .unwrap();
+        cache.insert("t:sorcery", &params, &[]).unwrap();
+        storage.put("images/a", b"unrelated").unwrap();
+        assert_eq!(2, cache.len().unwrap());
+        assert_eq!(2, cache.purge_expired().unwrap());
+        assert!(cache.is_empty().unwrap());
+        assert_eq!(None, cache.get("t:instant", &params).unwrap());
+
+        // Entries persist in the storage, so another cache over it sees them.
+        let cache = QueryCache::new(Arc::clone(&storage), Duration::from_secs(60));
+        cache.insert("t:instant", &params, &[]).unwrap();
+        let reopened = QueryCache::new(Arc::clone(&storage), Duration::from_secs(60));
+        assert_eq!(
+            Some(Vec::new()),
+            reopened.get("t:instant", &params).unwrap()
+        );
+        reopened.clear().unwrap();
+        assert!(cache.is_empty().unwrap());
+        assert_eq!(vec!["images/a"], storage.scan("").unwrap());
+    }
+}
@@ -0,0 +1,44 @@
+//! Facts about specific cards that the rules single out by name.
+
+use crate::names;
+
+/// The five basic land names.
+pub const BASIC_LAND_NAMES: [&str; 5] = ["Plains", "Island", "Swamp", "Mountain", "Forest"];
+
+/// The snow-covered basic land names.
+pub const SNOW_BASIC_LAND_NAMES: [&str; 6] = [
+    "Snow-Covered Plains",
+    "Snow-Covered Island",
+    "Snow-Covered Swamp",
+    "Snow-Covered Mountain",
+    "Snow-Covered Forest",
+    "Snow-Covered Wastes",
+];
+
+/// The colorless basic land, which isn't one of the five basic land types.
+pub const WASTES: &str = "Wastes";
+
+/// Whether `name` is a basic land, including snow-covered basics and Wastes. Decks may contain any number of these.
+/// Names are compared after [`names::normalize`], like every other local name lookup.
+pub fn is_basic_land_name(name: &str) -> bool {
+    let name = names::normalize(name);
+    BASIC_LAND_NAMES
+        .iter()
+        .chain(SNOW_BASIC_LAND_NAMES.iter())
+        .chain(std::iter::once(&WASTES))
+        .any(|basic| names::normalize(basic) == name)
+}
+
+mod tests {
+    #[test]
+    fn test_is_basic_land_name() {
+        use super::is_basic_land_name;
+
+        assert!(is_basic_land_name("Mountain"));
+        assert!(is_basic_land_name("snow-covered island"));
+        assert!(is_basic_land_name("Snow Covered  Island"));
+        assert!(is_basic_land_name("Wastes"));
+        assert!(!is_basic_land_name("Mountain Goat"));
+        assert!(!is_basic_land_name("Dryad Arbor"));
+    }
+}
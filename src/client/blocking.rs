@@ -0,0 +1,703 @@
+//! A blocking client for Scryfall's API, enabled by the `blocking` feature. It has the same methods as the async
+//! [`Client`](super::Client), for CLI tools and scripts that don't want an async runtime, plus `download_bulk` for
+//! streaming bulk files.
+//!
+//! ```no_run
+//! # fn example() -> Result<(), scryfall::client::Error> {
+//! let client = scryfall::client::blocking::Client::new();
+//! let card = client.card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")?;
+//! println!("{}", card.name);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Every call is made by an async client driven on a small runtime of the blocking client's own, so both clients share
+//! one implementation of the API, including its transport, retries and rate limiting. Because of that runtime, the
+//! blocking client mustn't be created or used from inside an async context.
+
+use super::error::Error;
+use super::estimate::Estimate;
+use super::transport::HttpRequest;
+use super::{ClientBuilder, PageCursor, RateLimiter, ResponseMeta, BASE_URL};
+use crate::bulk::{self, BulkKind, BulkReader, Dataset};
+use crate::types::bulk::BulkData;
+use crate::types::card::Card;
+use crate::types::catalog::{Catalog, CatalogKind};
+use crate::types::date::Date;
+use crate::types::identifier::{CardIdentifier, Collection};
+use crate::types::list::{CardList, List, SetList};
+use crate::types::ruling::Ruling;
+use crate::types::search::SearchParams;
+use crate::types::set::Set;
+use crate::types::symbol::{CardSymbol, ManaCostInfo};
+use crate::types::uri::Uri;
+use futures_util::stream::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// A blocking client for Scryfall's API. Like the async client, it's `Send + Sync` and clones share one connection
+/// pool, configuration and rate limiter.
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: super::Client,
+    runtime: Arc<Runtime>,
+
+    /// The client for bulk downloads, which are streamed rather than read whole through the transport.
+    http: reqwest::blocking::Client,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client::new()
+    }
+}
+
+impl Client {
+    /// A client for api.scryfall.com.
+    pub fn new() -> Self {
+        Client::with_base_url(BASE_URL)
+    }
+
+    /// A client for a Scryfall-compatible API at `base_url`, such as a mock server in tests.
+    pub(crate) fn with_base_url(base_url: &str) -> Self {
+        ClientBuilder::new().base_url(base_url).build_blocking()
+    }
+
+    /// A blocking client making its calls with `client`, and bulk downloads with `http`.
+    pub(super) fn from_async(client: super::Client, http: reqwest::blocking::Client) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start the blocking client's runtime");
+        Client {
+            client,
+            runtime: Arc::new(runtime),
+            http,
+        }
+    }
+
+    /// Runs `call` to completion on this client's runtime.
+    fn block_on<F: Future>(&self, call: F) -> F::Output {
+        self.runtime.block_on(call)
+    }
+
+    /// A handle to this client whose calls each fail with [`Error::Timeout`] if they take longer than `timeout`,
+    /// including retries and waiting for the rate limiter. It shares this client's connection pool and rate limiter.
+    pub fn timeout(&self, timeout: Duration) -> Client {
+        Client {
+            client: self.client.timeout(timeout),
+            ..self.clone()
+        }
+    }
+
+    /// The request `call` would send first, with every header and the body, without sending it. `call` is given a
+    /// handle to this client that stops at its first request; see [`Client::dry_run`](super::Client::dry_run).
+    pub fn dry_run<F, T>(&self, call: F) -> Result<Option<HttpRequest>, Error>
+    where
+        F: FnOnce(&Client) -> Result<T, Error>,
+    {
+        let (client, captured) = self.client.dry_run_handle();
+        let result = call(&Client {
+            client,
+            ..self.clone()
+        });
+        super::Client::dry_run_result(&captured, result)
+    }
+
+    /// Runs `call` on a handle to this client that records every response it receives, and returns the call's result
+    /// with what was recorded; see [`Client::with_meta`](super::Client::with_meta).
+    pub fn with_meta<F, T>(&self, call: F) -> (Result<T, Error>, Vec<ResponseMeta>)
+    where
+        F: FnOnce(&Client) -> Result<T, Error>,
+    {
+        let (client, recorded) = self.client.meta_handle();
+        let result = call(&Client {
+            client,
+            ..self.clone()
+        });
+        let meta = recorded.lock().unwrap().drain(..).collect();
+        (result, meta)
+    }
+
+    /// How long Scryfall takes to answer a small request, for services deciding whether to go into an offline mode;
+    /// see [`Client::ping`](super::Client::ping).
+    pub fn ping(&self) -> Result<Duration, Error> {
+        self.block_on(self.client.ping())
+    }
+
+    /// The rate limiter spacing out this client's requests, shared with all of its clones. By default it sends one
+    /// request every [`DEFAULT_INTERVAL`](super::DEFAULT_INTERVAL), as Scryfall asks.
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        self.client.rate_limiter()
+    }
+
+    /// The card with Scryfall ID `id`.
+    pub fn card_by_id(&self, id: &str) -> Result<Card, Error> {
+        self.block_on(self.client.card_by_id(id))
+    }
+
+    /// The card with Gatherer multiverse ID `id`.
+    pub fn card_by_multiverse_id(&self, id: u32) -> Result<Card, Error> {
+        self.block_on(self.client.card_by_multiverse_id(id))
+    }
+
+    /// The card with Magic Online ID `id`.
+    pub fn card_by_mtgo_id(&self, id: u32) -> Result<Card, Error> {
+        self.block_on(self.client.card_by_mtgo_id(id))
+    }
+
+    /// The card with MTG Arena ID `id`.
+    pub fn card_by_arena_id(&self, id: u32) -> Result<Card, Error> {
+        self.block_on(self.client.card_by_arena_id(id))
+    }
+
+    /// The card with TCGplayer product ID `id`.
+    pub fn card_by_tcgplayer_id(&self, id: u32) -> Result<Card, Error> {
+        self.block_on(self.client.card_by_tcgplayer_id(id))
+    }
+
+    /// The card with Cardmarket product ID `id`.
+    pub fn card_by_cardmarket_id(&self, id: u32) -> Result<Card, Error> {
+        self.block_on(self.client.card_by_cardmarket_id(id))
+    }
+
+    /// The printing with collector number `number` in the set with code `set`, in language `lang` if given (e.g.
+    /// `"ja"`) or English otherwise. Collector numbers are sent exactly as given, since they can contain letters and
+    /// symbols like `★`.
+    pub fn card_by_set_number(
+        &self,
+        set: &str,
+        number: &str,
+        lang: Option<&str>,
+    ) -> Result<Card, Error> {
+        self.block_on(self.client.card_by_set_number(set, number, lang))
+    }
+
+    /// The cards matching `identifiers`, plus the identifiers that matched nothing. Scryfall accepts at most
+    /// [`COLLECTION_LIMIT`](super::COLLECTION_LIMIT) identifiers per request, so longer lists are sent in chunks.
+    pub fn collection(&self, identifiers: &[CardIdentifier]) -> Result<Collection, Error> {
+        self.block_on(self.client.collection(identifiers))
+    }
+
+    /// The card each of `inputs` refers to, in the same order, from as few requests as possible. See
+    /// [`Client::resolve`](super::Client::resolve) for the inputs understood.
+    pub fn resolve(&self, inputs: &[&str]) -> Result<Vec<Result<Card, Error>>, Error> {
+        self.block_on(self.client.resolve(inputs))
+    }
+
+    /// Up to 20 card names starting with or containing `partial`, for autocompleting a name as it's typed. Extras
+    /// like tokens and art cards are left out unless `include_extras` is set. Queries shorter than two characters
+    /// return an empty catalog.
+    pub fn autocomplete(&self, partial: &str, include_extras: bool) -> Result<Catalog, Error> {
+        self.block_on(self.client.autocomplete(partial, include_extras))
+    }
+
+    /// The card named exactly `name`, ignoring case and punctuation. If several printings exist, the most recent one
+    /// is returned, unless `set` names the set code to take it from.
+    pub fn card_named_exact(&self, name: &str, set: Option<&str>) -> Result<Card, Error> {
+        self.block_on(self.client.card_named_exact(name, set))
+    }
+
+    /// The card whose name best matches `name`, which may be misspelled or partial, as in `"jac bele"` for Jace
+    /// Beleren. Fails with a `not_found` error if no single card matches, including when the name is ambiguous.
+    pub fn card_named_fuzzy(&self, name: &str, set: Option<&str>) -> Result<Card, Error> {
+        self.block_on(self.client.card_named_fuzzy(name, set))
+    }
+
+    /// One page of the cards matching the search `query`, written in Scryfall's search syntax. Check the list's
+    /// `warnings` for parts of the query Scryfall ignored. A search with no results fails with a `not_found` error, and
+    /// a query with a mistake [`query::validate`](crate::query::validate) can catch fails before it's sent.
+    pub fn search(&self, query: &str, params: &SearchParams) -> Result<CardList, Error> {
+        self.block_on(self.client.search(query, params))
+    }
+
+    /// Every card matching the search `query`, fetching each page of results only once the previous one has been
+    /// consumed. A search with no results yields nothing, and a query with a mistake
+    /// [`query::validate`](crate::query::validate) can catch is yielded as an error before anything is sent.
+    ///
+    /// ```no_run
+    /// let client = scryfall::client::blocking::Client::new();
+    /// for card in client.search_iter("t:goblin") {
+    ///     println!("{}", card.unwrap().name);
+    /// }
+    /// ```
+    pub fn search_iter(&self, query: &str) -> PagedIter<Card> {
+        self.iter(self.client.search_all(query))
+    }
+
+    /// Every card matching the search `query` with `params`, from the page `params` asks for onwards, served from the
+    /// [query cache](super::ClientBuilder::query_cache) when it holds an unexpired entry for the same search.
+    pub fn search_cached(&self, query: &str, params: &SearchParams) -> Result<Vec<Card>, Error> {
+        self.block_on(self.client.search_cached(query, params))
+    }
+
+    /// Every item of `list`, followed by those on each of its later pages, fetched one page at a time as the
+    /// iterator is consumed.
+    pub fn paginate<T>(&self, list: List<T>) -> PagedIter<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.iter(self.client.paginate(list))
+    }
+
+    /// The object at `uri`, a link from a response such as a card's `rulings_uri`, decoded as `T`. Only the link's
+    /// path and query are used, so it's fetched from this client's base URL like any other call.
+    pub fn fetch<T: DeserializeOwned>(&self, uri: &Uri) -> Result<T, Error> {
+        self.block_on(self.client.fetch(uri))
+    }
+
+    /// The page of a paginated list that `cursor` is at. Use [`PageCursor::after`] on it for the cursor at the next
+    /// page.
+    pub fn page<T: DeserializeOwned>(&self, cursor: &PageCursor) -> Result<List<T>, Error> {
+        self.block_on(self.client.page(cursor))
+    }
+
+    /// Every item of the paginated list at `uri`, such as a card's `prints_search_uri`, fetching each page only once
+    /// the previous one has been consumed.
+    pub fn fetch_all<T>(&self, uri: &Uri) -> PagedIter<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.iter(self.client.fetch_all(uri))
+    }
+
+    /// How many cards match the search `query`, from the first page of results alone.
+    pub fn count(&self, query: &str) -> Result<u32, Error> {
+        self.block_on(self.client.count(query))
+    }
+
+    /// How many cards and pages a search would return, from the first page of results alone, so tools can confirm
+    /// with [`Estimate::exceeds`] before iterating over a huge result.
+    pub fn estimate(&self, query: &str, params: &SearchParams) -> Result<Estimate, Error> {
+        self.block_on(self.client.estimate(query, params))
+    }
+
+    /// `n` distinct random cards matching the search `query`, or all of them if fewer match. This picks random
+    /// positions among the results and fetches only the pages holding them, which takes far fewer requests than
+    /// calling [`random_card_filtered`](Self::random_card_filtered) `n` times.
+    pub fn sample(&self, query: &str, n: usize) -> Result<Vec<Card>, Error> {
+        self.block_on(self.client.sample(query, n))
+    }
+
+    /// Every printing previewed on or after `since`, or released then if it was never previewed, newest previews
+    /// first, for spoiler bots. Pass the results through a [`SpoilerFeed`](super::SpoilerFeed) to report each card
+    /// only once across polls.
+    pub fn cards_added_since(&self, since: Date) -> Result<Vec<Card>, Error> {
+        self.block_on(self.client.cards_added_since(since))
+    }
+
+    /// A random card.
+    pub fn random_card(&self) -> Result<Card, Error> {
+        self.block_on(self.client.random_card())
+    }
+
+    /// A random card matching the search `query`, e.g. `"is:commander"`. Fails with a `not_found` error if nothing
+    /// matches.
+    pub fn random_card_filtered(&self, query: &str) -> Result<Card, Error> {
+        self.block_on(self.client.random_card_filtered(query))
+    }
+
+    /// The rulings for the card with Scryfall ID `id`.
+    pub fn rulings_by_id(&self, id: &str) -> Result<List<Ruling>, Error> {
+        self.block_on(self.client.rulings_by_id(id))
+    }
+
+    /// The rulings for the card with Gatherer multiverse ID `id`.
+    pub fn rulings_by_multiverse_id(&self, id: u32) -> Result<List<Ruling>, Error> {
+        self.block_on(self.client.rulings_by_multiverse_id(id))
+    }
+
+    /// The rulings for the card with Magic Online ID `id`.
+    pub fn rulings_by_mtgo_id(&self, id: u32) -> Result<List<Ruling>, Error> {
+        self.block_on(self.client.rulings_by_mtgo_id(id))
+    }
+
+    /// The rulings for the card with MTG Arena ID `id`.
+    pub fn rulings_by_arena_id(&self, id: u32) -> Result<List<Ruling>, Error> {
+        self.block_on(self.client.rulings_by_arena_id(id))
+    }
+
+    /// The rulings for the printing with collector number `number` in the set with code `set`.
+    pub fn rulings_by_set_number(&self, set: &str, number: &str) -> Result<List<Ruling>, Error> {
+        self.block_on(self.client.rulings_by_set_number(set, number))
+    }
+
+    /// Every set on Scryfall, newest first.
+    pub fn sets(&self) -> Result<SetList, Error> {
+        self.block_on(self.client.sets())
+    }
+
+    /// The set with code `code`, e.g. `"blb"`. MTGO codes work too.
+    pub fn set_by_code(&self, code: &str) -> Result<Set, Error> {
+        self.block_on(self.client.set_by_code(code))
+    }
+
+    /// The set with Scryfall ID `id`.
+    pub fn set_by_id(&self, id: &str) -> Result<Set, Error> {
+        self.block_on(self.client.set_by_id(id))
+    }
+
+    /// The set with TCGplayer group ID `id`.
+    pub fn set_by_tcgplayer_id(&self, id: u32) -> Result<Set, Error> {
+        self.block_on(self.client.set_by_tcgplayer_id(id))
+    }
+
+    /// Every symbol that can appear in a card's costs or text.
+    pub fn symbology(&self) -> Result<List<CardSymbol>, Error> {
+        self.block_on(self.client.symbology())
+    }
+
+    /// Scryfall's reading of the mana cost `cost`, normalized and with its mana value and colors. Loose input like
+    /// `"RUx"` is accepted; unreadable input fails with a `bad_request` error.
+    pub fn parse_mana(&self, cost: &str) -> Result<ManaCostInfo, Error> {
+        self.block_on(self.client.parse_mana(cost))
+    }
+
+    /// The catalog of `kind`, e.g. every creature type or every keyword ability.
+    pub fn catalog(&self, kind: CatalogKind) -> Result<Catalog, Error> {
+        self.block_on(self.client.catalog(kind))
+    }
+
+    /// Every bulk file Scryfall currently exports, with where to download it.
+    pub fn bulk_data(&self) -> Result<List<BulkData>, Error> {
+        self.block_on(self.client.bulk_data())
+    }
+
+    /// The bulk file of `kind`, with where to download it.
+    pub fn bulk_data_by_type(&self, kind: BulkKind) -> Result<BulkData, Error> {
+        self.block_on(self.client.bulk_data_by_type(kind))
+    }
+
+    /// Downloads the bulk file `D`, decoding entries one at a time as they arrive so even the multi-gigabyte
+    /// `all_cards` file is never held in memory. The download starts before this returns, and read errors surface
+    /// from the iterator.
+    ///
+    /// ```no_run
+    /// # fn example() -> Result<(), scryfall::client::Error> {
+    /// use scryfall::bulk::AllCards;
+    ///
+    /// let client = scryfall::client::blocking::Client::new();
+    /// for card in client.download_bulk::<AllCards>()? {
+    ///     println!("{}", card.unwrap().name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn download_bulk<D: Dataset>(
+        &self,
+    ) -> Result<BulkReader<D::Item, reqwest::blocking::Response>, Error> {
+        Ok(bulk::read::<D, _>(self.open_bulk(D::KIND)?))
+    }
+
+    /// The bulk file of `kind` as it downloads, without decoding it, e.g. to save it to disk. The download starts
+    /// before this returns, and read errors surface from the response.
+    pub fn open_bulk(&self, kind: BulkKind) -> Result<reqwest::blocking::Response, Error> {
+        let bulk_data = self.bulk_data_by_type(kind)?;
+        self.block_on(self.client.wait_turn());
+        let mut builder = self.http.get(bulk_data.download_uri.as_str());
+        for (name, value) in &self.client.inner.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        let response = builder.send()?;
+        let status = response.status().as_u16();
+        if !(200..300).contains(&status) {
+            return Err(Error::Status(status));
+        }
+
+        Ok(response)
+    }
+
+    /// The emblems `card` creates. They're fetched from the card's related cards where Scryfall lists them, and
+    /// otherwise, for a planeswalker whose text mentions an emblem, searched for by its planeswalker type, which can
+    /// also turn up emblems made by other cards of the same planeswalker.
+    pub fn emblems(&self, card: &Card) -> Result<Vec<Card>, Error> {
+        self.block_on(self.client.emblems(card))
+    }
+
+    /// Every dungeon card, such as those from Adventures in the Forgotten Realms and the Undercity.
+    pub fn dungeons(&self) -> Result<Vec<Card>, Error> {
+        self.block_on(self.client.dungeons())
+    }
+
+    /// An iterator over the items of `stream`, driven on this client's runtime.
+    fn iter<T, S>(&self, stream: S) -> PagedIter<T>
+    where
+        S: Stream<Item = Result<T, Error>> + Send + 'static,
+    {
+        PagedIter {
+            client: self.clone(),
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+/// An iterator over the items of a paginated list, fetching each page as the previous one runs out. Stops after the
+/// first error.
+pub struct PagedIter<T> {
+    client: Client,
+    stream: Pin<Box<dyn Stream<Item = Result<T, Error>> + Send>>,
+}
+
+impl<T> Iterator for PagedIter<T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.client.block_on(self.stream.next())
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_card_by_id() {
+        use super::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        // The blocking client can't run on the async test's runtime, so drive it from a blocking thread.
+        let uri = server.uri();
+        let name = tokio::task::spawn_blocking(move || {
+            Client::with_base_url(&uri)
+                .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+                .map(|card| card.name)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!("Lightning Bolt", name);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_fetch() {
+        use super::Client;
+        use crate::testing;
+        use crate::types::card::Card;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let name = tokio::task::spawn_blocking(move || {
+            Client::with_base_url(&uri)
+                .fetch::<Card>(&testing::card().uri)
+                .map(|card| card.name)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!("Lightning Bolt", name);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_download_bulk() {
+        use super::Client;
+        use crate::bulk::OracleCards;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bulk-data/oracle_cards"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "bulk_data",
+                "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+                "type": "oracle_cards",
+                "updated_at": "2024-08-01T09:02:49.367+00:00",
+                "uri": format!("{}/bulk-data/27bf3214-1271-490b-bdfe-c0be6c23d02e", server.uri()),
+                "name": "Oracle Cards",
+                "description": "A JSON file containing one Scryfall card object for each Oracle ID on Scryfall.",
+                "size": 160955958,
+                "download_uri": format!("{}/oracle-cards.json", server.uri()),
+                "content_type": "application/json",
+                "content_encoding": "gzip",
+            })))
+            .mount(&server)
+            .await;
+        let bolt = include_str!("../../testdata/cards/lightning_bolt.json");
+        Mock::given(method("GET"))
+            .and(path("/oracle-cards.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(format!("[\n{}\n]", bolt)))
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let names = tokio::task::spawn_blocking(move || {
+            Client::with_base_url(&uri)
+                .download_bulk::<OracleCards>()
+                .unwrap()
+                .map(|card| card.unwrap().name)
+                .collect::<Vec<_>>()
+        })
+        .await
+        .unwrap();
+        assert_eq!(vec!["Lightning Bolt"], names);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_search_iter() {
+        use super::Client;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let bolt: serde_json::Value =
+            serde_json::from_str(include_str!("../../testdata/cards/lightning_bolt.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 2,
+                "has_more": false,
+                "data": [bolt],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "t:goblin"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 2,
+                "has_more": true,
+                "next_page": "https://api.scryfall.com/cards/search?q=t%3Agoblin&page=2",
+                "data": [bolt],
+            })))
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let names = tokio::task::spawn_blocking(move || {
+            Client::with_base_url(&uri)
+                .search_iter("t:goblin")
+                .map(|card| card.unwrap().name)
+                .collect::<Vec<_>>()
+        })
+        .await
+        .unwrap();
+        assert_eq!(vec!["Lightning Bolt", "Lightning Bolt"], names);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_timeout() {
+        use super::Client;
+        use crate::client::Error;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json"))
+                    .set_delay(Duration::from_millis(300)),
+            )
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let (timed_out, unbounded) = tokio::task::spawn_blocking(move || {
+            let client = Client::with_base_url(&uri);
+            let timed_out = client
+                .timeout(Duration::from_millis(20))
+                .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d");
+            let unbounded = client.card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d");
+            (timed_out, unbounded)
+        })
+        .await
+        .unwrap();
+        assert!(matches!(timed_out, Err(Error::Timeout)));
+        assert!(unbounded.is_ok());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_transport() {
+        use crate::client::clock::{FixedJitter, VirtualClock};
+        use crate::client::fault::{Fault, FaultInjector};
+        use crate::client::transport::ReqwestTransport;
+        use crate::client::ClientBuilder;
+        use std::sync::Arc;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        // The blocking client goes through the configured transport, clock and retries like the async one.
+        let clock = Arc::new(VirtualClock::new());
+        let faults =
+            Arc::new(FaultInjector::new(ReqwestTransport::new()).fail_next(Fault::Status(503)));
+        let builder = ClientBuilder::new()
+            .base_url(&server.uri())
+            .transport(Arc::clone(&faults))
+            .clock(Arc::clone(&clock))
+            .jitter(FixedJitter(1.0));
+        let name = tokio::task::spawn_blocking(move || {
+            builder
+                .build_blocking()
+                .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+                .map(|card| card.name)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!("Lightning Bolt", name);
+        assert_eq!(2, faults.requests());
+        assert_eq!(vec![Duration::from_millis(500)], clock.sleeps());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_dry_run() {
+        use super::Client;
+
+        let url = tokio::task::spawn_blocking(|| {
+            Client::new()
+                .dry_run(|client| client.set_by_code("blb"))
+                .unwrap()
+                .unwrap()
+                .url
+        })
+        .await
+        .unwrap();
+        assert_eq!("https://api.scryfall.com/sets/blb", url);
+    }
+}
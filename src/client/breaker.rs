@@ -0,0 +1,263 @@
+//! A [`Transport`] decorator that stops sending requests while Scryfall is down, answering from the last good copy
+//! of each response instead, so a user-facing bot can keep answering through an outage.
+//!
+//! ```no_run
+//! use scryfall::client::breaker::CircuitBreaker;
+//! use scryfall::client::transport::ReqwestTransport;
+//! use scryfall::client::Client;
+//! use scryfall::storage::FsStorage;
+//! use std::time::Duration;
+//!
+//! # fn example() -> std::io::Result<()> {
+//! let breaker = CircuitBreaker::new(ReqwestTransport::new(), FsStorage::new("scryfall-stale")?)
+//!     .threshold(3)
+//!     .cooldown(Duration::from_secs(60));
+//! let client = Client::with_transport(breaker);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::clock::{Clock, SystemClock};
+use super::error::Error;
+use super::transport::{
+    HttpRequest, HttpResponse, Method, ResponseFuture, ResponseSource, Transport,
+};
+use crate::storage::Storage;
+use sha1::{Digest, Sha1};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The prefix of the storage keys the breaker uses, followed by a hash of the request URL.
+const KEY_PREFIX: &str = "stale/";
+
+/// Wraps a transport, keeping the last successful response to every `GET` in `storage`. After `threshold` failures in
+/// a row, where a failure is a 5xx status or no response at all, the breaker opens: for the next `cooldown` no
+/// requests are sent, and `GET`s are answered with their stored copy, marked [`ResponseSource::Stale`]. Requests with
+/// nothing stored fail with [`Error::Unavailable`]. Once the cooldown is over, the next request is sent as a trial;
+/// success closes the breaker, and failure opens it for another cooldown.
+///
+/// The failure that opens the breaker is answered from storage too, but failures before then are returned as they
+/// are, so the client's retries still apply to them.
+#[derive(Debug)]
+pub struct CircuitBreaker<T, S> {
+    inner: T,
+    storage: S,
+    threshold: u32,
+    cooldown: Duration,
+    clock: Arc<dyn Clock>,
+    state: Mutex<State>,
+    stale: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Failures since the last success.
+    failures: u32,
+
+    /// When the breaker closes again, if it's open.
+    open_until: Option<Instant>,
+}
+
+impl<T: Transport, S: Storage + Debug> CircuitBreaker<T, S> {
+    /// A breaker in front of `inner`, storing responses in `storage` under keys starting with `stale/`. It opens after
+    /// 5 failures in a row, for 30 seconds.
+    pub fn new(inner: T, storage: S) -> Self {
+        CircuitBreaker {
+            inner,
+            storage,
+            threshold: 5,
+            cooldown: Duration::from_secs(30),
+            clock: Arc::new(SystemClock),
+            state: Mutex::new(State::default()),
+            stale: AtomicU64::new(0),
+        }
+    }
+
+    /// How many failures in a row open the breaker.
+    ///
+    /// # Panics
+    ///
+    /// If `failures` is zero.
+    pub fn threshold(mut self, failures: u32) -> Self {
+        assert!(failures > 0, "the threshold needs at least one failure");
+        self.threshold = failures;
+        self
+    }
+
+    /// How long the breaker stays open before trying Scryfall again.
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Tells time with `clock` instead of the system clock.
+    pub fn clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Whether the breaker is open, so requests aren't being sent.
+    pub fn is_open(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state
+            .open_until
+            .is_some_and(|until| self.clock.now() < until)
+    }
+
+    /// How many stale responses have been served from storage.
+    pub fn stale_served(&self) -> u64 {
+        self.stale.load(Ordering::SeqCst)
+    }
+
+    /// Counts a response or failure, returning whether it opened the breaker.
+    fn record(&self, failed: bool) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if !failed {
+            *state = State::default();
+            return false;
+        }
+
+        state.failures += 1;
+        if state.failures >= self.threshold {
+            state.open_until = Some(self.clock.now() + self.cooldown);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The stored copy of the response to `request`, marked stale, or [`Error::Unavailable`] if there isn't one.
+    /// Storage errors count as there being no copy.
+    fn stale(&self, request: &HttpRequest) -> Result<HttpResponse, Error> {
+        if request.method != Method::Get {
+            return Err(Error::Unavailable);
+        }
+
+        match self.storage.get(&key(&request.url)) {
+            Ok(Some(body)) => {
+                self.stale.fetch_add(1, Ordering::SeqCst);
+                Ok(HttpResponse {
+                    status: 200,
+                    headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+                    body,
+                    source: ResponseSource::Stale,
+                })
+            }
+            Ok(None) | Err(_) => Err(Error::Unavailable),
+        }
+    }
+}
+
+fn key(url: &str) -> String {
+    let digest = Sha1::digest(url.as_bytes());
+    let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}{}", KEY_PREFIX, hex)
+}
+
+impl<T: Transport, S: Storage + Debug> Transport for CircuitBreaker<T, S> {
+    fn send(&self, request: HttpRequest) -> ResponseFuture<'_> {
+        Box::pin(async move {
+            if self.is_open() {
+                return self.stale(&request);
+            }
+
+            let response = self.inner.send(request.clone()).await;
+            let failed = match &response {
+                Ok(response) => response.status >= 500,
+                Err(_) => true,
+            };
+            if self.record(failed) {
+                return self.stale(&request).or(response);
+            }
+
+            if let Ok(response) = &response {
+                if response.status == 200 && request.method == Method::Get {
+                    // A copy that can't be stored only means there's less to fall back on.
+                    let _ = self.storage.put(&key(&request.url), &response.body);
+                }
+            }
+            response
+        })
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_circuit_breaker() {
+        use super::CircuitBreaker;
+        use crate::client::clock::VirtualClock;
+        use crate::client::fault::{Fault, FaultInjector};
+        use crate::client::transport::{
+            HttpRequest, Method, ReqwestTransport, ResponseSource, Transport,
+        };
+        use crate::client::Error;
+        use crate::storage::{MemoryStorage, Storage};
+        use std::sync::Arc;
+        use std::time::Duration;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+            .mount(&server)
+            .await;
+
+        let faults = Arc::new(
+            FaultInjector::new(ReqwestTransport::new())
+                .fail_next(Fault::Status(503))
+                .fail_next(Fault::Timeout),
+        );
+        let clock = Arc::new(VirtualClock::new());
+        let storage = Arc::new(MemoryStorage::new());
+        let breaker = CircuitBreaker::new(Arc::clone(&faults), Arc::clone(&storage))
+            .threshold(2)
+            .cooldown(Duration::from_secs(30))
+            .clock(Arc::clone(&clock));
+        let get = |path: &str| HttpRequest {
+            method: Method::Get,
+            url: format!("{}{}", server.uri(), path),
+            headers: Vec::new(),
+            body: None,
+            attempt: 1,
+        };
+
+        // The first failure passes through.
+        storage
+            .put(&super::key(&get("/sets/blb").url), b"{}")
+            .unwrap();
+        assert_eq!(503, breaker.send(get("/sets/blb")).await.unwrap().status);
+        assert!(!breaker.is_open());
+
+        // The second opens the breaker, and is answered with the stored copy.
+        let stale = breaker.send(get("/sets/blb")).await.unwrap();
+        assert_eq!(ResponseSource::Stale, stale.source);
+        assert_eq!(b"{}".to_vec(), stale.body);
+        assert!(breaker.is_open());
+
+        // While it's open nothing is sent, and there's nothing stored for other URLs.
+        let sent = faults.requests();
+        assert_eq!(
+            ResponseSource::Stale,
+            breaker.send(get("/sets/blb")).await.unwrap().source
+        );
+        assert!(matches!(
+            breaker.send(get("/sets/mh3")).await,
+            Err(Error::Unavailable)
+        ));
+        assert_eq!(sent, faults.requests());
+        assert_eq!(2, breaker.stale_served());
+
+        // After the cooldown a trial request goes through and closes it.
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(
+            ResponseSource::Network,
+            breaker.send(get("/sets/mh3")).await.unwrap().source
+        );
+        assert!(!breaker.is_open());
+        assert_eq!(2, storage.scan("stale/").unwrap().len());
+    }
+}
@@ -0,0 +1,497 @@
+use super::clock::{Clock, Jitter, SystemClock};
+use super::etag::EtagCache;
+use super::rate_limit::{RateLimiter, DEFAULT_INTERVAL};
+use super::retry::RetryPolicy;
+use super::transport::{ReqwestTransport, Transport};
+use super::{Client, Inner, BASE_URL, DEFAULT_USER_AGENT};
+use crate::cache::QueryCache;
+use crate::storage::{FsStorage, Storage};
+use std::env;
+use std::error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Configures a [`Client`] before it's created, for anything beyond the defaults of [`Client::new`].
+///
+/// ```no_run
+/// use scryfall::client::ClientBuilder;
+/// use std::time::Duration;
+///
+/// let client = ClientBuilder::new()
+///     .base_url("https://scryfall-mirror.example.com")
+///     .max_attempts(5)
+///     .timeout(Duration::from_secs(10))
+///     .user_agent("deckbuilder/1.0 (+https://deckbuilder.example.com)")
+///     .retry_backoff(Duration::from_secs(1))
+///     .build();
+/// ```
+#[derive(Debug, Default)]
+pub struct ClientBuilder {
+    base_url: Option<String>,
+    transport: Option<Box<dyn Transport>>,
+    retry: RetryPolicy,
+    rate_limit: Option<(Duration, u32)>,
+    clock: Option<Arc<dyn Clock>>,
+    cache: Option<FsStorage>,
+    query_cache: Option<QueryCache<Box<dyn Storage>>>,
+    user_agent: Option<String>,
+    headers: Vec<(String, String)>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+}
+
+impl ClientBuilder {
+    /// A builder with the same defaults as [`Client::new`].
+    pub fn new() -> Self {
+        ClientBuilder::default()
+    }
+
+    /// A builder configured from the environment, so deployments can tune the client without code changes. Each
+    /// variable that's set overrides the default:
+    ///
+    /// - `SCRYFALL_BASE_URL`: the API to send requests to, as for [`base_url`](Self::base_url).
+    /// - `SCRYFALL_USER_AGENT`: the `User-Agent` header, as for [`user_agent`](Self::user_agent).
+    /// - `SCRYFALL_CACHE_DIR`: a directory to cache responses in, as for [`cache_dir`](Self::cache_dir).
+    /// - `SCRYFALL_RATE_LIMIT_MS`: the milliseconds between requests, as for [`rate_limit`](Self::rate_limit).
+    /// - `SCRYFALL_RATE_LIMIT_BURST`: how many requests may be sent at once before the interval applies. Defaults to
+    ///   1.
+    ///
+    /// Fails if a variable's value can't be used, or the cache directory can't be created.
+    pub fn from_env() -> Result<Self, EnvError> {
+        ClientBuilder::from_vars(|name| env::var(name).ok())
+    }
+
+    /// [`from_env`](Self::from_env), with the variables looked up by `var`.
+    fn from_vars<F: Fn(&str) -> Option<String>>(var: F) -> Result<Self, EnvError> {
+        let invalid = |name: &'static str, value: String| EnvError::Invalid { name, value };
+        let mut builder = ClientBuilder::new();
+
+        if let Some(base_url) = var("SCRYFALL_BASE_URL") {
+            builder = builder.base_url(&base_url);
+        }
+        if let Some(user_agent) = var("SCRYFALL_USER_AGENT") {
+            if http::HeaderValue::from_str(&user_agent).is_err() {
+                return Err(invalid("SCRYFALL_USER_AGENT", user_agent));
+            }
+            builder = builder.user_agent(&user_agent);
+        }
+        if let Some(dir) = var("SCRYFALL_CACHE_DIR") {
+            builder = builder.cache_dir(dir).map_err(EnvError::CacheDir)?;
+        }
+
+        let interval = match var("SCRYFALL_RATE_LIMIT_MS") {
+            Some(millis) => match millis.trim().parse() {
+                Ok(millis) => Some(Duration::from_millis(millis)),
+                Err(_) => return Err(invalid("SCRYFALL_RATE_LIMIT_MS", millis)),
+            },
+            None => None,
+        };
+        let burst = match var("SCRYFALL_RATE_LIMIT_BURST") {
+            Some(burst) => match burst.trim().parse() {
+                Ok(count) if count > 0 => Some(count),
+                _ => return Err(invalid("SCRYFALL_RATE_LIMIT_BURST", burst)),
+            },
+            None => None,
+        };
+        if interval.is_some() || burst.is_some() {
+            builder = builder.rate_limit(interval.unwrap_or(DEFAULT_INTERVAL), burst.unwrap_or(1));
+        }
+
+        Ok(builder)
+    }
+
+    /// Sends requests to the Scryfall-compatible API at `base_url` instead of api.scryfall.com, such as a mock server
+    /// in tests or a mirror behind a corporate proxy. Paths are appended to it, so it may include a path prefix.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Sends requests through `transport` instead of reqwest.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// The most times a request is sent when it's rate limited or hits a transient server error, including the first
+    /// try. Defaults to 3; 1 disables retries.
+    ///
+    /// # Panics
+    ///
+    /// If `attempts` is zero.
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        assert!(attempts > 0, "max_attempts needs at least one attempt");
+        self.retry.max_attempts = attempts;
+        self
+    }
+
+    /// The wait before the first retry, doubled for each retry after it and jittered. Defaults to half a second. A
+    /// `Retry-After` header from Scryfall takes precedence.
+    pub fn retry_backoff(mut self, delay: Duration) -> Self {
+        self.retry.base_delay = delay;
+        self
+    }
+
+    /// Caches responses by their `ETag` in `dir`, which is created if it doesn't exist, by putting an
+    /// [`EtagCache`] in front of the transport.
+    pub fn cache_dir<P: AsRef<Path>>(mut self, dir: P) -> io::Result<Self> {
+        self.cache = Some(FsStorage::new(dir)?);
+        Ok(self)
+    }
+
+    /// Keeps the decoded results of [`Client::search_cached`] in `cache`, so repeating a search, even an equivalent
+    /// one spelled differently, skips Scryfall until the entry expires.
+    pub fn query_cache<S: Storage + 'static>(mut self, cache: QueryCache<S>) -> Self {
+        self.query_cache = Some(cache.boxed());
+        self
+    }
+
+    /// Where the randomness that spreads out retries comes from. Defaults to the thread's random number generator;
+    /// [`FixedJitter`](super::clock::FixedJitter) makes backoff predictable in tests.
+    pub fn jitter<J: Jitter + 'static>(mut self, jitter: J) -> Self {
+        self.retry.jitter = Arc::new(jitter);
+        self
+    }
+
+    /// Allows bursts of up to `burst` requests, then one more every `interval`. Defaults to one request every
+    /// [`DEFAULT_INTERVAL`], as Scryfall asks.
+    pub fn rate_limit(mut self, interval: Duration, burst: u32) -> Self {
+        self.rate_limit = Some((interval, burst));
+        self
+    }
+
+    /// The clock the rate limiter and retries tell time and wait with, instead of the system clock. With a
+    /// [`VirtualClock`](super::clock::VirtualClock), tests can check throttling and backoff without waiting for them.
+    /// Timeouts are still measured in real time.
+    pub fn clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// The `User-Agent` header sent with every request. Scryfall asks that it identify your application; it defaults to
+    /// [`DEFAULT_USER_AGENT`], which only identifies this crate.
+    ///
+    /// # Panics
+    ///
+    /// If `user_agent` isn't a valid header value.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        assert!(
+            http::HeaderValue::from_str(user_agent).is_ok(),
+            "invalid User-Agent: {:?}",
+            user_agent
+        );
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Sends the header `name` with every request, replacing any default header of the same name set before. Headers
+    /// that a request sets itself, such as `Accept`, take precedence.
+    ///
+    /// # Panics
+    ///
+    /// If `name` or `value` isn't valid in a header.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        assert!(
+            http::header::HeaderName::from_bytes(name.as_bytes()).is_ok(),
+            "invalid header name: {:?}",
+            name
+        );
+        assert!(
+            http::HeaderValue::from_str(value).is_ok(),
+            "invalid value for header {}: {:?}",
+            name,
+            value
+        );
+        self.headers
+            .retain(|(key, _)| !key.eq_ignore_ascii_case(name));
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// How long to wait for a connection to Scryfall before failing with [`Error::Timeout`](super::Error::Timeout).
+    /// This only applies to the default transport.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// How long a call may take, including retries and waiting for the rate limiter, before it fails with
+    /// [`Error::Timeout`](super::Error::Timeout). Calls aren't bounded by default. [`Client::timeout`] overrides this
+    /// for individual calls. Bulk downloads aren't bounded by it.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The headers sent with every request: the `User-Agent`, then the ones added with [`header`](Self::header),
+    /// which may replace it.
+    fn default_headers(&self) -> Vec<(String, String)> {
+        let user_agent = self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
+        let mut headers = vec![("User-Agent".to_string(), user_agent.to_string())];
+        headers.retain(|(key, _)| {
+            !self
+                .headers
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case(key))
+        });
+        headers.extend(self.headers.iter().cloned());
+        headers
+    }
+
+    /// The async client.
+    pub fn build(mut self) -> Client {
+        let headers = self.default_headers();
+        let mut transport = match self.transport.take() {
+            Some(transport) => transport,
+            None => {
+                let mut http = reqwest::Client::builder();
+                if let Some(timeout) = self.connect_timeout {
+                    http = http.connect_timeout(timeout);
+                }
+                let http = http.build().expect("failed to create the HTTP client");
+                Box::new(ReqwestTransport::from(http))
+            }
+        };
+        if let Some(storage) = self.cache.take() {
+            transport = Box::new(EtagCache::new(transport, storage));
+        }
+        let clock = self.clock.unwrap_or_else(|| Arc::new(SystemClock));
+        let (interval, burst) = self.rate_limit.unwrap_or((DEFAULT_INTERVAL, 1));
+        Client {
+            timeout: None,
+            dry_run: None,
+            meta: None,
+            tenant: None,
+            inner: Arc::new(Inner {
+                transport,
+                base_url: self.base_url.unwrap_or_else(|| BASE_URL.to_string()),
+                rate_limiter: RateLimiter::with_clock(interval, burst, Arc::clone(&clock)),
+                retry: self.retry,
+                clock,
+                headers,
+                timeout: self.timeout,
+                query_cache: self.query_cache,
+            }),
+        }
+    }
+
+    /// The blocking client. It makes its calls through the same transport, retries and rate limiter as the async
+    /// client would; only bulk downloads bypass the transport, since they're streamed.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> super::blocking::Client {
+        let mut http = reqwest::blocking::Client::builder();
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        let http = http.build().expect("failed to create the HTTP client");
+        super::blocking::Client::from_async(self.build(), http)
+    }
+}
+
+/// Why [`ClientBuilder::from_env`] couldn't configure a client.
+#[derive(Debug)]
+pub enum EnvError {
+    /// The variable `name` is set to `value`, which can't be used.
+    Invalid { name: &'static str, value: String },
+
+    /// The cache directory couldn't be created.
+    CacheDir(io::Error),
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EnvError::Invalid { name, value } => {
+                write!(f, "invalid value for {}: {:?}", name, value)
+            }
+            EnvError::CacheDir(err) => write!(f, "failed to create the cache directory: {}", err),
+        }
+    }
+}
+
+impl error::Error for EnvError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            EnvError::Invalid { .. } => None,
+            EnvError::CacheDir(err) => Some(err),
+        }
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_base_url() {
+        use super::ClientBuilder;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/mirror/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ClientBuilder::new()
+            .base_url(&format!("{}/mirror/", server.uri()))
+            .build();
+        let card = client
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await
+            .unwrap();
+        assert_eq!("Lightning Bolt", card.name);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_base_url_pages() {
+        use super::ClientBuilder;
+        use futures_util::TryStreamExt;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let bolt: serde_json::Value =
+            serde_json::from_str(include_str!("../../testdata/cards/lightning_bolt.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/mirror/cards/search"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": false,
+                "data": [bolt],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/mirror/cards/search"))
+            .and(query_param("q", "t:instant"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": true,
+                "next_page": format!("{}/mirror/cards/search?q=t%3Ainstant&page=2", server.uri()),
+                "data": [bolt],
+            })))
+            .mount(&server)
+            .await;
+
+        // The mirror's links include its path prefix, which mustn't be added to them again.
+        let client = ClientBuilder::new()
+            .base_url(&format!("{}/mirror", server.uri()))
+            .build();
+        let cards: Vec<_> = client.search_all("t:instant").try_collect().await.unwrap();
+        assert_eq!(2, cards.len());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_headers() {
+        use super::ClientBuilder;
+        use crate::client::DEFAULT_USER_AGENT;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .and(header("User-Agent", DEFAULT_USER_AGENT))
+            .and(header("Accept", "application/json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .and(header("User-Agent", "deckbuilder/1.0"))
+            .and(header("X-Team", "red"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ClientBuilder::new().base_url(&server.uri()).build();
+        assert!(client
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await
+            .is_ok());
+
+        let client = ClientBuilder::new()
+            .base_url(&server.uri())
+            .user_agent("deckbuilder/1.0")
+            .header("X-Team", "red")
+            .build();
+        assert!(client
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await
+            .is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid header name")]
+    fn test_invalid_header() {
+        use super::ClientBuilder;
+
+        let _ = ClientBuilder::new().header("X Team", "red");
+    }
+
+    #[test]
+    fn test_from_vars() {
+        use super::{ClientBuilder, EnvError};
+        use std::collections::HashMap;
+        use std::time::Duration;
+
+        let dir = std::env::temp_dir().join(format!("scryfall-env-test-{}", std::process::id()));
+        let mut vars = HashMap::new();
+        vars.insert(
+            "SCRYFALL_BASE_URL",
+            "https://mirror.example.com/".to_string(),
+        );
+        vars.insert("SCRYFALL_USER_AGENT", "deckbuilder/1.0".to_string());
+        vars.insert("SCRYFALL_CACHE_DIR", dir.display().to_string());
+        vars.insert("SCRYFALL_RATE_LIMIT_MS", "250".to_string());
+        let builder = ClientBuilder::from_vars(|name| vars.get(name).cloned()).unwrap();
+        assert_eq!(
+            Some("https://mirror.example.com"),
+            builder.base_url.as_deref()
+        );
+        assert_eq!(Some("deckbuilder/1.0"), builder.user_agent.as_deref());
+        assert_eq!(
+            Some(dir.as_path()),
+            builder.cache.as_ref().map(|cache| cache.root())
+        );
+        assert_eq!(Some((Duration::from_millis(250), 1)), builder.rate_limit);
+        assert_eq!(
+            Duration::from_millis(250),
+            builder.build().rate_limiter().interval()
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let builder = ClientBuilder::from_vars(|_| None).unwrap();
+        assert_eq!(None, builder.base_url);
+        assert_eq!(None, builder.rate_limit);
+
+        let err = ClientBuilder::from_vars(|name| {
+            Some("ten".to_string()).filter(|_| name == "SCRYFALL_RATE_LIMIT_BURST")
+        })
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            EnvError::Invalid {
+                name: "SCRYFALL_RATE_LIMIT_BURST",
+                ..
+            }
+        ));
+    }
+}
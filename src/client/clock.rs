@@ -0,0 +1,162 @@
+//! The time and randomness the client's rate limiting and retry backoff depend on. Both can be replaced through
+//! [`ClientBuilder`](super::ClientBuilder), so tests can check throttling and backoff against a [`VirtualClock`]
+//! instead of waiting in real time.
+//!
+//! ```
+//! # async fn example() {
+//! use scryfall::client::clock::{FixedJitter, VirtualClock};
+//! use scryfall::client::ClientBuilder;
+//! use std::sync::Arc;
+//!
+//! let clock = Arc::new(VirtualClock::new());
+//! let client = ClientBuilder::new()
+//!     .clock(Arc::clone(&clock))
+//!     .jitter(FixedJitter(1.0))
+//!     .build();
+//! # }
+//! ```
+
+use rand::Rng;
+use std::fmt::Debug;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The future returned by [`Clock::sleep`].
+pub type SleepFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+/// A source of the current time and of waiting. Clocks are shared by every clone of a client, so they must be
+/// `Send + Sync`.
+pub trait Clock: Debug + Send + Sync {
+    /// The current time.
+    fn now(&self) -> Instant;
+
+    /// Waits for `duration`.
+    fn sleep(&self, duration: Duration) -> SleepFuture<'_>;
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn sleep(&self, duration: Duration) -> SleepFuture<'_> {
+        (**self).sleep(duration)
+    }
+}
+
+/// The real clock, waiting with tokio's timer. This is the default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> SleepFuture<'_> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// A clock whose time only moves when it's told to. Sleeping returns at once, after moving the clock forward by the
+/// duration of the sleep. Every sleep is recorded for tests to check.
+#[derive(Debug)]
+pub struct VirtualClock {
+    start: Instant,
+    state: Mutex<VirtualState>,
+}
+
+#[derive(Debug, Default)]
+struct VirtualState {
+    elapsed: Duration,
+    sleeps: Vec<Duration>,
+}
+
+impl VirtualClock {
+    /// A clock starting at the current real time.
+    pub fn new() -> Self {
+        VirtualClock {
+            start: Instant::now(),
+            state: Mutex::new(VirtualState::default()),
+        }
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.state.lock().unwrap().elapsed += duration;
+    }
+
+    /// How far the clock has moved since it was created.
+    pub fn elapsed(&self) -> Duration {
+        self.state.lock().unwrap().elapsed
+    }
+
+    /// The duration of every sleep so far, in the order they started.
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.state.lock().unwrap().sleeps.clone()
+    }
+}
+
+impl Default for VirtualClock {
+    fn default() -> Self {
+        VirtualClock::new()
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) -> SleepFuture<'_> {
+        let mut state = self.state.lock().unwrap();
+        state.sleeps.push(duration);
+        state.elapsed += duration;
+        Box::pin(future::ready(()))
+    }
+}
+
+/// A source of the randomness that spreads out retries, so that many clients failing together don't all retry
+/// together.
+pub trait Jitter: Debug + Send + Sync {
+    /// A number from 0 to 1, inclusive.
+    fn sample(&self) -> f64;
+}
+
+/// Jitter from the thread's random number generator. This is the default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RandomJitter;
+
+impl Jitter for RandomJitter {
+    fn sample(&self) -> f64 {
+        rand::thread_rng().gen_range(0.0..=1.0)
+    }
+}
+
+/// Jitter that's always the same number, for predictable backoff in tests.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedJitter(pub f64);
+
+impl Jitter for FixedJitter {
+    fn sample(&self) -> f64 {
+        self.0
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_virtual_clock() {
+        use super::{Clock, VirtualClock};
+        use std::time::Duration;
+
+        let clock = VirtualClock::new();
+        let start = clock.now();
+        clock.sleep(Duration::from_secs(2)).await;
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(Duration::from_secs(3), clock.now() - start);
+        assert_eq!(vec![Duration::from_secs(2)], clock.sleeps());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::query::QueryError;
+use crate::types::error::Error as ApiError;
+use std::error;
+use std::fmt;
+
+/// Ways a call to the API can fail.
+#[derive(Debug)]
+pub enum Error {
+    /// The request couldn't be sent or the response couldn't be read.
+    Http(reqwest::Error),
+
+    /// No response arrived in time.
+    Timeout,
+
+    /// Scryfall answered with an error object, e.g. a 404 for an unknown card.
+    Api(ApiError),
+
+    /// Scryfall answered with an error status but no error object, e.g. from a proxy in between.
+    Status(u16),
+
+    /// The response wasn't the JSON that was expected.
+    Json(serde_json::Error),
+
+    /// The search query is invalid, so it was never sent.
+    Query(QueryError),
+
+    /// Scryfall has been failing, so a circuit breaker stopped the request from being sent, and there was no stored
+    /// copy to answer with instead.
+    Unavailable,
+
+    /// The call was made on the handle given to [`Client::dry_run`](super::Client::dry_run), which stops calls before
+    /// their first request is sent.
+    DryRun,
+}
+
+impl Error {
+    /// The HTTP status of the response, if one was received.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::Http(err) => err.status().map(|status| status.as_u16()),
+            Error::Timeout => None,
+            Error::Api(err) => Some(err.status),
+            Error::Status(status) => Some(*status),
+            Error::Json(_) | Error::Query(_) | Error::Unavailable | Error::DryRun => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Http(err) => write!(f, "request failed: {}", err),
+            Error::Timeout => write!(f, "request timed out"),
+            Error::Api(err) => write!(f, "Scryfall returned an error: {}", err),
+            Error::Status(status) => write!(f, "Scryfall returned HTTP {}", status),
+            Error::Json(err) => write!(f, "failed to decode response: {}", err),
+            Error::Query(err) => write!(f, "invalid query: {}", err),
+            Error::Unavailable => write!(f, "Scryfall is unavailable"),
+            Error::DryRun => write!(f, "request not sent in a dry run"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Http(err) => Some(err),
+            Error::Timeout => None,
+            Error::Api(err) => Some(err),
+            Error::Status(_) => None,
+            Error::Json(err) => Some(err),
+            Error::Query(err) => Some(err),
+            Error::Unavailable | Error::DryRun => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Error::Timeout
+        } else {
+            Error::Http(err)
+        }
+    }
+}
+
+impl From<QueryError> for Error {
+    fn from(err: QueryError) -> Self {
+        Error::Query(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
+/// Decodes a response body with status `status` into `T`, or into the error it describes.
+pub(crate) fn decode<T: serde::de::DeserializeOwned>(status: u16, body: &[u8]) -> Result<T, Error> {
+    if (200..300).contains(&status) {
+        return Ok(serde_json::from_slice(body)?);
+    }
+
+    match serde_json::from_slice::<ApiError>(body) {
+        Ok(err) => Err(Error::Api(err)),
+        Err(_) => Err(Error::Status(status)),
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_decode() {
+        use super::{decode, Error};
+
+        assert_eq!(3, decode::<u32>(200, b"3").unwrap());
+        assert!(matches!(
+            decode::<u32>(200, b"\"three\""),
+            Err(Error::Json(_))
+        ));
+        assert!(matches!(
+            decode::<u32>(503, b"<html>"),
+            Err(Error::Status(503))
+        ));
+
+        let err = decode::<u32>(
+            404,
+            br#"{"object": "error", "code": "not_found", "status": 404, "details": "Nope."}"#,
+        )
+        .unwrap_err();
+        assert_eq!(Some(404), err.status());
+        assert!(matches!(err, Error::Api(_)));
+    }
+}
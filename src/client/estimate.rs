@@ -0,0 +1,56 @@
+use super::error::Error;
+use crate::types::list::CardList;
+
+/// How many cards Scryfall returns per page of search results.
+pub const PAGE_SIZE: u32 = 175;
+
+/// The size of a search's results, from its first page.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Estimate {
+    /// How many cards match across all pages.
+    pub total_cards: u32,
+
+    /// How many pages it takes to fetch them all.
+    pub pages: u32,
+}
+
+impl Estimate {
+    /// An estimate for `total_cards` results.
+    pub fn new(total_cards: u32) -> Self {
+        Estimate {
+            total_cards,
+            pages: total_cards.div_ceil(PAGE_SIZE),
+        }
+    }
+
+    /// Whether fetching every result would take more than `max_pages` requests, which is worth confirming before
+    /// iterating.
+    pub fn exceeds(&self, max_pages: u32) -> bool {
+        self.pages > max_pages
+    }
+
+    /// The estimate for the result of searching for a first page. A search with no results is an estimate of zero
+    /// rather than an error.
+    pub(crate) fn from_search(result: Result<CardList, Error>) -> Result<Self, Error> {
+        match result {
+            Ok(list) => Ok(Estimate::new(
+                list.total_cards.unwrap_or(list.data.len() as u32),
+            )),
+            Err(Error::Api(err)) if err.code == "not_found" => Ok(Estimate::new(0)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_estimate() {
+        use super::Estimate;
+
+        assert_eq!(0, Estimate::new(0).pages);
+        assert_eq!(1, Estimate::new(175).pages);
+        assert_eq!(2, Estimate::new(176).pages);
+        assert!(Estimate::new(1000).exceeds(5));
+        assert!(!Estimate::new(875).exceeds(5));
+    }
+}
@@ -0,0 +1,228 @@
+//! A [`Transport`] decorator that caches responses by their `ETag`, so refetching a card or set that hasn't changed
+//! costs Scryfall a `304 Not Modified` instead of the whole object.
+//!
+//! ```no_run
+//! use scryfall::client::etag::EtagCache;
+//! use scryfall::client::transport::ReqwestTransport;
+//! use scryfall::client::Client;
+//! use scryfall::storage::FsStorage;
+//!
+//! # fn example() -> std::io::Result<()> {
+//! let storage = FsStorage::new("scryfall-cache")?;
+//! let client = Client::with_transport(EtagCache::new(ReqwestTransport::new(), storage));
+//! # Ok(())
+//! # }
+//! ```
+
+use super::transport::{HttpRequest, Method, ResponseFuture, ResponseSource, Transport};
+use crate::storage::Storage;
+use sha1::{Digest, Sha1};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The prefix of the storage keys the cache uses, followed by the SHA-1 hash of the request URL in hex.
+const KEY_PREFIX: &str = "etag/";
+
+/// Wraps a transport and keeps the body and `ETag` of every successful `GET` response in `storage`. Later requests
+/// for the same URL send `If-None-Match`, and when Scryfall answers `304 Not Modified` the stored body is returned as
+/// a `200` instead. Other requests pass through untouched.
+///
+/// The cache never fails a request: storage errors are logged as warnings and treated as misses, and responses that
+/// can't be stored are still returned.
+#[derive(Debug)]
+pub struct EtagCache<T, S> {
+    inner: T,
+    storage: S,
+    hits: AtomicU64,
+}
+
+impl<T: Transport, S: Storage + Debug> EtagCache<T, S> {
+    /// A cache in front of `inner`, storing responses in `storage` under keys starting with `etag/` followed by a hash
+    /// of the URL, so they stay short whatever the query.
+    pub fn new(inner: T, storage: S) -> Self {
+        EtagCache {
+            inner,
+            storage,
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    /// How many responses have been served from storage after Scryfall confirmed they were unchanged.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::SeqCst)
+    }
+
+    /// The stored `ETag` and body for `url`, if any.
+    fn load(&self, url: &str) -> Option<(String, Vec<u8>)> {
+        let entry = match self.storage.get(&key(url)) {
+            Ok(entry) => entry?,
+            Err(err) => {
+                log::warn!("failed to read the cached response for {}: {}", url, err);
+                return None;
+            }
+        };
+        let split = entry.iter().position(|&byte| byte == b'\n')?;
+        let etag = String::from_utf8(entry[..split].to_vec()).ok()?;
+        Some((etag, entry[split + 1..].to_vec()))
+    }
+
+    fn store(&self, url: &str, etag: &str, body: &[u8]) {
+        let mut entry = Vec::with_capacity(etag.len() + 1 + body.len());
+        entry.extend_from_slice(etag.as_bytes());
+        entry.push(b'\n');
+        entry.extend_from_slice(body);
+        if let Err(err) = self.storage.put(&key(url), &entry) {
+            log::warn!("failed to cache the response for {}: {}", url, err);
+        }
+    }
+}
+
+fn key(url: &str) -> String {
+    let digest = Sha1::digest(url.as_bytes());
+    let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}{}", KEY_PREFIX, hex)
+}
+
+impl<T: Transport, S: Storage + Debug> Transport for EtagCache<T, S> {
+    fn send(&self, mut request: HttpRequest) -> ResponseFuture<'_> {
+        Box::pin(async move {
+            if request.method != Method::Get {
+                return self.inner.send(request).await;
+            }
+
+            let cached = self.load(&request.url);
+            if let Some((etag, _)) = &cached {
+                request
+                    .headers
+                    .retain(|(name, _)| !name.eq_ignore_ascii_case("If-None-Match"));
+                request
+                    .headers
+                    .push(("If-None-Match".to_string(), etag.clone()));
+            }
+
+            let mut response = self.inner.send(request.clone()).await?;
+            match (response.status, cached) {
+                (304, Some((_, body))) => {
+                    self.hits.fetch_add(1, Ordering::SeqCst);
+                    response.status = 200;
+                    response.body = body;
+                    response.source = ResponseSource::Cache;
+                }
+                (200, _) => {
+                    if let Some(etag) = response.header("ETag").filter(|etag| !etag.contains('\n'))
+                    {
+                        self.store(&request.url, etag, &response.body);
+                    }
+                }
+                _ => {}
+            }
+            Ok(response)
+        })
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_etag_cache() {
+        use super::EtagCache;
+        use crate::client::transport::ReqwestTransport;
+        use crate::client::Client;
+        use crate::storage::{MemoryStorage, Storage};
+        use std::sync::Arc;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let storage = Arc::new(MemoryStorage::new());
+        let cache = Arc::new(EtagCache::new(
+            ReqwestTransport::new(),
+            Arc::clone(&storage),
+        ));
+        let client = Client::builder()
+            .base_url(&server.uri())
+            .transport(Arc::clone(&cache))
+            .build();
+        for _ in 0..3 {
+            let card = client
+                .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+                .await
+                .unwrap();
+            assert_eq!("Lightning Bolt", card.name);
+        }
+        assert_eq!(2, cache.hits());
+        assert_eq!(
+            vec![super::key(&format!(
+                "{}/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d",
+                server.uri()
+            ))],
+            storage.scan("etag/").unwrap()
+        );
+    }
+
+    #[cfg(all(test, feature = "blocking"))]
+    #[tokio::test]
+    async fn test_blocking_etag_cache() {
+        use crate::client::ClientBuilder;
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/sets/blb"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sets/blb"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_string(include_str!("../../testdata/sets/bloomburrow.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = std::env::temp_dir().join(format!(
+            "scryfall-blocking-etag-test-{}",
+            std::process::id()
+        ));
+        let builder = ClientBuilder::new()
+            .base_url(&server.uri())
+            .cache_dir(&dir)
+            .unwrap();
+        let names = tokio::task::spawn_blocking(move || {
+            let client = builder.build_blocking();
+            (0..2)
+                .map(|_| client.set_by_code("blb").map(|set| set.name))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(vec!["Bloomburrow", "Bloomburrow"], names);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
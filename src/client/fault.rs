@@ -0,0 +1,330 @@
+//! A [`Transport`] decorator that injects failures, for testing how code copes with Scryfall misbehaving.
+//!
+//! ```no_run
+//! use scryfall::client::fault::{Fault, FaultInjector};
+//! use scryfall::client::transport::ReqwestTransport;
+//! use scryfall::client::Client;
+//!
+//! # async fn example() {
+//! let faults = FaultInjector::new(ReqwestTransport::new())
+//!     .fail_next(Fault::RateLimited { retry_after: Some(1) })
+//!     .fail_every(10, Fault::Timeout);
+//! let client = Client::with_transport(faults);
+//! assert!(client.card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d").await.is_err());
+//! # }
+//! ```
+
+use super::error::Error;
+use super::transport::{HttpRequest, HttpResponse, ResponseFuture, ResponseSource, Transport};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// A way for a request to fail.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Fault {
+    /// The request times out without reaching the server.
+    Timeout,
+
+    /// Scryfall rejects the request with a 429, as it does when clients send requests too quickly, optionally with a
+    /// `Retry-After` header of this many seconds.
+    RateLimited { retry_after: Option<u64> },
+
+    /// The server answers with this status and an empty body, like a failing proxy or load balancer would.
+    Status(u16),
+
+    /// The request goes through, but the response body is replaced with something that isn't JSON.
+    MalformedJson,
+
+    /// The request goes through, but only the first half of the response body arrives.
+    TruncatedBody,
+}
+
+/// Wraps a transport and makes some of its requests fail. Scripted faults from [`fail_next`](FaultInjector::fail_next)
+/// are used first, in order; after that, every `n`th request fails with the fault from
+/// [`fail_every`](FaultInjector::fail_every), if set. Every other request passes through untouched.
+#[derive(Debug)]
+pub struct FaultInjector<T> {
+    inner: T,
+    scripted: Mutex<VecDeque<Fault>>,
+    every: Option<(u64, Fault)>,
+    requests: AtomicU64,
+}
+
+impl<T: Transport> FaultInjector<T> {
+    /// A fault injector that passes everything through to `inner` until faults are added.
+    pub fn new(inner: T) -> Self {
+        FaultInjector {
+            inner,
+            scripted: Mutex::new(VecDeque::new()),
+            every: None,
+            requests: AtomicU64::new(0),
+        }
+    }
+
+    /// Fails the next request that isn't already scripted to fail with `fault`.
+    pub fn fail_next(self, fault: Fault) -> Self {
+        self.scripted.lock().unwrap().push_back(fault);
+        self
+    }
+
+    /// Fails every `n`th request with `fault`, counting from the first request sent.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero.
+    pub fn fail_every(mut self, n: u64, fault: Fault) -> Self {
+        assert!(n > 0, "fail_every needs a positive interval");
+        self.every = Some((n, fault));
+        self
+    }
+
+    /// How many requests have been sent through this injector, including failed ones.
+    pub fn requests(&self) -> u64 {
+        self.requests.load(Ordering::SeqCst)
+    }
+
+    /// The fault for the next request, if it should fail.
+    fn next_fault(&self) -> Option<Fault> {
+        let count = self.requests.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(fault) = self.scripted.lock().unwrap().pop_front() {
+            return Some(fault);
+        }
+
+        match &self.every {
+            Some((n, fault)) if count.is_multiple_of(*n) => Some(fault.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Transport> Transport for FaultInjector<T> {
+    fn send(&self, request: HttpRequest) -> ResponseFuture<'_> {
+        let fault = self.next_fault();
+        Box::pin(async move {
+            match fault {
+                None => self.inner.send(request).await,
+                Some(Fault::Timeout) => Err(Error::Timeout),
+                Some(Fault::RateLimited { retry_after }) => Ok(rate_limited(retry_after)),
+                Some(Fault::Status(status)) => Ok(HttpResponse {
+                    status,
+                    headers: Vec::new(),
+                    body: Vec::new(),
+                    source: ResponseSource::Network,
+                }),
+                Some(Fault::MalformedJson) => {
+                    let mut response = self.inner.send(request).await?;
+                    response.body = b"<html><body>Service Unavailable</body></html>".to_vec();
+                    Ok(response)
+                }
+                Some(Fault::TruncatedBody) => {
+                    let mut response = self.inner.send(request).await?;
+                    response.body.truncate(response.body.len() / 2);
+                    Ok(response)
+                }
+            }
+        })
+    }
+}
+
+/// The response Scryfall sends when a client exceeds its rate limit.
+fn rate_limited(retry_after: Option<u64>) -> HttpResponse {
+    let body = serde_json::json!({
+        "object": "error",
+        "code": "rate_limited",
+        "status": 429,
+        "details": "You are sending requests too quickly. Please wait and try again.",
+    });
+
+    HttpResponse {
+        status: 429,
+        headers: retry_after
+            .map(|seconds| ("Retry-After".to_string(), seconds.to_string()))
+            .into_iter()
+            .collect(),
+        body: body.to_string().into_bytes(),
+        source: ResponseSource::Network,
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    #[derive(Debug)]
+    struct Ok200;
+
+    #[cfg(test)]
+    impl super::Transport for Ok200 {
+        fn send(&self, _: super::HttpRequest) -> super::ResponseFuture<'_> {
+            Box::pin(async {
+                Ok(super::HttpResponse {
+                    status: 200,
+                    headers: Vec::new(),
+                    body: br#"{"name": "Lightning Bolt"}"#.to_vec(),
+                    source: super::ResponseSource::Network,
+                })
+            })
+        }
+    }
+
+    #[cfg(test)]
+    #[derive(Debug)]
+    struct LightningBolt;
+
+    #[cfg(test)]
+    impl super::Transport for LightningBolt {
+        fn send(&self, _: super::HttpRequest) -> super::ResponseFuture<'_> {
+            Box::pin(async {
+                Ok(super::HttpResponse {
+                    status: 200,
+                    headers: Vec::new(),
+                    body: include_bytes!("../../testdata/cards/lightning_bolt.json").to_vec(),
+                    source: super::ResponseSource::Network,
+                })
+            })
+        }
+    }
+
+    #[cfg(test)]
+    fn request() -> super::HttpRequest {
+        super::HttpRequest {
+            method: crate::client::transport::Method::Get,
+            url: "https://api.scryfall.com/cards/random".to_string(),
+            headers: Vec::new(),
+            body: None,
+            attempt: 1,
+        }
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_scripted_faults() {
+        use super::{Fault, FaultInjector, Transport};
+        use crate::client::Error;
+
+        let faults = FaultInjector::new(Ok200)
+            .fail_next(Fault::Timeout)
+            .fail_next(Fault::RateLimited {
+                retry_after: Some(3),
+            })
+            .fail_next(Fault::MalformedJson)
+            .fail_next(Fault::TruncatedBody);
+
+        assert!(matches!(faults.send(request()).await, Err(Error::Timeout)));
+
+        let response = faults.send(request()).await.unwrap();
+        assert_eq!(429, response.status);
+        assert_eq!(Some("3"), response.header("retry-after"));
+
+        let response = faults.send(request()).await.unwrap();
+        assert!(serde_json::from_slice::<serde_json::Value>(&response.body).is_err());
+
+        let response = faults.send(request()).await.unwrap();
+        assert_eq!(br#"{"name": "Lig"#.to_vec(), response.body);
+
+        let response = faults.send(request()).await.unwrap();
+        assert_eq!(br#"{"name": "Lightning Bolt"}"#.to_vec(), response.body);
+        assert_eq!(5, faults.requests());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_fail_every() {
+        use super::{Fault, FaultInjector, Transport};
+
+        let faults = FaultInjector::new(Ok200).fail_every(3, Fault::Status(503));
+        let mut statuses = Vec::new();
+        for _ in 0..6 {
+            statuses.push(faults.send(request()).await.unwrap().status);
+        }
+        assert_eq!(vec![200, 200, 503, 200, 200, 503], statuses);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_client_errors() {
+        use super::{Fault, FaultInjector};
+        use crate::client::{Client, Error};
+
+        // Retries would hide the faults, so send each request once.
+        let faults = FaultInjector::new(Ok200)
+            .fail_next(Fault::RateLimited { retry_after: None })
+            .fail_next(Fault::Status(502))
+            .fail_next(Fault::TruncatedBody);
+        let client = Client::builder().transport(faults).max_attempts(1).build();
+
+        match client.card_by_id("x").await {
+            Err(Error::Api(err)) => assert_eq!("rate_limited", err.code),
+            other => panic!(
+                "expected a rate limit error, got {:?}",
+                other.map(|card| card.name)
+            ),
+        }
+        assert!(matches!(
+            client.card_by_id("x").await,
+            Err(Error::Status(502))
+        ));
+        assert!(matches!(client.card_by_id("x").await, Err(Error::Json(_))));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_client_retries() {
+        use super::{Fault, FaultInjector};
+        use crate::client::clock::{FixedJitter, VirtualClock};
+        use crate::client::{Client, Error};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let faults = FaultInjector::new(LightningBolt)
+            .fail_next(Fault::RateLimited {
+                retry_after: Some(2),
+            })
+            .fail_next(Fault::Status(503))
+            .fail_next(Fault::Status(502));
+        let clock = Arc::new(VirtualClock::new());
+        let client = Client::builder()
+            .transport(faults)
+            .rate_limit(Duration::ZERO, 1)
+            .clock(Arc::clone(&clock))
+            .jitter(FixedJitter(1.0))
+            .retry_backoff(Duration::from_secs(1))
+            .build();
+
+        // The first two faults are retried away, but the third is one more than three attempts allow. The rate limit
+        // waits for the server's Retry-After, then backs off twice as long as the first backoff.
+        assert!(matches!(
+            client.card_by_id("x").await,
+            Err(Error::Status(502))
+        ));
+        assert_eq!(
+            vec![Duration::from_secs(2), Duration::from_secs(2)],
+            clock.sleeps()
+        );
+        assert_eq!("Lightning Bolt", client.card_by_id("x").await.unwrap().name);
+        assert_eq!(Duration::from_secs(4), clock.elapsed());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_retry_after_timeout() {
+        use super::{Fault, FaultInjector};
+        use crate::client::clock::VirtualClock;
+        use crate::client::{Client, Error};
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        // Waiting out the Retry-After would overrun the call's timeout, so the call gives up without waiting.
+        let faults = FaultInjector::new(LightningBolt).fail_next(Fault::RateLimited {
+            retry_after: Some(5),
+        });
+        let clock = Arc::new(VirtualClock::new());
+        let client = Client::builder()
+            .transport(faults)
+            .clock(Arc::clone(&clock))
+            .timeout(Duration::from_secs(1))
+            .build();
+        assert!(matches!(client.card_by_id("x").await, Err(Error::Timeout)));
+        assert!(clock.sleeps().is_empty());
+        assert_eq!("Lightning Bolt", client.card_by_id("x").await.unwrap().name);
+    }
+}
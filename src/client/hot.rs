@@ -0,0 +1,247 @@
+use super::{Client, Error};
+use crate::types::card::Card;
+use crate::types::identifier::CardIdentifier;
+use crate::types::uuid::Uuid;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Keeps a set of "hot" cards, such as the ones a bot is asked about most, in memory and refreshes them on an interval,
+/// so looking them up never waits on the network. Refreshes fetch the hot cards through the collection endpoint,
+/// [`COLLECTION_LIMIT`](super::COLLECTION_LIMIT) per request, and wait on the client's rate limiter like any other
+/// request, so keeping many cards warm costs few requests and never exceeds the client's budget.
+///
+/// ```no_run
+/// use scryfall::client::{Client, HotCards};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), scryfall::client::Error> {
+/// let hot = Arc::new(HotCards::new(Client::new(), Duration::from_secs(6 * 60 * 60)));
+/// hot.add("e3285e6b-3e79-4d7c-bf96-d920f973b122");
+/// tokio::spawn({
+///     let hot = Arc::clone(&hot);
+///     async move { hot.run().await }
+/// });
+///
+/// let card = hot.card("e3285e6b-3e79-4d7c-bf96-d920f973b122").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HotCards {
+    client: Client,
+    interval: Duration,
+    state: Mutex<HotState>,
+}
+
+#[derive(Debug, Default)]
+struct HotState {
+    ids: BTreeSet<Uuid>,
+    cards: HashMap<Uuid, Arc<Card>>,
+}
+
+impl HotCards {
+    /// An empty set of hot cards, fetched through `client` and refreshed every `interval` once [`run`](Self::run).
+    pub fn new(client: Client, interval: Duration) -> Self {
+        HotCards {
+            client,
+            interval,
+            state: Mutex::new(HotState::default()),
+        }
+    }
+
+    /// Marks the printing with Scryfall ID `id` as hot. It's fetched on the next refresh, or by the next
+    /// [`card`](Self::card) lookup, whichever comes first.
+    pub fn add(&self, id: &str) {
+        self.state
+            .lock()
+            .unwrap()
+            .ids
+            .insert(id.to_ascii_lowercase());
+    }
+
+    /// Stops keeping the printing with Scryfall ID `id` in memory.
+    pub fn remove(&self, id: &str) {
+        let id = id.to_ascii_lowercase();
+        let mut state = self.state.lock().unwrap();
+        state.ids.remove(&id);
+        state.cards.remove(&id);
+    }
+
+    /// The Scryfall IDs of the hot cards, in lowercase and sorted.
+    pub fn ids(&self) -> Vec<Uuid> {
+        self.state.lock().unwrap().ids.iter().cloned().collect()
+    }
+
+    /// How many cards are hot.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().ids.len()
+    }
+
+    /// Whether no card is hot.
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().unwrap().ids.is_empty()
+    }
+
+    /// The hot card with Scryfall ID `id` as of the last time it was fetched, without touching the network. `None` if
+    /// the card isn't hot or hasn't been fetched yet.
+    pub fn get(&self, id: &str) -> Option<Arc<Card>> {
+        self.state
+            .lock()
+            .unwrap()
+            .cards
+            .get(&id.to_ascii_lowercase())
+            .cloned()
+    }
+
+    /// The card with Scryfall ID `id`, from memory when it's hot and has been fetched, and otherwise from Scryfall. A
+    /// hot card fetched this way is kept for later lookups.
+    pub async fn card(&self, id: &str) -> Result<Arc<Card>, Error> {
+        if let Some(card) = self.get(id) {
+            return Ok(card);
+        }
+
+        let card = Arc::new(self.client.card_by_id(id).await?);
+        let id = id.to_ascii_lowercase();
+        let mut state = self.state.lock().unwrap();
+        if state.ids.contains(&id) {
+            state.cards.insert(id, Arc::clone(&card));
+        }
+        Ok(card)
+    }
+
+    /// Fetches every hot card again, replacing the copies in memory. Returns the IDs of hot cards Scryfall no longer
+    /// has, which are dropped from memory but stay hot until [`remove`](Self::remove)d. If a request fails, the cards
+    /// fetched by earlier requests are still updated.
+    pub async fn refresh(&self) -> Result<Vec<Uuid>, Error> {
+        let identifiers: Vec<CardIdentifier> =
+            self.ids().into_iter().map(CardIdentifier::Id).collect();
+        let mut missing = Vec::new();
+        for chunk in identifiers.chunks(super::COLLECTION_LIMIT) {
+            let collection = self.client.collection(chunk).await?;
+
+            let mut state = self.state.lock().unwrap();
+            for card in collection.cards {
+                let id = card.id.to_ascii_lowercase();
+                if state.ids.contains(&id) {
+                    state.cards.insert(id, Arc::new(card));
+                }
+            }
+            for identifier in collection.not_found {
+                if let CardIdentifier::Id(id) = identifier {
+                    state.cards.remove(&id.to_ascii_lowercase());
+                    missing.push(id);
+                }
+            }
+        }
+
+        Ok(missing)
+    }
+
+    /// Refreshes the hot cards now and then every interval after that, forever, so spawn it as a task of its own. A
+    /// failed refresh is logged and tried again at the next interval, keeping the cards from the last one meanwhile.
+    pub async fn run(&self) {
+        loop {
+            match self.refresh().await {
+                Ok(missing) if !missing.is_empty() => {
+                    log::warn!("hot cards no longer on Scryfall: {}", missing.join(", "));
+                }
+                Ok(_) => {}
+                Err(err) => log::warn!("failed to refresh the hot cards: {}", err),
+            }
+            self.client.inner.clock.sleep(self.interval).await;
+        }
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    const BOLT: &str = "e3285e6b-3e79-4d7c-bf96-d920f973b122";
+
+    #[cfg(test)]
+    const GONE: &str = "00000000-0000-0000-0000-000000000000";
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_refresh() {
+        use super::HotCards;
+        use crate::client::Client;
+        use crate::testing;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/cards/collection"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "not_found": [{"id": GONE}],
+                "data": [testing::card_json(serde_json::json!({ "id": BOLT }))],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let hot = HotCards::new(
+            Client::with_base_url(&server.uri()),
+            Duration::from_secs(60),
+        );
+        hot.add(&BOLT.to_ascii_uppercase());
+        hot.add(GONE);
+        assert_eq!(2, hot.len());
+        assert!(hot.get(BOLT).is_none());
+
+        assert_eq!(vec![GONE.to_string()], hot.refresh().await.unwrap());
+        assert_eq!(BOLT, hot.get(BOLT).unwrap().id);
+        assert_eq!(BOLT, hot.card(BOLT).await.unwrap().id);
+        assert!(hot.get(GONE).is_none());
+        assert_eq!(2, hot.len());
+
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            serde_json::json!([{ "id": GONE }, { "id": BOLT }]),
+            body["identifiers"]
+        );
+
+        hot.remove(BOLT);
+        assert!(hot.get(BOLT).is_none());
+        assert_eq!(vec![GONE.to_string()], hot.ids());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_card() {
+        use super::HotCards;
+        use crate::client::Client;
+        use crate::testing;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/cards/{}", BOLT)))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(testing::card_json(serde_json::json!({ "id": BOLT }))),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let hot = HotCards::new(
+            Client::with_base_url(&server.uri()),
+            Duration::from_secs(60),
+        );
+        hot.card(BOLT).await.unwrap();
+        assert!(hot.get(BOLT).is_none());
+
+        hot.add(BOLT);
+        hot.card(BOLT).await.unwrap();
+        hot.card(BOLT).await.unwrap();
+        assert!(hot.get(BOLT).is_some());
+    }
+}
@@ -0,0 +1,238 @@
+//! A [`Transport`] decorator that logs every request through the [`log`] facade, one line per attempt with its
+//! method, URL, status, duration, attempt number and cache outcome, as `key=value` pairs:
+//!
+//! ```text
+//! method=GET url=https://api.scryfall.com/cards/named?exact=Lightning%20Bolt status=200 duration_ms=84 attempt=1 cache=miss
+//! ```
+//!
+//! Put it outside any caching decorator, so it sees which responses came from storage:
+//!
+//! ```no_run
+//! use scryfall::client::etag::EtagCache;
+//! use scryfall::client::logging::{QueryLogging, RequestLogger};
+//! use scryfall::client::transport::ReqwestTransport;
+//! use scryfall::client::Client;
+//! use scryfall::storage::FsStorage;
+//!
+//! # fn example() -> std::io::Result<()> {
+//! let cache = EtagCache::new(ReqwestTransport::new(), FsStorage::new("scryfall-cache")?);
+//! let client = Client::with_transport(RequestLogger::new(cache).query(QueryLogging::Truncate(16)));
+//! # Ok(())
+//! # }
+//! ```
+
+use super::transport::{HttpRequest, Method, ResponseFuture, ResponseSource, Transport};
+use log::Level;
+use std::time::Instant;
+
+/// The `log` target every line is logged under.
+pub const TARGET: &str = "scryfall::http";
+
+/// How much of a URL's query string to log. Search queries can carry what users typed, which privacy-sensitive
+/// deployments may not want in their logs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QueryLogging {
+    /// The whole query string.
+    Full,
+
+    /// At most this many characters of the query string, followed by `…` if any were cut.
+    Truncate(usize),
+
+    /// Only whether there was a query string, logged as `?…`.
+    Omit,
+}
+
+/// Wraps a transport and logs each request it sends: successful responses at the logger's level, which defaults to
+/// `Info`, and error statuses and failures to get a response at `Warn`.
+#[derive(Debug)]
+pub struct RequestLogger<T> {
+    inner: T,
+    query: QueryLogging,
+    level: Level,
+}
+
+impl<T: Transport> RequestLogger<T> {
+    /// A logger in front of `inner`, logging whole URLs at `Info`.
+    pub fn new(inner: T) -> Self {
+        RequestLogger {
+            inner,
+            query: QueryLogging::Full,
+            level: Level::Info,
+        }
+    }
+
+    /// How much of each query string to log.
+    pub fn query(mut self, query: QueryLogging) -> Self {
+        self.query = query;
+        self
+    }
+
+    /// The level successful requests are logged at.
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// `url` with its query string cut down as configured.
+    fn redact(&self, url: &str) -> String {
+        let (path, query) = match url.split_once('?') {
+            Some(parts) => parts,
+            None => return url.to_string(),
+        };
+        match self.query {
+            QueryLogging::Full => url.to_string(),
+            QueryLogging::Truncate(max) if query.chars().count() <= max => url.to_string(),
+            QueryLogging::Truncate(max) => {
+                format!("{}?{}…", path, query.chars().take(max).collect::<String>())
+            }
+            QueryLogging::Omit => format!("{}?…", path),
+        }
+    }
+}
+
+impl<T: Transport> Transport for RequestLogger<T> {
+    fn send(&self, request: HttpRequest) -> ResponseFuture<'_> {
+        Box::pin(async move {
+            let method = match request.method {
+                Method::Get => "GET",
+                Method::Post => "POST",
+            };
+            let url = self.redact(&request.url);
+            let attempt = request.attempt;
+
+            let start = Instant::now();
+            let response = self.inner.send(request).await;
+            let duration_ms = start.elapsed().as_millis();
+            match &response {
+                Ok(response) => {
+                    let cache = match response.source {
+                        ResponseSource::Network => "miss",
+                        ResponseSource::Cache => "hit",
+                        ResponseSource::Stale => "stale",
+                    };
+                    let level = if response.status >= 400 {
+                        Level::Warn
+                    } else {
+                        self.level
+                    };
+                    log::log!(
+                        target: TARGET,
+                        level,
+                        "method={} url={} status={} duration_ms={} attempt={} cache={}",
+                        method,
+                        url,
+                        response.status,
+                        duration_ms,
+                        attempt,
+                        cache
+                    );
+                }
+                Err(err) => log::warn!(
+                    target: TARGET,
+                    "method={} url={} error={:?} duration_ms={} attempt={}",
+                    method,
+                    url,
+                    err.to_string(),
+                    duration_ms,
+                    attempt
+                ),
+            }
+            response
+        })
+    }
+}
+
+mod tests {
+    /// A logger keeping the lines logged under [`TARGET`](super::TARGET).
+    #[cfg(test)]
+    struct Capture(std::sync::Mutex<Vec<String>>);
+
+    #[cfg(test)]
+    impl log::Log for Capture {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == super::TARGET
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                let line = format!("{} {}", record.level(), record.args());
+                self.0.lock().unwrap().push(line);
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[cfg(test)]
+    static CAPTURE: Capture = Capture(std::sync::Mutex::new(Vec::new()));
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_request_logger() {
+        use super::{QueryLogging, RequestLogger};
+        use crate::client::clock::VirtualClock;
+        use crate::client::fault::{Fault, FaultInjector};
+        use crate::client::transport::ReqwestTransport;
+        use crate::client::ClientBuilder;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        log::set_logger(&CAPTURE).unwrap();
+        log::set_max_level(log::LevelFilter::Info);
+        let faults = FaultInjector::new(ReqwestTransport::new()).fail_next(Fault::Status(503));
+        let client = ClientBuilder::new()
+            .base_url(&server.uri())
+            .transport(RequestLogger::new(faults).query(QueryLogging::Omit))
+            .clock(VirtualClock::new())
+            .build();
+        client
+            .card_named_exact("Lightning Bolt", None)
+            .await
+            .unwrap();
+
+        let lines = CAPTURE.0.lock().unwrap();
+        let url = format!("{}/cards/named?…", server.uri());
+        assert_eq!(2, lines.len());
+        assert!(lines[0].starts_with(&format!(
+            "WARN method=GET url={} status=503 duration_ms=",
+            url
+        )));
+        assert!(lines[0].ends_with(" attempt=1 cache=miss"));
+        assert!(lines[1].starts_with(&format!("INFO method=GET url={} status=200", url)));
+        assert!(lines[1].ends_with(" attempt=2 cache=miss"));
+    }
+
+    #[test]
+    fn test_redact() {
+        use super::{QueryLogging, RequestLogger};
+        use crate::client::transport::ReqwestTransport;
+
+        let url = "https://api.scryfall.com/cards/search?q=t%3Agoblin&page=2";
+        let logger = RequestLogger::new(ReqwestTransport::new());
+        assert_eq!(url, logger.redact(url));
+        assert_eq!(
+            "https://api.scryfall.com/sets",
+            logger.redact("https://api.scryfall.com/sets")
+        );
+
+        let logger = logger.query(QueryLogging::Truncate(8));
+        assert_eq!(
+            "https://api.scryfall.com/cards/search?q=t%3Ago…",
+            logger.redact(url)
+        );
+        let logger = logger.query(QueryLogging::Omit);
+        assert_eq!(
+            "https://api.scryfall.com/cards/search?…",
+            logger.redact(url)
+        );
+    }
+}
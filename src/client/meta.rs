@@ -0,0 +1,61 @@
+use super::transport::{HttpResponse, ResponseSource};
+use std::time::Duration;
+
+/// What's known about one response a call received, for per-call observability without global logging. Collected
+/// with [`Client::with_meta`](super::Client::with_meta).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResponseMeta {
+    /// The URL requested.
+    pub url: String,
+
+    /// The HTTP status.
+    pub status: u16,
+
+    /// Which try at the call got this response, counting from 1.
+    pub attempt: u32,
+
+    /// How long the transport took to answer, from sending the request to reading the whole body.
+    pub elapsed: Duration,
+
+    /// Whether the response came from Scryfall or from a stored copy.
+    pub source: ResponseSource,
+
+    /// The response's rate limiting headers: `Retry-After`, and any whose names start with `X-RateLimit` or
+    /// `RateLimit`.
+    pub rate_limit_headers: Vec<(String, String)>,
+}
+
+impl ResponseMeta {
+    /// The metadata of `response` to the request for `url`.
+    pub(crate) fn new(url: &str, attempt: u32, elapsed: Duration, response: &HttpResponse) -> Self {
+        let rate_limit_headers = response
+            .headers
+            .iter()
+            .filter(|(name, _)| {
+                let name = name.to_ascii_lowercase();
+                name == "retry-after"
+                    || name.starts_with("x-ratelimit")
+                    || name.starts_with("ratelimit")
+            })
+            .cloned()
+            .collect();
+        ResponseMeta {
+            url: url.to_string(),
+            status: response.status,
+            attempt,
+            elapsed,
+            source: response.source,
+            rate_limit_headers,
+        }
+    }
+
+    /// Whether the response was served from storage, whether or not Scryfall confirmed it was current.
+    pub fn from_cache(&self) -> bool {
+        self.source != ResponseSource::Network
+    }
+
+    /// Whether the response is a stored copy that Scryfall couldn't confirm, which may be out of date.
+    pub fn is_stale(&self) -> bool {
+        self.source == ResponseSource::Stale
+    }
+}
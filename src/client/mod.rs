@@ -0,0 +1,2152 @@
+//! An async client for Scryfall's API, enabled by the `client` feature.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), scryfall::client::Error> {
+//! let client = scryfall::client::Client::new();
+//! let card = client.card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d").await?;
+//! println!("{}", card.name);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With the `blocking` feature, `blocking::Client` offers the same calls without an async runtime.
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod breaker;
+mod builder;
+pub mod clock;
+mod error;
+mod estimate;
+pub mod etag;
+pub mod fault;
+mod hot;
+pub mod logging;
+mod meta;
+mod pages;
+mod rate_limit;
+mod request;
+mod resolve;
+mod retry;
+mod sample;
+mod spoilers;
+mod tenant;
+pub mod transport;
+
+pub use self::builder::{ClientBuilder, EnvError};
+pub use self::error::Error;
+pub use self::estimate::{Estimate, PAGE_SIZE};
+pub use self::hot::HotCards;
+pub use self::meta::ResponseMeta;
+pub use self::pages::PageCursor;
+pub use self::rate_limit::{RateLimiter, DEFAULT_INTERVAL};
+pub use self::spoilers::SpoilerFeed;
+pub use self::tenant::Tenants;
+
+use self::clock::Clock;
+use self::pages::Pages;
+use self::request::Request;
+use self::retry::RetryPolicy;
+use self::transport::{HttpRequest, ResponseSource, Transport};
+use crate::bulk::BulkKind;
+use crate::cache::QueryCache;
+use crate::query;
+use crate::storage::Storage;
+use crate::types::bulk::BulkData;
+use crate::types::card::Card;
+use crate::types::catalog::{Catalog, CatalogKind};
+use crate::types::date::Date;
+use crate::types::identifier::{CardIdentifier, Collection};
+use crate::types::list::{CardList, List, SetList};
+use crate::types::ruling::Ruling;
+use crate::types::search::SearchParams;
+use crate::types::set::Set;
+use crate::types::symbol::{CardSymbol, ManaCostInfo};
+use crate::types::uri::Uri;
+use futures_util::stream::{self, Stream};
+use futures_util::TryStreamExt;
+use serde::de::{DeserializeOwned, IgnoredAny};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Where Scryfall's API lives.
+pub const BASE_URL: &str = "https://api.scryfall.com";
+
+/// The `User-Agent` sent when [`ClientBuilder::user_agent`] isn't set. It names this crate and its version.
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "-rs/", env!("CARGO_PKG_VERSION"));
+
+/// The most identifiers Scryfall accepts in one `/cards/collection` request.
+pub const COLLECTION_LIMIT: usize = 75;
+
+/// An async client for Scryfall's API.
+///
+/// A client is `Send + Sync`, and cloning one only bumps a reference count: every clone shares the same connection
+/// pool, configuration and rate limiter. Create one client for the whole program and hand clones to as many tasks as
+/// needed, rather than making one per call.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: Arc<Inner>,
+
+    /// This handle's override of the timeout in `inner`.
+    timeout: Option<Duration>,
+
+    /// Where a handle made by [`dry_run`](Client::dry_run) puts the request it would have sent.
+    dry_run: Option<Arc<Mutex<Option<HttpRequest>>>>,
+
+    /// Where a handle made by [`with_meta`](Client::with_meta) records the responses it receives.
+    meta: Option<Arc<Mutex<Vec<ResponseMeta>>>>,
+
+    /// The budget of the tenant a handle from [`Tenants`] acts for, waited on before the shared rate limiter.
+    tenant: Option<Arc<RateLimiter>>,
+}
+
+/// The state shared by every clone of a `Client`.
+#[derive(Debug)]
+struct Inner {
+    transport: Box<dyn Transport>,
+    base_url: String,
+    rate_limiter: RateLimiter,
+    retry: RetryPolicy,
+    clock: Arc<dyn Clock>,
+    headers: Vec<(String, String)>,
+    timeout: Option<Duration>,
+    query_cache: Option<QueryCache<Box<dyn Storage>>>,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client::new()
+    }
+}
+
+impl Client {
+    /// A client for api.scryfall.com.
+    pub fn new() -> Self {
+        Client::with_base_url(BASE_URL)
+    }
+
+    /// A builder for a client with settings other than the defaults.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// A client for a Scryfall-compatible API at `base_url`, such as a mock server in tests.
+    pub(crate) fn with_base_url(base_url: &str) -> Self {
+        ClientBuilder::new().base_url(base_url).build()
+    }
+
+    /// A client for api.scryfall.com that sends its requests through `transport`.
+    pub fn with_transport<T: Transport + 'static>(transport: T) -> Self {
+        ClientBuilder::new().transport(transport).build()
+    }
+
+    /// A handle to this client whose calls each fail with [`Error::Timeout`] if they take longer than `timeout`,
+    /// including retries and waiting for the rate limiter. It shares this client's connection pool and rate limiter,
+    /// so it's cheap to make one for a single interactive lookup:
+    ///
+    /// ```no_run
+    /// # async fn lookup(client: &scryfall::client::Client) -> Result<(), scryfall::client::Error> {
+    /// use std::time::Duration;
+    ///
+    /// let card = client
+    ///     .timeout(Duration::from_secs(2))
+    ///     .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timeout(&self, timeout: Duration) -> Client {
+        Client {
+            timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+
+    /// The rate limiter spacing out this client's requests, shared with all of its clones. By default it sends one
+    /// request every [`DEFAULT_INTERVAL`], as Scryfall asks.
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.inner.rate_limiter
+    }
+
+    /// The card with Scryfall ID `id`.
+    pub async fn card_by_id(&self, id: &str) -> Result<Card, Error> {
+        self.execute(Request::get(&["cards", id])).await
+    }
+
+    /// The card with Gatherer multiverse ID `id`.
+    pub async fn card_by_multiverse_id(&self, id: u32) -> Result<Card, Error> {
+        self.execute(Request::get(&["cards", "multiverse", &id.to_string()]))
+            .await
+    }
+
+    /// The card with Magic Online ID `id`.
+    pub async fn card_by_mtgo_id(&self, id: u32) -> Result<Card, Error> {
+        self.execute(Request::get(&["cards", "mtgo", &id.to_string()]))
+            .await
+    }
+
+    /// The card with MTG Arena ID `id`.
+    pub async fn card_by_arena_id(&self, id: u32) -> Result<Card, Error> {
+        self.execute(Request::get(&["cards", "arena", &id.to_string()]))
+            .await
+    }
+
+    /// The card with TCGplayer product ID `id`.
+    pub async fn card_by_tcgplayer_id(&self, id: u32) -> Result<Card, Error> {
+        self.execute(Request::get(&["cards", "tcgplayer", &id.to_string()]))
+            .await
+    }
+
+    /// The card with Cardmarket product ID `id`.
+    pub async fn card_by_cardmarket_id(&self, id: u32) -> Result<Card, Error> {
+        self.execute(Request::get(&["cards", "cardmarket", &id.to_string()]))
+            .await
+    }
+
+    /// The printing with collector number `number` in the set with code `set`, in language `lang` if given (e.g.
+    /// `"ja"`) or English otherwise. Collector numbers are sent exactly as given, since they can contain letters and
+    /// symbols like `★`.
+    pub async fn card_by_set_number(
+        &self,
+        set: &str,
+        number: &str,
+        lang: Option<&str>,
+    ) -> Result<Card, Error> {
+        let mut segments = vec!["cards", set, number];
+        segments.extend(lang);
+        self.execute(Request::get(&segments)).await
+    }
+
+    /// The cards matching `identifiers`, plus the identifiers that matched nothing. Scryfall accepts at most
+    /// [`COLLECTION_LIMIT`] identifiers per request, so longer lists are sent in chunks.
+    pub async fn collection(&self, identifiers: &[CardIdentifier]) -> Result<Collection, Error> {
+        let mut collection = Collection {
+            cards: Vec::new(),
+            not_found: Vec::new(),
+        };
+        for chunk in identifiers.chunks(COLLECTION_LIMIT) {
+            let body = serde_json::json!({ "identifiers": chunk });
+            let page: Collection = self
+                .execute(Request::post(&["cards", "collection"], body))
+                .await?;
+            collection.cards.extend(page.cards);
+            collection.not_found.extend(page.not_found);
+        }
+
+        Ok(collection)
+    }
+
+    /// The card each of `inputs` refers to, in the same order, from as few requests as possible. An input may be a
+    /// Scryfall ID, a card link such as a Scryfall or Gatherer page, a set code and collector number like `m10/146`, an
+    /// exact name marked with a leading `!` or wrapped in double quotes, or any other name, which may be misspelled
+    /// or partial.
+    ///
+    /// Everything the collection endpoint can identify is looked up there, [`COLLECTION_LIMIT`] inputs per request.
+    /// Names that aren't marked exact and match no card exactly are then looked up fuzzily, one request each, as are
+    /// links the collection endpoint can't identify. An input that matches no card gets a `not_found` error; the call
+    /// as a whole only fails if a collection request does.
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), scryfall::client::Error> {
+    /// let client = scryfall::client::Client::new();
+    /// let cards = client
+    ///     .resolve(&["m10/146", "!Counterspell", "jac bele", "https://scryfall.com/card/lea/161/giant-growth"])
+    ///     .await?;
+    /// for card in cards {
+    ///     match card {
+    ///         Ok(card) => println!("{}", card.name),
+    ///         Err(err) => println!("{}", err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve(&self, inputs: &[&str]) -> Result<Vec<Result<Card, Error>>, Error> {
+        let lookups: Vec<_> = inputs
+            .iter()
+            .map(|input| resolve::classify(input))
+            .collect();
+        let mut found: Vec<Option<Card>> = inputs.iter().map(|_| None).collect();
+
+        let batched: Vec<_> = lookups
+            .iter()
+            .enumerate()
+            .filter_map(|(i, lookup)| lookup.identifier().map(|identifier| (i, identifier)))
+            .collect();
+        for chunk in batched.chunks(COLLECTION_LIMIT) {
+            let identifiers: Vec<_> = chunk
+                .iter()
+                .map(|(_, identifier)| identifier.clone())
+                .collect();
+            // The cards come back in the order they were asked for, skipping the identifiers that matched nothing.
+            let mut cards = self
+                .collection(&identifiers)
+                .await?
+                .cards
+                .into_iter()
+                .peekable();
+            for (i, identifier) in chunk {
+                if cards.peek().is_some_and(|card| identifier.matches(card)) {
+                    found[*i] = cards.next();
+                }
+            }
+        }
+
+        let mut results = Vec::with_capacity(inputs.len());
+        for ((input, lookup), card) in inputs.iter().zip(lookups).zip(found) {
+            let result = match (card, lookup) {
+                (Some(card), _) => Ok(card),
+                (None, resolve::Lookup::Name(name)) => self.card_named_fuzzy(&name, None).await,
+                (None, resolve::Lookup::Single(request)) => self.execute(request).await,
+                (None, _) => Err(resolve::not_found(input)),
+            };
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+
+    /// Up to 20 card names starting with or containing `partial`, for autocompleting a name as it's typed. Extras
+    /// like tokens and art cards are left out unless `include_extras` is set. Queries shorter than two characters
+    /// return an empty catalog.
+    pub async fn autocomplete(
+        &self,
+        partial: &str,
+        include_extras: bool,
+    ) -> Result<Catalog, Error> {
+        let request = Request::get(&["cards", "autocomplete"])
+            .query("q", partial)
+            .flag("include_extras", include_extras);
+        self.execute(request).await
+    }
+
+    /// The card named exactly `name`, ignoring case and punctuation. If several printings exist, the most recent one
+    /// is returned, unless `set` names the set code to take it from.
+    pub async fn card_named_exact(&self, name: &str, set: Option<&str>) -> Result<Card, Error> {
+        self.execute(
+            Request::get(&["cards", "named"])
+                .query("exact", name)
+                .query_opt("set", set),
+        )
+        .await
+    }
+
+    /// The card whose name best matches `name`, which may be misspelled or partial, as in `"jac bele"` for Jace
+    /// Beleren. Fails with a `not_found` error if no single card matches, including when the name is ambiguous.
+    pub async fn card_named_fuzzy(&self, name: &str, set: Option<&str>) -> Result<Card, Error> {
+        self.execute(
+            Request::get(&["cards", "named"])
+                .query("fuzzy", name)
+                .query_opt("set", set),
+        )
+        .await
+    }
+
+    /// One page of the cards matching the search `query`, written in Scryfall's search syntax. Check the list's
+    /// `warnings` for parts of the query Scryfall ignored. A search with no results fails with a `not_found` error, and
+    /// a query with a mistake [`query::validate`] can catch fails before it's sent.
+    pub async fn search(&self, query: &str, params: &SearchParams) -> Result<CardList, Error> {
+        query::validate(query)?;
+        self.execute(Request::search(query, params)).await
+    }
+
+    /// Every card matching the search `query`, fetching each page of results only once the previous one has been
+    /// consumed. A search with no results is an empty stream, and a query with a mistake [`query::validate`] can catch
+    /// is yielded as an error before anything is sent.
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), scryfall::client::Error> {
+    /// use futures_util::{pin_mut, StreamExt};
+    ///
+    /// let client = scryfall::client::Client::new();
+    /// let goblins = client.search_all("t:goblin");
+    /// pin_mut!(goblins);
+    /// while let Some(card) = goblins.next().await {
+    ///     println!("{}", card?.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_all(&self, query: &str) -> impl Stream<Item = Result<Card, Error>> {
+        let request = query::validate(query)
+            .map(|()| Request::search(query, &SearchParams::default()))
+            .map_err(Error::from);
+        self.pages(Pages::starting_at(request))
+    }
+
+    /// Every item of `list`, followed by those on each of its later pages, fetched one page at a time as the stream
+    /// is consumed.
+    pub fn paginate<T>(&self, list: List<T>) -> impl Stream<Item = Result<T, Error>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.pages(Pages::new(list))
+    }
+
+    /// The object at `uri`, a link from a response such as a card's `rulings_uri`, decoded as `T`. Only the link's
+    /// path and query are used, so it's fetched from this client's base URL like any other call.
+    ///
+    /// ```no_run
+    /// # async fn example(card: &scryfall::types::card::Card) -> Result<(), scryfall::client::Error> {
+    /// use scryfall::types::list::List;
+    /// use scryfall::types::ruling::Ruling;
+    ///
+    /// let client = scryfall::client::Client::new();
+    /// let rulings: List<Ruling> = client.fetch(&card.rulings_uri).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch<T: DeserializeOwned>(&self, uri: &Uri) -> Result<T, Error> {
+        self.execute(Request::follow(uri)).await
+    }
+
+    /// The page of a paginated list that `cursor` is at. Use [`PageCursor::after`] on it for the cursor at the next
+    /// page.
+    pub async fn page<T: DeserializeOwned>(&self, cursor: &PageCursor) -> Result<List<T>, Error> {
+        self.fetch(cursor.uri()).await
+    }
+
+    /// Every item of the paginated list at `uri`, such as a card's `prints_search_uri`, fetching each page only once
+    /// the previous one has been consumed. A search link with no results is an empty stream.
+    pub fn fetch_all<T>(&self, uri: &Uri) -> impl Stream<Item = Result<T, Error>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.pages(Pages::starting_at(Ok(Request::follow(uri))))
+    }
+
+    /// Every card matching the search `query` with `params`, from the page `params` asks for onwards, served from the
+    /// [query cache](ClientBuilder::query_cache) when it holds an unexpired entry for the same search. Otherwise the
+    /// pages are fetched and the results stored for next time. A search with no results is empty. Without a query
+    /// cache this always fetches. The cache never fails a search: storage errors are logged as warnings and treated as
+    /// misses.
+    pub async fn search_cached(
+        &self,
+        query: &str,
+        params: &SearchParams,
+    ) -> Result<Vec<Card>, Error> {
+        query::validate(query)?;
+        let cache = self.inner.query_cache.as_ref();
+        if let Some(cache) = cache {
+            match cache.get(query, params) {
+                Ok(Some(cards)) => return Ok(cards),
+                Ok(None) => {}
+                Err(err) => log::warn!("failed to read cached results for {:?}: {}", query, err),
+            }
+        }
+
+        let cards: Vec<Card> = self
+            .pages(Pages::starting_at(Ok(Request::search(query, params))))
+            .try_collect()
+            .await?;
+        if let Some(cache) = cache {
+            if let Err(err) = cache.insert(query, params, &cards) {
+                log::warn!("failed to cache results for {:?}: {}", query, err);
+            }
+        }
+        Ok(cards)
+    }
+
+    /// How many cards match the search `query`, from the first page of results alone.
+    pub async fn count(&self, query: &str) -> Result<u32, Error> {
+        Ok(self
+            .estimate(query, &SearchParams::default())
+            .await?
+            .total_cards)
+    }
+
+    /// How many cards and pages a search would return, from the first page of results alone, so tools can confirm
+    /// with [`Estimate::exceeds`] before iterating over a huge result.
+    pub async fn estimate(&self, query: &str, params: &SearchParams) -> Result<Estimate, Error> {
+        let params = SearchParams {
+            page: None,
+            ..params.clone()
+        };
+        Estimate::from_search(self.search(query, &params).await)
+    }
+
+    /// `n` distinct random cards matching the search `query`, or all of them if fewer match. This picks random
+    /// positions among the results and fetches only the pages holding them, which takes far fewer requests than
+    /// calling [`random_card_filtered`](Self::random_card_filtered) `n` times.
+    pub async fn sample(&self, query: &str, n: usize) -> Result<Vec<Card>, Error> {
+        let first = match self.search(query, &SearchParams::default()).await {
+            Ok(list) => list,
+            Err(Error::Api(err)) if err.code == "not_found" => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let total = first.total_cards.unwrap_or(first.data.len() as u32);
+
+        let mut first = Some(first);
+        let mut cards = Vec::new();
+        for (page, offsets) in sample::plan(total, n) {
+            let list = match first.take().filter(|_| page == 1) {
+                Some(list) => list,
+                None => {
+                    let params = SearchParams {
+                        page: Some(page),
+                        ..SearchParams::default()
+                    };
+                    self.search(query, &params).await?
+                }
+            };
+            cards.extend(sample::pick(list.data, &offsets));
+        }
+        sample::shuffle(&mut cards);
+
+        Ok(cards)
+    }
+
+    /// Every printing previewed on or after `since`, or released then if it was never previewed, newest previews
+    /// first, for spoiler bots. This searches with `date>=` and the `spoiled` order, then drops printings released
+    /// since but previewed earlier. Pass the results through a [`SpoilerFeed`] to report each card only once across
+    /// polls.
+    pub async fn cards_added_since(&self, since: Date) -> Result<Vec<Card>, Error> {
+        let (query, params) = spoilers::query(since);
+        let cards: Vec<Card> = self
+            .pages(Pages::starting_at(Ok(Request::search(&query, &params))))
+            .try_collect()
+            .await?;
+
+        Ok(cards
+            .into_iter()
+            .filter(|card| spoilers::added_since(card, since))
+            .collect())
+    }
+
+    /// A random card.
+    pub async fn random_card(&self) -> Result<Card, Error> {
+        self.execute(Request::get(&["cards", "random"])).await
+    }
+
+    /// A random card matching the search `query`, e.g. `"is:commander"`. Fails with a `not_found` error if nothing
+    /// matches.
+    pub async fn random_card_filtered(&self, query: &str) -> Result<Card, Error> {
+        query::validate(query)?;
+        self.execute(Request::get(&["cards", "random"]).query("q", query))
+            .await
+    }
+
+    /// The rulings for the card with Scryfall ID `id`.
+    pub async fn rulings_by_id(&self, id: &str) -> Result<List<Ruling>, Error> {
+        self.execute(Request::get(&["cards", id, "rulings"])).await
+    }
+
+    /// The rulings for the card with Gatherer multiverse ID `id`.
+    pub async fn rulings_by_multiverse_id(&self, id: u32) -> Result<List<Ruling>, Error> {
+        self.execute(Request::get(&[
+            "cards",
+            "multiverse",
+            &id.to_string(),
+            "rulings",
+        ]))
+        .await
+    }
+
+    /// The rulings for the card with Magic Online ID `id`.
+    pub async fn rulings_by_mtgo_id(&self, id: u32) -> Result<List<Ruling>, Error> {
+        self.execute(Request::get(&["cards", "mtgo", &id.to_string(), "rulings"]))
+            .await
+    }
+
+    /// The rulings for the card with MTG Arena ID `id`.
+    pub async fn rulings_by_arena_id(&self, id: u32) -> Result<List<Ruling>, Error> {
+        self.execute(Request::get(&[
+            "cards",
+            "arena",
+            &id.to_string(),
+            "rulings",
+        ]))
+        .await
+    }
+
+    /// The rulings for the printing with collector number `number` in the set with code `set`.
+    pub async fn rulings_by_set_number(
+        &self,
+        set: &str,
+        number: &str,
+    ) -> Result<List<Ruling>, Error> {
+        self.execute(Request::get(&["cards", set, number, "rulings"]))
+            .await
+    }
+
+    /// Every set on Scryfall, newest first.
+    pub async fn sets(&self) -> Result<SetList, Error> {
+        self.execute(Request::get(&["sets"])).await
+    }
+
+    /// The set with code `code`, e.g. `"blb"`. MTGO codes work too.
+    pub async fn set_by_code(&self, code: &str) -> Result<Set, Error> {
+        self.execute(Request::get(&["sets", code])).await
+    }
+
+    /// The set with Scryfall ID `id`.
+    pub async fn set_by_id(&self, id: &str) -> Result<Set, Error> {
+        self.execute(Request::get(&["sets", id])).await
+    }
+
+    /// The set with TCGplayer group ID `id`.
+    pub async fn set_by_tcgplayer_id(&self, id: u32) -> Result<Set, Error> {
+        self.execute(Request::get(&["sets", "tcgplayer", &id.to_string()]))
+            .await
+    }
+
+    /// Every symbol that can appear in a card's costs or text.
+    pub async fn symbology(&self) -> Result<List<CardSymbol>, Error> {
+        self.execute(Request::get(&["symbology"])).await
+    }
+
+    /// Scryfall's reading of the mana cost `cost`, normalized and with its mana value and colors. Loose input like
+    /// `"RUx"` is accepted; unreadable input fails with a `bad_request` error.
+    pub async fn parse_mana(&self, cost: &str) -> Result<ManaCostInfo, Error> {
+        self.execute(Request::get(&["symbology", "parse-mana"]).query("cost", cost))
+            .await
+    }
+
+    /// The catalog of `kind`, e.g. every creature type or every keyword ability.
+    pub async fn catalog(&self, kind: CatalogKind) -> Result<Catalog, Error> {
+        self.execute(Request::get(&["catalog", kind.as_str()]))
+            .await
+    }
+
+    /// Every bulk file Scryfall currently exports, with where to download it.
+    pub async fn bulk_data(&self) -> Result<List<BulkData>, Error> {
+        self.execute(Request::get(&["bulk-data"])).await
+    }
+
+    /// The bulk file of `kind`, with where to download it.
+    pub async fn bulk_data_by_type(&self, kind: BulkKind) -> Result<BulkData, Error> {
+        self.execute(Request::get(&["bulk-data", kind.type_name()]))
+            .await
+    }
+
+    /// The items of `pages` as a stream, fetching each page as the previous one runs out.
+    fn pages<T>(&self, pages: Pages<T>) -> impl Stream<Item = Result<T, Error>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        stream::unfold((self.clone(), pages), |(client, mut pages)| async move {
+            loop {
+                if let Some(item) = pages.next_item() {
+                    return Some((Ok(item), (client, pages)));
+                }
+
+                let page = match pages.next_request()? {
+                    Ok(request) => client.execute(request).await,
+                    Err(err) => Err(err),
+                };
+                if let Err(err) = pages.receive(page) {
+                    return Some((Err(err), (client, pages)));
+                }
+            }
+        })
+    }
+
+    /// The emblems `card` creates. They're fetched from the card's related cards where Scryfall lists them, and
+    /// otherwise, for a planeswalker whose text mentions an emblem, searched for by its planeswalker type, which can
+    /// also turn up emblems made by other cards of the same planeswalker.
+    pub async fn emblems(&self, card: &Card) -> Result<Vec<Card>, Error> {
+        let related = card.related_emblems();
+        if !related.is_empty() {
+            let mut emblems = Vec::new();
+            for part in related {
+                emblems.push(self.card_by_id(&part.id).await?);
+            }
+            return Ok(emblems);
+        }
+
+        match emblem_query(card) {
+            Some(query) => self.search_all(&query).try_collect().await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// How long Scryfall takes to answer a small request, for services deciding whether to go into an offline mode.
+    /// The request isn't retried, and neither waiting for the rate limiter nor the client's timeout is part of the
+    /// latency, though the timeout still applies. A stale answer from a
+    /// [`CircuitBreaker`](breaker::CircuitBreaker) fails with [`Error::Unavailable`], since it means Scryfall wasn't
+    /// reached.
+    pub async fn ping(&self) -> Result<Duration, Error> {
+        let ping = async {
+            let mut request = Request::get(&["bulk-data"]).to_http(&self.inner.base_url);
+            request.add_default_headers(&self.inner.headers);
+            self.wait_turn().await;
+
+            let start = Instant::now();
+            let response = self.inner.transport.send(request).await?;
+            let latency = start.elapsed();
+            if response.source == ResponseSource::Stale {
+                return Err(Error::Unavailable);
+            }
+            error::decode::<IgnoredAny>(response.status, &response.body)?;
+            Ok(latency)
+        };
+        match self.timeout.or(self.inner.timeout) {
+            Some(timeout) => tokio::time::timeout(timeout, ping)
+                .await
+                .unwrap_or(Err(Error::Timeout)),
+            None => ping.await,
+        }
+    }
+
+    /// Every dungeon card, such as those from Adventures in the Forgotten Realms and the Undercity.
+    pub async fn dungeons(&self) -> Result<Vec<Card>, Error> {
+        self.search_all("t:dungeon include:extras")
+            .try_collect()
+            .await
+    }
+
+    /// The request `call` would send first, with every header and the body, without sending it, so request shapes can
+    /// be checked or debugged. `call` is given a handle to this client that stops at its first request, so calls that
+    /// send several requests only show the first. `Ok(None)` means `call` finished without sending anything, and an
+    /// error is one `call` hit before it got that far, such as an invalid search query.
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), scryfall::client::Error> {
+    /// let client = scryfall::client::Client::new();
+    /// let request = client
+    ///     .dry_run(|client| async move { client.card_named_fuzzy("jac bele", None).await })
+    ///     .await?
+    ///     .unwrap();
+    /// assert_eq!("https://api.scryfall.com/cards/named?fuzzy=jac%20bele", request.url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dry_run<F, Fut, T>(&self, call: F) -> Result<Option<HttpRequest>, Error>
+    where
+        F: FnOnce(Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let (client, captured) = self.dry_run_handle();
+        let result = call(client).await;
+        Client::dry_run_result(&captured, result)
+    }
+
+    /// A handle to this client that captures its first request instead of sending it, and where it's captured.
+    pub(crate) fn dry_run_handle(&self) -> (Client, Arc<Mutex<Option<HttpRequest>>>) {
+        let captured = Arc::new(Mutex::new(None));
+        let client = Client {
+            dry_run: Some(Arc::clone(&captured)),
+            ..self.clone()
+        };
+        (client, captured)
+    }
+
+    /// The outcome of a dry run that captured into `captured` and ended with `result`.
+    pub(crate) fn dry_run_result<T>(
+        captured: &Mutex<Option<HttpRequest>>,
+        result: Result<T, Error>,
+    ) -> Result<Option<HttpRequest>, Error> {
+        match (captured.lock().unwrap().take(), result) {
+            (Some(request), _) => Ok(Some(request)),
+            (None, Ok(_)) => Ok(None),
+            (None, Err(err)) => Err(err),
+        }
+    }
+
+    /// Runs `call` on a handle to this client that records every response it receives, including ones that were
+    /// retried, and returns the call's result with what was recorded.
+    ///
+    /// ```no_run
+    /// # async fn example() {
+    /// let client = scryfall::client::Client::new();
+    /// let (card, meta) = client
+    ///     .with_meta(|client| async move { client.card_named_exact("Lightning Bolt", None).await })
+    ///     .await;
+    /// for response in meta {
+    ///     println!("{} {} in {:?}, stale: {}", response.status, response.url, response.elapsed, response.is_stale());
+    /// }
+    /// # }
+    /// ```
+    pub async fn with_meta<F, Fut, T>(&self, call: F) -> (Result<T, Error>, Vec<ResponseMeta>)
+    where
+        F: FnOnce(Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let (client, recorded) = self.meta_handle();
+        let result = call(client).await;
+        let meta = recorded.lock().unwrap().drain(..).collect();
+        (result, meta)
+    }
+
+    /// A handle to this client that records the responses it receives, and where they're recorded.
+    pub(crate) fn meta_handle(&self) -> (Client, Arc<Mutex<Vec<ResponseMeta>>>) {
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let client = Client {
+            meta: Some(Arc::clone(&recorded)),
+            ..self.clone()
+        };
+        (client, recorded)
+    }
+
+    /// Waits until the rate limiter, and the tenant's budget if this handle acts for one, allow another request.
+    async fn wait_turn(&self) {
+        if let Some(tenant) = &self.tenant {
+            tenant.acquire().await;
+        }
+        self.inner.rate_limiter.acquire().await;
+    }
+
+    /// Sends `request` and decodes the response, retrying transient failures as the retry policy allows. The timeout
+    /// is measured in real time, whatever clock the client was built with.
+    async fn execute<T: DeserializeOwned>(&self, request: Request) -> Result<T, Error> {
+        match self.timeout.or(self.inner.timeout) {
+            Some(timeout) => {
+                let deadline = Instant::now() + timeout;
+                tokio::time::timeout(timeout, self.send(request, Some(deadline)))
+                    .await
+                    .unwrap_or(Err(Error::Timeout))
+            }
+            None => self.send(request, None).await,
+        }
+    }
+
+    /// Sends `request` with retries. A retry that couldn't be sent before `deadline` fails with [`Error::Timeout`]
+    /// at once, rather than waiting for the deadline.
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: Request,
+        deadline: Option<Instant>,
+    ) -> Result<T, Error> {
+        let mut request = request.to_http(&self.inner.base_url);
+        request.add_default_headers(&self.inner.headers);
+        if let Some(captured) = &self.dry_run {
+            *captured.lock().unwrap() = Some(request);
+            return Err(Error::DryRun);
+        }
+
+        let mut attempt = 1;
+        loop {
+            self.wait_turn().await;
+
+            request.attempt = attempt;
+            let start = Instant::now();
+            let response = self.inner.transport.send(request.clone()).await?;
+            if let Some(meta) = &self.meta {
+                let elapsed = start.elapsed();
+                meta.lock().unwrap().push(ResponseMeta::new(
+                    &request.url,
+                    attempt,
+                    elapsed,
+                    &response,
+                ));
+            }
+            let retry_after = response.header("Retry-After");
+            // An HTTP date in Retry-After is relative to the server's clock, which its Date header reports.
+            let now = response
+                .header("Date")
+                .and_then(retry::parse_http_date)
+                .unwrap_or_else(SystemTime::now);
+            match self
+                .inner
+                .retry
+                .delay(attempt, response.status, retry_after, now)
+            {
+                Some(delay)
+                    if deadline.is_some_and(|deadline| Instant::now() + delay >= deadline) =>
+                {
+                    return Err(Error::Timeout)
+                }
+                Some(delay) => self.inner.clock.sleep(delay).await,
+                None => return error::decode(response.status, &response.body),
+            }
+            attempt += 1;
+        }
+    }
+}
+
+/// The search for emblems made by planeswalkers of `card`'s planeswalker type, if it's a planeswalker whose text
+/// mentions an emblem.
+fn emblem_query(card: &Card) -> Option<String> {
+    let mentions_emblem = card
+        .combined_oracle_text()
+        .is_some_and(|text| text.to_lowercase().contains("emblem"));
+    if !mentions_emblem {
+        return None;
+    }
+
+    let type_line = card.combined_type_line();
+    let planeswalker = type_line
+        .split(" // ")
+        .find(|type_line| type_line.contains("Planeswalker"))?;
+    let (_, subtypes) = planeswalker.split_once('—')?;
+    let subtype = subtypes.split_whitespace().last()?;
+    Some(format!(
+        "t:emblem t:{} include:extras",
+        subtype.to_lowercase()
+    ))
+}
+
+mod tests {
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_card_by_id() {
+        use super::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let card = client
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await
+            .unwrap();
+        assert_eq!("Lightning Bolt", card.name);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_not_found() {
+        use super::{Client, Error};
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_string(
+                r#"{"object": "error", "code": "not_found", "status": 404, "details": "No card found with the given ID."}"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        match client
+            .card_by_id("00000000-0000-0000-0000-000000000000")
+            .await
+        {
+            Err(Error::Api(err)) => assert_eq!("not_found", err.code),
+            other => panic!(
+                "expected an API error, got {:?}",
+                other.map(|card| card.name)
+            ),
+        }
+    }
+
+    #[test]
+    fn test_client_is_shareable() {
+        use super::Client;
+
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<Client>();
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_clones_share_requests() {
+        use super::clock::VirtualClock;
+        use super::ClientBuilder;
+        use std::sync::Arc;
+        use std::time::Duration;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .expect(100)
+            .mount(&server)
+            .await;
+
+        // Every clone waits on the same rate limiter, so the requests are spread out rather than all sent at once.
+        let clock = Arc::new(VirtualClock::new());
+        let client = ClientBuilder::new()
+            .base_url(&server.uri())
+            .clock(Arc::clone(&clock))
+            .build();
+        let tasks: Vec<_> = (0..100)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    client
+                        .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+                        .await
+                        .map(|card| card.name)
+                })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!("Lightning Bolt", task.await.unwrap().unwrap());
+        }
+        assert!(clock.elapsed() >= Duration::from_millis(99 * 100));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_card_named() {
+        use super::Client;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/named"))
+            .and(query_param("exact", "Lightning Bolt"))
+            .and(query_param("set", "m10"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/named"))
+            .and(query_param("fuzzy", "lightnin bol"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let card = client
+            .card_named_exact("Lightning Bolt", Some("m10"))
+            .await
+            .unwrap();
+        assert_eq!("Lightning Bolt", card.name);
+        let card = client.card_named_fuzzy("lightnin bol", None).await.unwrap();
+        assert_eq!("Lightning Bolt", card.name);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_search() {
+        use super::Client;
+        use crate::testing;
+        use crate::types::search::{SearchParams, SortOrder};
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "bolt is:bolt"))
+            .and(query_param("order", "cmc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 1,
+                "has_more": false,
+                "data": [testing::card_json(serde_json::json!({}))],
+                "warnings": ["Invalid expression “is:bolt” was ignored. Checking if cards are “bolt” is not supported"],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let params = SearchParams {
+            order: Some(SortOrder::Cmc),
+            ..SearchParams::default()
+        };
+        let list = client.search("bolt is:bolt", &params).await.unwrap();
+        assert_eq!(Some(1), list.total_cards);
+        assert_eq!("Lightning Bolt", list.data[0].name);
+        assert_eq!(1, list.warnings.unwrap().len());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_random_card() {
+        use super::{Client, Error};
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/random"))
+            .and(query_param("q", "is:commander"))
+            .respond_with(ResponseTemplate::new(404).set_body_string(
+                r#"{"object": "error", "code": "not_found", "status": 404, "details": "No cards found."}"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/random"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        assert_eq!("Lightning Bolt", client.random_card().await.unwrap().name);
+        assert!(matches!(
+            client.random_card_filtered("is:commander").await,
+            Err(Error::Api(_))
+        ));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_card_by_external_id() {
+        use super::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for endpoint in &[
+            "/cards/multiverse/191089",
+            "/cards/mtgo/35192",
+            "/cards/arena/67534",
+            "/cards/tcgplayer/33372",
+            "/cards/cardmarket/21136",
+        ] {
+            Mock::given(method("GET"))
+                .and(path(*endpoint))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = Client::with_base_url(&server.uri());
+        let cards = [
+            client.card_by_multiverse_id(191089).await.unwrap(),
+            client.card_by_mtgo_id(35192).await.unwrap(),
+            client.card_by_arena_id(67534).await.unwrap(),
+            client.card_by_tcgplayer_id(33372).await.unwrap(),
+            client.card_by_cardmarket_id(21136).await.unwrap(),
+        ];
+        assert!(cards.iter().all(|card| card.name == "Lightning Bolt"));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_invalid_query_is_not_sent() {
+        use super::{Client, Error};
+        use crate::query::QueryError;
+        use crate::types::search::SearchParams;
+        use wiremock::MockServer;
+
+        let server = MockServer::start().await;
+        let client = Client::with_base_url(&server.uri());
+        assert!(matches!(
+            client.search("(t:goblin", &SearchParams::default()).await,
+            Err(Error::Query(QueryError::UnbalancedParen(0)))
+        ));
+        assert!(matches!(
+            client.random_card_filtered("").await,
+            Err(Error::Query(QueryError::Empty))
+        ));
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_card_by_set_number() {
+        use super::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for endpoint in &["/cards/m10/146", "/cards/war/1%E2%98%85/ja"] {
+            Mock::given(method("GET"))
+                .and(path(*endpoint))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = Client::with_base_url(&server.uri());
+        client.card_by_set_number("m10", "146", None).await.unwrap();
+        client
+            .card_by_set_number("war", "1★", Some("ja"))
+            .await
+            .unwrap();
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_estimate() {
+        use super::Client;
+        use crate::types::search::SearchParams;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "t:goblin"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 1200,
+                "has_more": true,
+                "next_page": "https://api.scryfall.com/cards/search?page=2&q=t%3Agoblin",
+                "data": [],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .respond_with(ResponseTemplate::new(404).set_body_string(
+                r#"{"object": "error", "code": "not_found", "status": 404, "details": "No cards found."}"#,
+            ))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let estimate = client
+            .estimate("t:goblin", &SearchParams::default())
+            .await
+            .unwrap();
+        assert_eq!(1200, estimate.total_cards);
+        assert_eq!(7, estimate.pages);
+        assert!(estimate.exceeds(5));
+        assert_eq!(0, client.count("t:nothing").await.unwrap());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_collection() {
+        use super::Client;
+        use crate::testing;
+        use crate::types::identifier::CardIdentifier;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/cards/collection"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "not_found": [{"name": "Lightning Blot"}],
+                "data": [testing::card_json(serde_json::json!({}))],
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let identifiers = vec![CardIdentifier::Name("Lightning Bolt".to_string()); 80];
+        let collection = client.collection(&identifiers).await.unwrap();
+        assert_eq!(2, collection.cards.len());
+        assert_eq!(2, collection.not_found.len());
+
+        let requests = server.received_requests().await.unwrap();
+        let sizes: Vec<usize> = requests
+            .iter()
+            .map(|request| {
+                let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                body["identifiers"].as_array().unwrap().len()
+            })
+            .collect();
+        assert_eq!(vec![75, 5], sizes);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_sample() {
+        use super::Client;
+        use crate::testing;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let data: Vec<_> = (0..3)
+            .map(|i| testing::card_json(serde_json::json!({ "name": format!("Card {}", i) })))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 3,
+                "has_more": false,
+                "data": data,
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let cards = client.sample("t:goblin", 2).await.unwrap();
+        assert_eq!(2, cards.len());
+        assert_ne!(cards[0].name, cards[1].name);
+
+        let mut names: Vec<_> = client
+            .sample("t:goblin", 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|card| card.name)
+            .collect();
+        names.sort();
+        assert_eq!(vec!["Card 0", "Card 1", "Card 2"], names);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_cards_added_since() {
+        use super::{Client, SpoilerFeed};
+        use crate::testing;
+        use crate::types::date::Date;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let data = vec![
+            testing::card_json(serde_json::json!({
+                "name": "New",
+                "released_at": "2024-08-02",
+                "preview": {"previewed_at": "2024-07-09"},
+            })),
+            testing::card_json(serde_json::json!({
+                "id": "0000579f-7b35-4ed3-b44c-db2a538066fe",
+                "name": "Old",
+                "released_at": "2024-08-02",
+                "preview": {"previewed_at": "2024-06-20"},
+            })),
+        ];
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "date>=2024-07-01"))
+            .and(query_param("order", "spoiled"))
+            .and(query_param("dir", "desc"))
+            .and(query_param("unique", "prints"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 2,
+                "has_more": false,
+                "data": data,
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let since = Date {
+            year: 2024,
+            month: 7,
+            day: 1,
+        };
+        let mut feed = SpoilerFeed::new();
+        let cards = feed.update(client.cards_added_since(since).await.unwrap());
+        assert_eq!(
+            vec!["New"],
+            cards
+                .iter()
+                .map(|card| card.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(feed
+            .update(client.cards_added_since(since).await.unwrap())
+            .is_empty());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_search_cached() {
+        use super::ClientBuilder;
+        use crate::cache::QueryCache;
+        use crate::storage::MemoryStorage;
+        use crate::types::search::SearchParams;
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 1,
+                "has_more": false,
+                "data": [crate::testing::card_json(serde_json::json!({}))],
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = ClientBuilder::new()
+            .base_url(&server.uri())
+            .query_cache(QueryCache::new(
+                MemoryStorage::new(),
+                Duration::from_secs(60),
+            ))
+            .build();
+        let params = SearchParams::default();
+        for query in &["t:instant c:r", "C:R t:instant"] {
+            let cards = client.search_cached(query, &params).await.unwrap();
+            assert_eq!(
+                vec!["Lightning Bolt"],
+                cards
+                    .iter()
+                    .map(|card| card.name.as_str())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        // A different search misses.
+        client.search_cached("t:sorcery", &params).await.unwrap();
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_autocomplete() {
+        use super::Client;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/autocomplete"))
+            .and(query_param("q", "lightning b"))
+            .and(query_param("include_extras", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "catalog",
+                "total_values": 2,
+                "data": ["Lightning Bolt", "Lightning Blast"],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let catalog = client.autocomplete("lightning b", true).await.unwrap();
+        assert_eq!(vec!["Lightning Bolt", "Lightning Blast"], catalog.data);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_sets() {
+        use super::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let set: serde_json::Value =
+            serde_json::from_str(include_str!("../../testdata/sets/bloomburrow.json")).unwrap();
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/sets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": false,
+                "data": [set],
+            })))
+            .mount(&server)
+            .await;
+        for endpoint in &["/sets/blb", "/sets/tcgplayer/23736"] {
+            Mock::given(method("GET"))
+                .and(path(*endpoint))
+                .respond_with(ResponseTemplate::new(200).set_body_json(&set))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = Client::with_base_url(&server.uri());
+        assert_eq!("blb", client.sets().await.unwrap().data[0].code);
+        assert_eq!("Bloomburrow", client.set_by_code("blb").await.unwrap().name);
+        assert_eq!("blb", client.set_by_tcgplayer_id(23736).await.unwrap().code);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_rulings() {
+        use super::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for endpoint in &[
+            "/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d/rulings",
+            "/cards/multiverse/191089/rulings",
+            "/cards/mtgo/35192/rulings",
+            "/cards/arena/67534/rulings",
+            "/cards/m10/146/rulings",
+        ] {
+            Mock::given(method("GET"))
+                .and(path(*endpoint))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "object": "list",
+                    "has_more": false,
+                    "data": [{
+                        "object": "ruling",
+                        "oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070",
+                        "source": "wotc",
+                        "published_at": "2004-10-04",
+                        "comment": "It can target a planeswalker.",
+                    }],
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = Client::with_base_url(&server.uri());
+        let lists = [
+            client
+                .rulings_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+                .await
+                .unwrap(),
+            client.rulings_by_multiverse_id(191089).await.unwrap(),
+            client.rulings_by_mtgo_id(35192).await.unwrap(),
+            client.rulings_by_arena_id(67534).await.unwrap(),
+            client.rulings_by_set_number("m10", "146").await.unwrap(),
+        ];
+        assert!(lists
+            .iter()
+            .all(|list| list.data[0].comment == "It can target a planeswalker."));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_symbology() {
+        use super::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/symbology"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": false,
+                "data": [{
+                    "object": "card_symbol",
+                    "symbol": "{T}",
+                    "svg_uri": "https://svgs.scryfall.io/card-symbols/T.svg",
+                    "loose_variant": null,
+                    "english": "tap this permanent",
+                    "transposable": false,
+                    "represents_mana": false,
+                    "appears_in_mana_costs": false,
+                    "mana_value": 0.0,
+                    "hybrid": false,
+                    "phyrexian": false,
+                    "cmc": 0.0,
+                    "funny": false,
+                    "colors": [],
+                    "gatherer_alternates": ["ocT", "oT"],
+                }],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let symbols = client.symbology().await.unwrap();
+        assert_eq!("{T}", symbols.data[0].symbol);
+        assert!(!symbols.data[0].represents_mana);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_parse_mana() {
+        use super::Client;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/symbology/parse-mana"))
+            .and(query_param("cost", "RUx"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "mana_cost",
+                "cost": "{X}{U}{R}",
+                "colors": ["U", "R"],
+                "cmc": 2.0,
+                "colorless": false,
+                "monocolored": false,
+                "multicolored": true,
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let info = client.parse_mana("RUx").await.unwrap();
+        assert_eq!("{X}{U}{R}", info.cost);
+        assert!(info.multicolored);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_catalog() {
+        use super::Client;
+        use crate::types::catalog::CatalogKind;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/catalog/planeswalker-types"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "catalog",
+                "uri": "https://api.scryfall.com/catalog/planeswalker-types",
+                "total_values": 2,
+                "data": ["Ajani", "Jace"],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let catalog = client
+            .catalog(CatalogKind::PlaneswalkerTypes)
+            .await
+            .unwrap();
+        assert_eq!(vec!["Ajani", "Jace"], catalog.data);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_bulk_data_by_type() {
+        use super::Client;
+        use crate::bulk::BulkKind;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bulk-data/rulings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "bulk_data",
+                "id": "afbd9f27-a4b3-4b4b-b6b9-c7b1e1b0e5a0",
+                "type": "rulings",
+                "updated_at": "2024-08-01T21:01:24.597+00:00",
+                "uri": "https://api.scryfall.com/bulk-data/afbd9f27-a4b3-4b4b-b6b9-c7b1e1b0e5a0",
+                "name": "Rulings",
+                "description": "A JSON file containing all Rulings on Scryfall.",
+                "size": 23397185,
+                "download_uri": "https://data.scryfall.io/rulings/rulings-20240801210124.json",
+                "content_type": "application/json",
+                "content_encoding": "gzip",
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let bulk_data = client.bulk_data_by_type(BulkKind::Rulings).await.unwrap();
+        assert_eq!(BulkKind::Rulings, bulk_data.kind);
+        assert_eq!(23397185, bulk_data.size);
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_search_all() {
+        use super::Client;
+        use futures_util::StreamExt;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let bolt: serde_json::Value =
+            serde_json::from_str(include_str!("../../testdata/cards/lightning_bolt.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 3,
+                "has_more": false,
+                "data": [bolt],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "t:instant"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "total_cards": 3,
+                "has_more": true,
+                "next_page": "https://api.scryfall.com/cards/search?q=t%3Ainstant&page=2",
+                "data": [bolt, bolt],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let cards: Vec<_> = client.search_all("t:instant").collect().await;
+        assert_eq!(3, cards.len());
+        assert!(cards
+            .iter()
+            .all(|card| card.as_ref().unwrap().name == "Lightning Bolt"));
+
+        let errors: Vec<_> = client.search_all("t:instant)").collect().await;
+        assert_eq!(1, errors.len());
+        assert!(errors[0].is_err());
+    }
+
+    #[test]
+    fn test_emblem_query() {
+        use super::emblem_query;
+        use crate::testing;
+        use serde_json::json;
+
+        let elspeth = testing::card_with(json!({
+            "type_line": "Legendary Planeswalker — Elspeth",
+            "oracle_text": "−8: You get an emblem with \"Artifacts, creatures, enchantments, and lands you control have indestructible.\"",
+        }));
+        assert_eq!(
+            Some("t:emblem t:elspeth include:extras".to_string()),
+            emblem_query(&elspeth)
+        );
+        assert_eq!(None, emblem_query(&testing::card()));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_dungeons() {
+        use super::Client;
+        use crate::testing;
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "t:dungeon include:extras"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "total_cards": 1,
+                "has_more": false,
+                "data": [testing::card_json(json!({
+                    "name": "Tomb of Annihilation",
+                    "type_line": "Dungeon",
+                    "layout": "token",
+                }))],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let dungeons = client.dungeons().await.unwrap();
+        assert!(dungeons.iter().all(|card| card.is_dungeon()));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_timeout() {
+        use super::{Client, ClientBuilder, Error};
+        use std::time::Duration;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json"))
+                    .set_delay(Duration::from_millis(300)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let result = client
+            .timeout(Duration::from_millis(20))
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await;
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert!(client
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await
+            .is_ok());
+
+        let client = ClientBuilder::new()
+            .base_url(&server.uri())
+            .timeout(Duration::from_millis(20))
+            .build();
+        let result = client
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await;
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert!(client
+            .timeout(Duration::from_secs(5))
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await
+            .is_ok());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_fetch() {
+        use super::Client;
+        use crate::testing;
+        use crate::types::card::Card;
+        use crate::types::list::List;
+        use crate::types::ruling::Ruling;
+        use futures_util::TryStreamExt;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let bolt: serde_json::Value =
+            serde_json::from_str(include_str!("../../testdata/cards/lightning_bolt.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d/rulings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": false,
+                "data": [{
+                    "object": "ruling",
+                    "oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070",
+                    "source": "wotc",
+                    "published_at": "2009-07-15",
+                    "comment": "The damage is dealt by Lightning Bolt.",
+                }],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("unique", "prints"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": false,
+                "data": [bolt],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "oracleid:4457ed35-7c10-48c8-9776-456485fdf070"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": true,
+                "next_page": "https://api.scryfall.com/cards/search?q=oracleid%3A4457ed35-7c10-48c8-9776-456485fdf070&unique=prints&page=2",
+                "data": [bolt],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let card = testing::card();
+        let rulings: List<Ruling> = client.fetch(&card.rulings_uri).await.unwrap();
+        assert_eq!(1, rulings.data.len());
+
+        let prints: Vec<Card> = client
+            .fetch_all(&card.prints_search_uri)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(2, prints.len());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_resolve() {
+        use super::{Client, Error};
+        use crate::testing;
+        use wiremock::matchers::{body_partial_json, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let bolt = testing::card_json(serde_json::json!({}));
+        let counterspell = testing::card_json(serde_json::json!({
+            "id": "0df55e3b-8a4b-4b4b-8f0e-1b0e1e5b1b0e",
+            "name": "Counterspell",
+            "set": "mh2",
+            "collector_number": "267",
+        }));
+        Mock::given(method("POST"))
+            .and(path("/cards/collection"))
+            .and(body_partial_json(serde_json::json!({
+                "identifiers": [
+                    {"set": "m10", "collector_number": "146"},
+                    {"name": "Counterspell"},
+                    {"name": "jac bele"},
+                    {"name": "Lightning Blot"},
+                ],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "not_found": [{"name": "jac bele"}, {"name": "Lightning Blot"}],
+                "data": [bolt, counterspell],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/named"))
+            .and(query_param("fuzzy", "jac bele"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(testing::card_json(
+                serde_json::json!({"name": "Jace Beleren"}),
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let results = client
+            .resolve(&[
+                "m10/146",
+                "!Counterspell",
+                "jac bele",
+                "\"Lightning Blot\"",
+                "https://example.com/",
+            ])
+            .await
+            .unwrap();
+        let names: Vec<_> = results
+            .iter()
+            .map(|result| result.as_ref().ok().map(|card| card.name.as_str()))
+            .collect();
+        assert_eq!(
+            vec![
+                Some("Lightning Bolt"),
+                Some("Counterspell"),
+                Some("Jace Beleren"),
+                None,
+                None
+            ],
+            names
+        );
+        assert!(matches!(&results[3], Err(Error::Api(err)) if err.code == "not_found"));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_dry_run() {
+        use super::{Client, ClientBuilder, Error};
+        use crate::client::fault::FaultInjector;
+        use crate::client::transport::{Method, ReqwestTransport};
+        use crate::types::identifier::CardIdentifier;
+        use crate::types::search::SearchParams;
+        use std::sync::Arc;
+
+        let faults = Arc::new(FaultInjector::new(ReqwestTransport::new()));
+        let client = ClientBuilder::new()
+            .transport(Arc::clone(&faults))
+            .user_agent("deckbuilder/1.0")
+            .build();
+
+        let request = client
+            .dry_run(
+                |client| async move { client.card_named_exact("Fire // Ice", Some("mh2")).await },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(Method::Get, request.method);
+        assert_eq!(
+            "https://api.scryfall.com/cards/named?exact=Fire%20%2F%2F%20Ice&set=mh2",
+            request.url
+        );
+        assert!(request
+            .headers
+            .contains(&("User-Agent".to_string(), "deckbuilder/1.0".to_string())));
+
+        let request = client
+            .dry_run(|client| async move {
+                client
+                    .collection(&[CardIdentifier::Name("Lightning Bolt".to_string())])
+                    .await
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(Method::Post, request.method);
+        assert_eq!(
+            Some(br#"{"identifiers":[{"name":"Lightning Bolt"}]}"#.to_vec()),
+            request.body
+        );
+
+        let invalid = client
+            .dry_run(|client: Client| async move {
+                client.search("t:goblin)", &SearchParams::default()).await
+            })
+            .await;
+        assert!(matches!(invalid, Err(Error::Query(_))));
+        assert_eq!(0, faults.requests());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_page() {
+        use super::{Client, PageCursor};
+        use crate::types::list::CardList;
+        use crate::types::search::SearchParams;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let bolt: serde_json::Value =
+            serde_json::from_str(include_str!("../../testdata/cards/lightning_bolt.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": false,
+                "data": [bolt],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "t:instant"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": true,
+                "next_page": "https://api.scryfall.com/cards/search?q=t%3Ainstant&page=2",
+                "data": [bolt],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // A crawl stopped after the first page resumes from its saved cursor.
+        let client = Client::with_base_url(&server.uri());
+        let first: CardList = client
+            .page(&PageCursor::search("t:instant", &SearchParams::default()))
+            .await
+            .unwrap();
+        let saved = serde_json::to_string(&PageCursor::after(&first).unwrap()).unwrap();
+
+        let resumed: PageCursor = serde_json::from_str(&saved).unwrap();
+        let second: CardList = client.page(&resumed).await.unwrap();
+        assert_eq!(1, second.data.len());
+        assert_eq!(None, PageCursor::after(&second));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_with_meta() {
+        use super::clock::VirtualClock;
+        use super::transport::ResponseSource;
+        use super::ClientBuilder;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "1")
+                    .insert_header("X-RateLimit-Remaining", "0"),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ClientBuilder::new()
+            .base_url(&server.uri())
+            .clock(VirtualClock::new())
+            .build();
+        let (card, meta) = client
+            .with_meta(|client| async move {
+                client
+                    .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+                    .await
+            })
+            .await;
+        assert_eq!("Lightning Bolt", card.unwrap().name);
+        assert_eq!(2, meta.len());
+        assert_eq!((429, 1), (meta[0].status, meta[0].attempt));
+        assert_eq!(
+            vec![
+                ("retry-after".to_string(), "1".to_string()),
+                ("x-ratelimit-remaining".to_string(), "0".to_string())
+            ],
+            meta[0].rate_limit_headers
+        );
+        assert_eq!((200, 2), (meta[1].status, meta[1].attempt));
+        assert_eq!(ResponseSource::Network, meta[1].source);
+        assert!(!meta[1].from_cache());
+        assert_eq!(
+            format!(
+                "{}/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d",
+                server.uri()
+            ),
+            meta[1].url
+        );
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_ping() {
+        use super::{Client, Error};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/bulk-data"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": false,
+                "data": [],
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/bulk-data"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        assert!(client.ping().await.is_ok());
+        assert!(matches!(client.ping().await, Err(Error::Status(503))));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_retry_after_date() {
+        use super::clock::VirtualClock;
+        use super::ClientBuilder;
+        use std::sync::Arc;
+        use std::time::Duration;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(503)
+                    .insert_header("Date", "Sun, 06 Nov 1994 08:49:35 GMT")
+                    .insert_header("Retry-After", "Sun, 06 Nov 1994 08:49:37 GMT"),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let clock = Arc::new(VirtualClock::new());
+        let client = ClientBuilder::new()
+            .base_url(&server.uri())
+            .clock(Arc::clone(&clock))
+            .build();
+        let card = client
+            .card_by_id("e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+            .await
+            .unwrap();
+        assert_eq!("Lightning Bolt", card.name);
+        assert_eq!(vec![Duration::from_secs(2)], clock.sleeps());
+    }
+}
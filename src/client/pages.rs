@@ -0,0 +1,177 @@
+use super::error::Error;
+use super::request::Request;
+use super::BASE_URL;
+use crate::types::list::List;
+use crate::types::search::SearchParams;
+use crate::types::uri::Uri;
+use serde::{Deserialize, Serialize};
+use std::vec;
+
+/// Where a walk over a paginated list can pick up again: the link to the next page to fetch. Cursors serialize as
+/// that link, so a long crawl can save its cursor after each page and resume from it after a restart, with the same
+/// query and ordering.
+///
+/// ```no_run
+/// # async fn example() -> Result<(), scryfall::client::Error> {
+/// use scryfall::client::{Client, PageCursor};
+/// use scryfall::types::list::CardList;
+/// use scryfall::types::search::SearchParams;
+///
+/// let client = Client::new();
+/// let mut cursor = PageCursor::search("t:goblin", &SearchParams::default());
+/// loop {
+///     let page: CardList = client.page(&cursor).await?;
+///     // Process the page, then save the next cursor with serde_json::to_string.
+///     match PageCursor::after(&page) {
+///         Some(next) => cursor = next,
+///         None => break,
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct PageCursor(Uri);
+
+impl PageCursor {
+    /// A cursor at the page `uri` links to.
+    pub fn new(uri: Uri) -> Self {
+        PageCursor(uri)
+    }
+
+    /// A cursor at the first page of the search for `query`.
+    pub fn search(query: &str, params: &SearchParams) -> Self {
+        let url = Request::search(query, params).url(BASE_URL);
+        PageCursor(url.parse().expect("search URLs are valid URIs"))
+    }
+
+    /// A cursor at the page after `list`, or `None` if it's the last page.
+    pub fn after<T>(list: &List<T>) -> Option<Self> {
+        list.next_page
+            .clone()
+            .filter(|_| list.has_more)
+            .map(PageCursor)
+    }
+
+    /// The link to the page.
+    pub fn uri(&self) -> &Uri {
+        &self.0
+    }
+}
+
+/// How far a walk over a paginated list has got, independent of how pages are fetched. The async stream and the
+/// blocking iterator both drive one of these.
+pub(crate) struct Pages<T> {
+    items: vec::IntoIter<T>,
+    next: Option<Result<Request, Error>>,
+}
+
+impl<T> Pages<T> {
+    /// A walk starting with the page already fetched as `list`.
+    pub fn new(list: List<T>) -> Self {
+        Pages {
+            next: PageCursor::after(&list).map(|cursor| Ok(Request::follow(cursor.uri()))),
+            items: list.data.into_iter(),
+        }
+    }
+
+    /// A walk whose first page is fetched with `request`. An error is yielded once, before anything is fetched.
+    pub fn starting_at(request: Result<Request, Error>) -> Self {
+        Pages {
+            items: Vec::new().into_iter(),
+            next: Some(request),
+        }
+    }
+
+    /// The next item on the current page, if there are any left.
+    pub fn next_item(&mut self) -> Option<T> {
+        self.items.next()
+    }
+
+    /// The request for the next page, or `None` once the walk is over.
+    pub fn next_request(&mut self) -> Option<Result<Request, Error>> {
+        self.next.take()
+    }
+
+    /// Takes in the result of fetching the next page. A search with no results just ends the walk, while any other
+    /// error is returned to be yielded, and ends the walk too.
+    pub fn receive(&mut self, page: Result<List<T>, Error>) -> Result<(), Error> {
+        match page {
+            Ok(list) => {
+                *self = Pages::new(list);
+                Ok(())
+            }
+            Err(Error::Api(err)) if err.code == "not_found" => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_pages() {
+        use super::Pages;
+        use crate::types::list::List;
+
+        let list: List<u32> = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "data": [1, 2],
+            "has_more": true,
+            "next_page": "https://api.scryfall.com/cards/search?q=t%3Agoblin&page=2",
+        }))
+        .unwrap();
+
+        let mut pages = Pages::new(list);
+        assert_eq!(Some(1), pages.next_item());
+        assert_eq!(Some(2), pages.next_item());
+        assert_eq!(None, pages.next_item());
+
+        let request = pages.next_request().unwrap().unwrap();
+        assert_eq!(vec!["cards", "search"], request.path);
+        assert_eq!(
+            vec![
+                ("q".to_string(), "t:goblin".to_string()),
+                ("page".to_string(), "2".to_string())
+            ],
+            request.query
+        );
+        assert!(pages.next_request().is_none());
+    }
+
+    #[test]
+    fn test_page_cursor() {
+        use super::PageCursor;
+        use crate::types::list::List;
+        use crate::types::search::SearchParams;
+
+        let cursor = PageCursor::search("t:goblin", &SearchParams::default());
+        assert_eq!(
+            "https://api.scryfall.com/cards/search?q=t%3Agoblin",
+            cursor.uri().as_str()
+        );
+
+        let list: List<u32> = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "data": [1],
+            "has_more": true,
+            "next_page": "https://api.scryfall.com/cards/search?q=t%3Agoblin&page=2",
+        }))
+        .unwrap();
+        let next = PageCursor::after(&list).unwrap();
+        let saved = serde_json::to_string(&next).unwrap();
+        assert_eq!(
+            r#""https://api.scryfall.com/cards/search?q=t%3Agoblin&page=2""#,
+            saved
+        );
+        assert_eq!(next, serde_json::from_str(&saved).unwrap());
+
+        let last: List<u32> = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "data": [],
+            "has_more": false,
+        }))
+        .unwrap();
+        assert_eq!(None, PageCursor::after(&last));
+    }
+}
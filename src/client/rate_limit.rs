@@ -0,0 +1,150 @@
+use super::clock::{Clock, SystemClock};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The spacing Scryfall asks for between requests.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A token bucket spacing out requests. It holds up to `burst` tokens and gains one every `interval`; each request
+/// spends one, and when none are left it waits for the next. A client's limiter is shared by all of its clones, so
+/// requests from every task count against the same budget.
+#[derive(Debug)]
+pub struct RateLimiter {
+    state: Mutex<State>,
+    clock: Arc<dyn Clock>,
+}
+
+#[derive(Debug)]
+struct State {
+    interval: Duration,
+    burst: u32,
+
+    /// The tokens available, which goes negative once requests are queued waiting for tokens.
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    /// A limiter allowing bursts of up to `burst` requests, then one more every `interval`.
+    pub fn new(interval: Duration, burst: u32) -> Self {
+        RateLimiter::with_clock(interval, burst, Arc::new(SystemClock))
+    }
+
+    /// A limiter like [`new`](Self::new) that tells time and waits with `clock`.
+    pub fn with_clock(interval: Duration, burst: u32, clock: Arc<dyn Clock>) -> Self {
+        RateLimiter {
+            state: Mutex::new(State {
+                interval,
+                burst,
+                tokens: f64::from(burst),
+                updated: clock.now(),
+            }),
+            clock,
+        }
+    }
+
+    /// A limiter that never waits.
+    pub fn unlimited() -> Self {
+        RateLimiter::new(Duration::ZERO, 1)
+    }
+
+    /// The time between requests once a burst is spent.
+    pub fn interval(&self) -> Duration {
+        self.state.lock().unwrap().interval
+    }
+
+    /// Changes the time between requests, for every clone of the client using this limiter.
+    pub fn set_interval(&self, interval: Duration) {
+        self.state.lock().unwrap().interval = interval;
+    }
+
+    /// Waits until a request may be sent, then spends a token for it.
+    pub async fn acquire(&self) {
+        let wait = self.reserve(self.clock.now());
+        if !wait.is_zero() {
+            self.clock.sleep(wait).await;
+        }
+    }
+
+    /// Spends a token for a request sent at `now`, returning how long the request must wait before it's sent.
+    pub(crate) fn reserve(&self, now: Instant) -> Duration {
+        let mut state = self.state.lock().unwrap();
+        if state.interval.is_zero() {
+            return Duration::ZERO;
+        }
+
+        if now > state.updated {
+            let refilled =
+                now.duration_since(state.updated).as_secs_f64() / state.interval.as_secs_f64();
+            state.tokens = (state.tokens + refilled).min(f64::from(state.burst));
+            state.updated = now;
+        }
+
+        state.tokens -= 1.0;
+        if state.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            state.interval.mul_f64(-state.tokens)
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    /// A limiter sending one request every [`DEFAULT_INTERVAL`].
+    fn default() -> Self {
+        RateLimiter::new(DEFAULT_INTERVAL, 1)
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_reserve() {
+        use super::RateLimiter;
+        use std::time::{Duration, Instant};
+
+        let limiter = RateLimiter::new(Duration::from_millis(100), 2);
+        let start = Instant::now();
+        assert_eq!(Duration::ZERO, limiter.reserve(start));
+        assert_eq!(Duration::ZERO, limiter.reserve(start));
+        assert_eq!(Duration::from_millis(100), limiter.reserve(start));
+        assert_eq!(Duration::from_millis(200), limiter.reserve(start));
+
+        // After a long pause the bucket is full again, but no fuller than its burst.
+        let later = start + Duration::from_secs(10);
+        assert_eq!(Duration::ZERO, limiter.reserve(later));
+        assert_eq!(Duration::ZERO, limiter.reserve(later));
+        assert_eq!(Duration::from_millis(100), limiter.reserve(later));
+    }
+
+    #[test]
+    fn test_unlimited() {
+        use super::RateLimiter;
+        use std::time::{Duration, Instant};
+
+        let limiter = RateLimiter::unlimited();
+        let now = Instant::now();
+        for _ in 0..10 {
+            assert_eq!(Duration::ZERO, limiter.reserve(now));
+        }
+
+        limiter.set_interval(Duration::from_millis(50));
+        assert_eq!(Duration::from_millis(50), limiter.interval());
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_acquire() {
+        use super::RateLimiter;
+        use crate::client::clock::VirtualClock;
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        let clock = Arc::new(VirtualClock::new());
+        let limiter = RateLimiter::with_clock(Duration::from_millis(100), 2, clock.clone());
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert_eq!(Duration::from_millis(300), clock.elapsed());
+        assert_eq!(vec![Duration::from_millis(100); 3], clock.sleeps());
+    }
+}
@@ -0,0 +1,242 @@
+use super::transport::{HttpRequest, Method};
+use crate::types::search::SearchParams;
+use crate::types::uri::{percent_encode, Uri};
+use serde_json::Value;
+
+/// A description of one API call, independent of how it's sent, so that what a call asks for can be built and checked
+/// apart from the transport that sends it.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Request {
+    /// The path segments, unencoded, e.g. `["cards", "m10", "146"]`.
+    pub path: Vec<String>,
+
+    /// The query parameters, unencoded.
+    pub query: Vec<(String, String)>,
+
+    /// The JSON body. Requests with a body are sent as `POST`, and the rest as `GET`.
+    pub body: Option<Value>,
+
+    /// Whether the path came from a link in a response, and so may already start with the base URL's own path.
+    pub linked: bool,
+}
+
+impl Request {
+    /// A `GET` request for the path made of `segments`.
+    pub fn get<S: AsRef<str>>(segments: &[S]) -> Self {
+        Request {
+            path: segments
+                .iter()
+                .map(|segment| segment.as_ref().to_string())
+                .collect(),
+            query: Vec::new(),
+            body: None,
+            linked: false,
+        }
+    }
+
+    /// A `POST` request for the path made of `segments`, sending `body`.
+    pub fn post<S: AsRef<str>>(segments: &[S], body: Value) -> Self {
+        Request {
+            body: Some(body),
+            ..Request::get(segments)
+        }
+    }
+
+    /// Adds the query parameter `key`.
+    pub fn query<V: ToString>(mut self, key: &str, value: V) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds the query parameter `key` if `value` is present.
+    pub fn query_opt<V: ToString>(self, key: &str, value: Option<V>) -> Self {
+        match value {
+            Some(value) => self.query(key, value),
+            None => self,
+        }
+    }
+
+    /// Adds the query parameter `key` as `true` if `value` is set.
+    pub fn flag(self, key: &str, value: bool) -> Self {
+        if value {
+            self.query(key, true)
+        } else {
+            self
+        }
+    }
+
+    /// A `GET` request for `uri`, a link from a response such as a list's `next_page`. Only its path and query are
+    /// kept, so the request goes to the client's own base URL. A mirror with a path prefix includes the prefix in its
+    /// links, so it isn't added a second time.
+    pub fn follow(uri: &Uri) -> Self {
+        Request {
+            path: uri.path_segments(),
+            query: uri.query_pairs(),
+            body: None,
+            linked: true,
+        }
+    }
+
+    /// A search for cards matching `query`.
+    pub fn search(query: &str, params: &SearchParams) -> Self {
+        let query = if params.exclude_funny {
+            format!("({}) -is:funny", query)
+        } else {
+            query.to_string()
+        };
+        Request::get(&["cards", "search"])
+            .query("q", query)
+            .query_opt("unique", params.unique)
+            .query_opt("order", params.order)
+            .query_opt("dir", params.dir)
+            .flag("include_extras", params.include_extras)
+            .flag("include_multilingual", params.include_multilingual)
+            .flag("include_variations", params.include_variations)
+            .query_opt("page", params.page)
+    }
+
+    /// This request as HTTP, against `base_url`.
+    pub fn to_http(&self, base_url: &str) -> HttpRequest {
+        let mut headers = vec![("Accept".to_string(), "application/json".to_string())];
+        let body = self.body.as_ref().map(|body| {
+            headers.push(("Content-Type".to_string(), "application/json".to_string()));
+            body.to_string().into_bytes()
+        });
+
+        HttpRequest {
+            method: if body.is_some() {
+                Method::Post
+            } else {
+                Method::Get
+            },
+            url: self.url(base_url),
+            headers,
+            body,
+            attempt: 1,
+        }
+    }
+
+    /// The full URL of this request against `base_url`, with the path segments and query percent-encoded.
+    pub fn url(&self, base_url: &str) -> String {
+        let mut url = base_url.trim_end_matches('/').to_string();
+        let mut path = &self.path[..];
+        if self.linked {
+            if let Ok(base) = url.parse::<Uri>() {
+                let prefix = base.path_segments();
+                if path.starts_with(&prefix) {
+                    path = &path[prefix.len()..];
+                }
+            }
+        }
+        for segment in path {
+            url.push('/');
+            url.push_str(&percent_encode(segment));
+        }
+        for (i, (key, value)) in self.query.iter().enumerate() {
+            url.push(if i == 0 { '?' } else { '&' });
+            url.push_str(&percent_encode(key));
+            url.push('=');
+            url.push_str(&percent_encode(value));
+        }
+        url
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_url() {
+        use super::Request;
+
+        let request = Request::get(&["cards", "war", "1★"]);
+        assert_eq!(
+            "https://api.scryfall.com/cards/war/1%E2%98%85",
+            request.url("https://api.scryfall.com/")
+        );
+
+        let request = Request::get(&["cards", "named"])
+            .query("exact", "Fire // Ice")
+            .query_opt("set", Some("mh2"))
+            .query_opt("lang", None::<&str>);
+        assert_eq!(
+            "https://api.scryfall.com/cards/named?exact=Fire%20%2F%2F%20Ice&set=mh2",
+            request.url("https://api.scryfall.com")
+        );
+    }
+
+    #[test]
+    fn test_search() {
+        use super::Request;
+        use crate::types::search::{SearchParams, SortOrder, UniqueMode};
+
+        let params = SearchParams {
+            unique: Some(UniqueMode::Art),
+            order: Some(SortOrder::Released),
+            include_extras: true,
+            page: Some(2),
+            ..SearchParams::default()
+        };
+        assert_eq!(
+            "https://api.scryfall.com/cards/search?q=t%3Agoblin&unique=art&order=released&include_extras=true&page=2",
+            Request::search("t:goblin", &params).url("https://api.scryfall.com")
+        );
+    }
+
+    #[test]
+    fn test_to_http() {
+        use super::Request;
+        use crate::client::transport::Method;
+
+        let request = Request::get(&["cards", "random"]).to_http("https://api.scryfall.com");
+        assert_eq!(Method::Get, request.method);
+        assert_eq!(None, request.body);
+
+        let request = Request::post(
+            &["cards", "collection"],
+            serde_json::json!({"identifiers": []}),
+        )
+        .to_http("https://api.scryfall.com");
+        assert_eq!(Method::Post, request.method);
+        assert_eq!("https://api.scryfall.com/cards/collection", request.url);
+        assert_eq!(Some(br#"{"identifiers":[]}"#.to_vec()), request.body);
+        assert!(request
+            .headers
+            .contains(&("Content-Type".to_string(), "application/json".to_string())));
+    }
+
+    #[test]
+    fn test_search_exclude_funny() {
+        use super::Request;
+        use crate::types::search::SearchParams;
+
+        let params = SearchParams {
+            exclude_funny: true,
+            ..SearchParams::default()
+        };
+        assert_eq!(
+            vec![("q".to_string(), "(t:goblin or t:elf) -is:funny".to_string())],
+            Request::search("t:goblin or t:elf", &params).query
+        );
+    }
+
+    #[test]
+    fn test_follow() {
+        use super::Request;
+        use crate::types::uri::Uri;
+
+        let link: Uri = "https://mirror.example.com/scryfall/cards/search?q=bolt&page=2"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            "https://mirror.example.com/scryfall/cards/search?q=bolt&page=2",
+            Request::follow(&link).url("https://mirror.example.com/scryfall/")
+        );
+
+        let link: Uri = "https://api.scryfall.com/cards/search?q=bolt&page=2"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            "http://localhost:8080/scryfall/cards/search?q=bolt&page=2",
+            Request::follow(&link).url("http://localhost:8080/scryfall")
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use super::error::Error;
+use super::request::Request;
+use crate::links::{self, CardLink};
+use crate::types::error::Error as ApiError;
+use crate::types::identifier::CardIdentifier;
+
+/// How [`Client::resolve`](super::Client::resolve) looks up one input.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Lookup {
+    /// Through the collection endpoint, batched with the other inputs.
+    Collection(CardIdentifier),
+
+    /// A name that isn't marked exact: through the collection endpoint first, then fuzzily on its own if no card has
+    /// exactly that name.
+    Name(String),
+
+    /// On its own, since the collection endpoint has no identifier for it.
+    Single(Request),
+
+    /// Nothing; the input doesn't look like a card at all.
+    Unrecognized,
+}
+
+impl Lookup {
+    /// The identifier to send to the collection endpoint, if the input is looked up there.
+    pub fn identifier(&self) -> Option<CardIdentifier> {
+        match self {
+            Lookup::Collection(identifier) => Some(identifier.clone()),
+            Lookup::Name(name) => Some(CardIdentifier::Name(name.clone())),
+            Lookup::Single(_) | Lookup::Unrecognized => None,
+        }
+    }
+}
+
+/// Works out what kind of card reference `input` is:
+///
+/// - a Scryfall ID, e.g. `e3285e6b-3e79-4d7c-bf96-d920f973b80d`;
+/// - a card link that [`links::parse`] understands, e.g. `https://scryfall.com/card/m10/146/lightning-bolt`;
+/// - a set code and collector number, e.g. `m10/146`;
+/// - an exact name, marked with a leading `!` or wrapped in double quotes, e.g. `!Lightning Bolt`;
+/// - or otherwise a name that may be misspelled or partial, e.g. `lightnin bolt`.
+pub(crate) fn classify(input: &str) -> Lookup {
+    let input = input.trim();
+    if input.is_empty() {
+        return Lookup::Unrecognized;
+    }
+
+    if links::is_uuid(input) {
+        return Lookup::Collection(CardIdentifier::Id(input.to_lowercase()));
+    }
+
+    if input.contains("://") || input.starts_with("www.") {
+        return match links::parse(input) {
+            Some(CardLink::Id(id)) => Lookup::Collection(CardIdentifier::Id(id)),
+            Some(CardLink::SetNumber {
+                set,
+                collector_number,
+                lang: None,
+            }) => Lookup::Collection(CardIdentifier::SetNumber {
+                set,
+                collector_number,
+            }),
+            Some(CardLink::SetNumber {
+                set,
+                collector_number,
+                lang: Some(lang),
+            }) => Lookup::Single(Request::get(&["cards", &set, &collector_number, &lang])),
+            Some(CardLink::Multiverse(id)) => Lookup::Collection(CardIdentifier::MultiverseId(id)),
+            Some(CardLink::Tcgplayer(id)) => {
+                Lookup::Single(Request::get(&["cards", "tcgplayer", &id.to_string()]))
+            }
+            None => Lookup::Unrecognized,
+        };
+    }
+
+    if let Some((set, collector_number)) = input.split_once('/') {
+        let is_set = (2..=6).contains(&set.len()) && set.chars().all(|c| c.is_ascii_alphanumeric());
+        let is_number = collector_number.chars().any(|c| c.is_ascii_digit())
+            && !collector_number.contains(char::is_whitespace);
+        if is_set && is_number {
+            return Lookup::Collection(CardIdentifier::SetNumber {
+                set: set.to_lowercase(),
+                collector_number: collector_number.to_string(),
+            });
+        }
+    }
+
+    let exact = input.strip_prefix('!').unwrap_or(input);
+    let quoted = exact
+        .strip_prefix('"')
+        .and_then(|name| name.strip_suffix('"'));
+    match quoted {
+        Some(name) => Lookup::Collection(CardIdentifier::Name(name.to_string())),
+        None if exact.len() < input.len() => {
+            Lookup::Collection(CardIdentifier::Name(exact.trim().to_string()))
+        }
+        None => Lookup::Name(input.to_string()),
+    }
+}
+
+/// The error for an input that matched no card, shaped like the one Scryfall sends so that it's checked for the same
+/// way.
+pub(crate) fn not_found(input: &str) -> Error {
+    Error::Api(ApiError {
+        status: 404,
+        code: "not_found".to_string(),
+        details: format!("No card found for {:?}.", input),
+        error_type: None,
+        warnings: None,
+    })
+}
+
+mod tests {
+    #[test]
+    fn test_classify() {
+        use super::{classify, Lookup};
+        use crate::client::request::Request;
+        use crate::types::identifier::CardIdentifier;
+
+        let bolt_id = CardIdentifier::Id("e3285e6b-3e79-4d7c-bf96-d920f973b80d".to_string());
+        let bolt_number = CardIdentifier::SetNumber {
+            set: "m10".to_string(),
+            collector_number: "146".to_string(),
+        };
+
+        assert_eq!(
+            Lookup::Collection(bolt_id.clone()),
+            classify("E3285E6B-3E79-4D7C-BF96-D920F973B80D")
+        );
+        assert_eq!(
+            Lookup::Collection(bolt_id),
+            classify("https://api.scryfall.com/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+        );
+        assert_eq!(
+            Lookup::Collection(bolt_number.clone()),
+            classify("https://scryfall.com/card/m10/146/lightning-bolt")
+        );
+        assert_eq!(Lookup::Collection(bolt_number), classify("M10/146"));
+        assert_eq!(
+            Lookup::Single(Request::get(&["cards", "tcgplayer", "33660"])),
+            classify("https://www.tcgplayer.com/product/33660/magic-magic-2010-m10-lightning-bolt")
+        );
+        assert_eq!(Lookup::Unrecognized, classify("https://example.com/"));
+        assert_eq!(Lookup::Unrecognized, classify("  "));
+
+        let exact = Lookup::Collection(CardIdentifier::Name("Fire // Ice".to_string()));
+        assert_eq!(exact, classify("!Fire // Ice"));
+        assert_eq!(exact, classify("\"Fire // Ice\""));
+        assert_eq!(exact, classify("!\"Fire // Ice\""));
+        assert_eq!(
+            Lookup::Name("Fire // Ice".to_string()),
+            classify("Fire // Ice")
+        );
+        assert_eq!(Lookup::Name("jace/bele".to_string()), classify("jace/bele"));
+    }
+}
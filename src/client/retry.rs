@@ -0,0 +1,214 @@
+use super::clock::{Jitter, RandomJitter};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// When and how long to wait before retrying a request that failed transiently.
+#[derive(Clone, Debug)]
+pub(crate) struct RetryPolicy {
+    /// The most times a request is sent, including the first. One disables retries.
+    pub max_attempts: u32,
+
+    /// The wait before the first retry, doubled for each retry after it.
+    pub base_delay: Duration,
+
+    /// The longest wait between attempts, even if the server's `Retry-After` asks for longer.
+    pub max_delay: Duration,
+
+    /// Scales each backoff by between a half and all of it.
+    pub jitter: Arc<dyn Jitter>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            jitter: Arc::new(RandomJitter),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// How long to wait before retrying a request whose `attempt`th try (counting from 1) got `status` with the
+    /// `Retry-After` header `retry_after`, or `None` if it shouldn't be retried. Only rate limiting and transient
+    /// server errors are retried. A `Retry-After` in seconds, or as an HTTP date measured from `now`, is honored up to
+    /// `max_delay`; otherwise the wait backs off exponentially, with jitter so that many clients failing together don't
+    /// all retry together.
+    pub fn delay(
+        &self,
+        attempt: u32,
+        status: u16,
+        retry_after: Option<&str>,
+        now: SystemTime,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !matches!(status, 429 | 500 | 502 | 503 | 504) {
+            return None;
+        }
+
+        if let Some(requested) = retry_after.and_then(|value| requested_delay(value, now)) {
+            return Some(requested.min(self.max_delay));
+        }
+
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << (attempt - 1).min(16))
+            .min(self.max_delay);
+        let jitter = self.jitter.sample().clamp(0.0, 1.0);
+        Some(backoff.mul_f64(0.5 + jitter / 2.0))
+    }
+}
+
+/// The wait a `Retry-After` value asks for, either a number of seconds or an HTTP date. A date that has already passed
+/// asks for no wait.
+fn requested_delay(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = parse_http_date(value)?;
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+/// Parses an HTTP date in any of the three forms RFC 9110 requires recipients to accept: the preferred
+/// `Sun, 06 Nov 1994 08:49:37 GMT`, the obsolete RFC 850 form `Sunday, 06-Nov-94 08:49:37 GMT`, and C's asctime form
+/// `Sun Nov  6 08:49:37 1994`.
+pub(crate) fn parse_http_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let tokens: Vec<&str> = value
+        .split(|c: char| c.is_ascii_whitespace() || c == ',' || c == '-')
+        .filter(|token| !token.is_empty())
+        .collect();
+    let month = |token: &str| MONTHS.iter().position(|month| *month == token);
+    let (day, month, year, time) = match tokens.as_slice() {
+        [_, month_name, day, time, year] => (*day, month(month_name)?, *year, *time),
+        [_, day, month_name, year, time, "GMT"] => (*day, month(month_name)?, *year, *time),
+        _ => return None,
+    };
+
+    let day: u32 = day.parse().ok()?;
+    let year: i64 = match (year.len(), year.parse().ok()?) {
+        // RFC 850 years have two digits.
+        (2, year @ 0..=69) => 2000 + year,
+        (2, year) => 1900 + year,
+        (_, year) => year,
+    };
+    let mut clock = time.splitn(3, ':').map(|part| part.parse::<u64>().ok());
+    let (hour, minute, second) = match (clock.next()?, clock.next()?, clock.next()?) {
+        (Some(hour @ 0..=23), Some(minute @ 0..=59), Some(second @ 0..=60)) => {
+            (hour, minute, second)
+        }
+        _ => return None,
+    };
+    if !(1..=31).contains(&day) || year < 1970 {
+        return None;
+    }
+
+    let days = days_since_epoch(year, month as u32 + 1, day);
+    let seconds = days as u64 * 86_400 + hour * 3600 + minute * 60 + second;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// The number of days from 1970-01-01 to the given date in the proleptic Gregorian calendar.
+fn days_since_epoch(year: i64, month: u32, day: u32) -> i64 {
+    // Count years from March, so the leap day falls at the end of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year as i64;
+    era * 146_097 + day_of_era - 719_468
+}
+
+mod tests {
+    #[test]
+    fn test_delay() {
+        use super::RetryPolicy;
+        use crate::client::clock::FixedJitter;
+        use std::sync::Arc;
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let mut policy = RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            jitter: Arc::new(FixedJitter(1.0)),
+        };
+
+        let now = UNIX_EPOCH;
+        assert_eq!(None, policy.delay(1, 404, None, now));
+        assert_eq!(None, policy.delay(4, 503, None, now));
+        assert_eq!(
+            Some(Duration::from_secs(2)),
+            RetryPolicy::default().delay(1, 429, Some("2"), now)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(300)),
+            policy.delay(1, 429, Some("2"), now)
+        );
+
+        assert_eq!(
+            Some(Duration::from_millis(100)),
+            policy.delay(1, 503, None, now)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(200)),
+            policy.delay(2, 502, None, now)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(300)),
+            policy.delay(3, 503, Some("soon"), now)
+        );
+
+        policy.jitter = Arc::new(FixedJitter(0.0));
+        assert_eq!(
+            Some(Duration::from_millis(50)),
+            policy.delay(1, 503, None, now)
+        );
+        assert_eq!(
+            Some(Duration::from_millis(150)),
+            policy.delay(3, 500, None, now)
+        );
+    }
+
+    #[test]
+    fn test_http_date() {
+        use super::{parse_http_date, RetryPolicy};
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let date = UNIX_EPOCH + Duration::from_secs(784_111_777);
+        assert_eq!(Some(date), parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"));
+        assert_eq!(
+            Some(date),
+            parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT")
+        );
+        assert_eq!(Some(date), parse_http_date("Sun Nov  6 08:49:37 1994"));
+        assert_eq!(
+            Some(UNIX_EPOCH + Duration::from_secs(1_709_251_199)),
+            parse_http_date("Thu, 29 Feb 2024 23:59:59 GMT")
+        );
+        assert_eq!(None, parse_http_date("Sun, 06 Nov 1994 08:49:37 PST"));
+        assert_eq!(None, parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT"));
+        assert_eq!(None, parse_http_date("Sun, 06 Nov 1994 24:00:00 GMT"));
+        assert_eq!(None, parse_http_date("soon"));
+
+        let policy = RetryPolicy::default();
+        let now = date - Duration::from_secs(5);
+        assert_eq!(
+            Some(Duration::from_secs(5)),
+            policy.delay(1, 429, Some("Sun, 06 Nov 1994 08:49:37 GMT"), now)
+        );
+        assert_eq!(
+            Some(Duration::from_secs(8)),
+            policy.delay(1, 503, Some("Sun, 06 Nov 1994 08:59:37 GMT"), now)
+        );
+        assert_eq!(
+            Some(Duration::ZERO),
+            policy.delay(1, 429, Some("Sun, 06 Nov 1994 08:40:00 GMT"), now)
+        );
+    }
+}
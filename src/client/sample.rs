@@ -0,0 +1,67 @@
+use super::estimate::PAGE_SIZE;
+use rand::seq::{index, SliceRandom};
+use std::collections::BTreeMap;
+
+/// Which results to keep when sampling `n` of `total` search results: for each page that has at least one, its
+/// number (starting from 1) and the offsets on it, in page order.
+pub(crate) fn plan(total: u32, n: usize) -> Vec<(u32, Vec<usize>)> {
+    let total = total as usize;
+    let mut pages: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+    for i in index::sample(&mut rand::thread_rng(), total, n.min(total)) {
+        let page = (i / PAGE_SIZE as usize) as u32 + 1;
+        pages.entry(page).or_default().push(i % PAGE_SIZE as usize);
+    }
+
+    pages.into_iter().collect()
+}
+
+/// The items of `page` at `offsets`. Offsets past the end are skipped, in case the results shifted since the first
+/// page was fetched.
+pub(crate) fn pick<T>(page: Vec<T>, offsets: &[usize]) -> Vec<T> {
+    page.into_iter()
+        .enumerate()
+        .filter(|(i, _)| offsets.contains(i))
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// Shuffles `items`, so the sample isn't in search order.
+pub(crate) fn shuffle<T>(items: &mut [T]) {
+    items.shuffle(&mut rand::thread_rng());
+}
+
+mod tests {
+    #[test]
+    fn test_plan() {
+        use super::plan;
+
+        let pages = plan(1000, 50);
+        assert_eq!(
+            50,
+            pages
+                .iter()
+                .map(|(_, offsets)| offsets.len())
+                .sum::<usize>()
+        );
+        assert!(pages.iter().all(|(page, offsets)| *page >= 1
+            && *page <= 6
+            && offsets.iter().all(|offset| *offset < 175)));
+
+        let pages = plan(3, 10);
+        assert_eq!(
+            vec![(1, 3)],
+            pages
+                .iter()
+                .map(|(page, offsets)| (*page, offsets.len()))
+                .collect::<Vec<_>>()
+        );
+        assert!(plan(0, 10).is_empty());
+    }
+
+    #[test]
+    fn test_pick() {
+        use super::pick;
+
+        assert_eq!(vec!['a', 'c'], pick(vec!['a', 'b', 'c'], &[2, 0, 7]));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::types::card::Card;
+use crate::types::date::Date;
+use crate::types::search::{SearchParams, SortDirection, SortOrder, UniqueMode};
+use crate::types::uuid::Uuid;
+use std::collections::HashSet;
+
+/// The search for printings released on or after `since`, newest previews first.
+pub(crate) fn query(since: Date) -> (String, SearchParams) {
+    let params = SearchParams {
+        unique: Some(UniqueMode::Prints),
+        order: Some(SortOrder::Spoiled),
+        dir: Some(SortDirection::Desc),
+        ..SearchParams::default()
+    };
+    (format!("date>={}", since), params)
+}
+
+/// Whether `card` was added on or after `since`: previewed then, or released then if it was never previewed.
+pub(crate) fn added_since(card: &Card, since: Date) -> bool {
+    card.preview
+        .as_ref()
+        .and_then(|preview| preview.previewed_at)
+        .unwrap_or(card.released_at)
+        >= since
+}
+
+/// Remembers which printings a spoiler bot has already announced, so polling
+/// [`cards_added_since`](super::Client::cards_added_since) repeatedly only reports each card once. Scryfall's dates
+/// have no time of day, so every poll on the same day returns the same cards again, and cards without a preview date
+/// only show up once their release date comes; diffing against what's been seen catches both.
+#[derive(Clone, Debug, Default)]
+pub struct SpoilerFeed {
+    seen: HashSet<Uuid>,
+}
+
+impl SpoilerFeed {
+    /// A feed that hasn't seen any cards.
+    pub fn new() -> Self {
+        SpoilerFeed::default()
+    }
+
+    /// A feed that has already seen the printings with these ids, e.g. ones saved from an earlier run.
+    pub fn with_seen(ids: impl IntoIterator<Item = Uuid>) -> Self {
+        SpoilerFeed {
+            seen: ids.into_iter().collect(),
+        }
+    }
+
+    /// The cards among `cards` this feed hasn't seen before, in order, marking them all as seen.
+    pub fn update(&mut self, cards: Vec<Card>) -> Vec<Card> {
+        cards
+            .into_iter()
+            .filter(|card| self.seen.insert(card.id.clone()))
+            .collect()
+    }
+
+    /// The ids of every printing this feed has seen, to save for the next run.
+    pub fn seen(&self) -> impl Iterator<Item = &Uuid> {
+        self.seen.iter()
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_added_since() {
+        use super::added_since;
+        use crate::testing::card_with;
+        use crate::types::date::Date;
+
+        let since = Date {
+            year: 2024,
+            month: 7,
+            day: 1,
+        };
+        let previewed = card_with(serde_json::json!({
+            "released_at": "2024-08-02",
+            "preview": {"previewed_at": "2024-07-09", "source": "Wizards of the Coast"},
+        }));
+        assert!(added_since(&previewed, since));
+
+        let released = card_with(serde_json::json!({"released_at": "2024-07-01"}));
+        assert!(added_since(&released, since));
+
+        let old = card_with(serde_json::json!({
+            "released_at": "2024-08-02",
+            "preview": {"previewed_at": "2024-06-20"},
+        }));
+        assert!(!added_since(&old, since));
+    }
+
+    #[test]
+    fn test_spoiler_feed() {
+        use super::SpoilerFeed;
+        use crate::testing::{card, card_with};
+
+        let bolt = card();
+        let other = card_with(serde_json::json!({"id": "0000579f-7b35-4ed3-b44c-db2a538066fe"}));
+
+        let mut feed = SpoilerFeed::new();
+        assert_eq!(1, feed.update(vec![bolt.clone()]).len());
+        let new = feed.update(vec![bolt.clone(), other.clone()]);
+        assert_eq!(
+            vec![other.id.as_str()],
+            new.iter().map(|card| card.id.as_str()).collect::<Vec<_>>()
+        );
+        assert!(feed.update(vec![bolt.clone(), other]).is_empty());
+
+        let mut restored = SpoilerFeed::with_seen(feed.seen().cloned());
+        assert!(restored.update(vec![bolt]).is_empty());
+    }
+}
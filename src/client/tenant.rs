@@ -0,0 +1,131 @@
+use super::rate_limit::RateLimiter;
+use super::Client;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Per-tenant rate budgets on top of one client, for services such as bots serving many communities, so that one
+/// noisy tenant can't starve the others of requests. Each tenant gets its own [`RateLimiter`], which its requests
+/// wait on before the client's shared one. A tenant that spends its budget only delays itself, while the shared limiter
+/// still keeps the total within what Scryfall allows, handing out turns in the order requests arrive.
+///
+/// To split the shared budget evenly, make each tenant's interval the client's interval times the number of tenants
+/// expected to be busy at once.
+///
+/// ```no_run
+/// use scryfall::client::{Client, Tenants};
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), scryfall::client::Error> {
+/// let tenants = Tenants::new(Client::new(), Duration::from_millis(500), 2);
+/// let card = tenants
+///     .client("guild-1234")
+///     .card_named_fuzzy("bolt", None)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Tenants {
+    client: Client,
+    interval: Duration,
+    burst: u32,
+    limiters: Mutex<HashMap<String, Arc<RateLimiter>>>,
+}
+
+impl Tenants {
+    /// Budgets on top of `client`, allowing each tenant bursts of up to `burst` requests, then one more every
+    /// `interval`.
+    pub fn new(client: Client, interval: Duration, burst: u32) -> Self {
+        Tenants {
+            client,
+            interval,
+            burst,
+            limiters: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// A handle to the client whose requests count against `tenant`'s budget. Handles for the same tenant share its
+    /// budget, and a tenant's budget starts full the first time it's used.
+    pub fn client(&self, tenant: &str) -> Client {
+        let limiter = self
+            .limiters
+            .lock()
+            .unwrap()
+            .entry(tenant.to_string())
+            .or_insert_with(|| {
+                Arc::new(RateLimiter::with_clock(
+                    self.interval,
+                    self.burst,
+                    Arc::clone(&self.client.inner.clock),
+                ))
+            })
+            .clone();
+        Client {
+            tenant: Some(limiter),
+            ..self.client.clone()
+        }
+    }
+
+    /// Drops `tenant`'s budget, e.g. once it's been idle a while, so it starts full again next time.
+    pub fn forget(&self, tenant: &str) {
+        self.limiters.lock().unwrap().remove(tenant);
+    }
+
+    /// How many tenants have budgets.
+    pub fn len(&self) -> usize {
+        self.limiters.lock().unwrap().len()
+    }
+
+    /// Whether no tenant has a budget yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_tenants() {
+        use super::Tenants;
+        use crate::client::clock::VirtualClock;
+        use crate::client::ClientBuilder;
+        use std::sync::Arc;
+        use std::time::Duration;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+
+        let clock = Arc::new(VirtualClock::new());
+        let client = ClientBuilder::new()
+            .base_url(&server.uri())
+            .rate_limit(Duration::ZERO, 1)
+            .clock(Arc::clone(&clock))
+            .build();
+        let tenants = Tenants::new(client, Duration::from_secs(1), 1);
+        let id = "e3285e6b-3e79-4d7c-bf96-d920f973b80d";
+
+        // The noisy tenant waits for its second request, but the quiet one's first isn't held up behind it.
+        let noisy = tenants.client("noisy");
+        noisy.card_by_id(id).await.unwrap();
+        noisy.card_by_id(id).await.unwrap();
+        tenants.client("quiet").card_by_id(id).await.unwrap();
+        assert_eq!(vec![Duration::from_secs(1)], clock.sleeps());
+        assert_eq!(2, tenants.len());
+
+        // Handles for the same tenant share its budget.
+        tenants.client("noisy").card_by_id(id).await.unwrap();
+        assert_eq!(vec![Duration::from_secs(1); 2], clock.sleeps());
+
+        tenants.forget("noisy");
+        assert_eq!(1, tenants.len());
+    }
+}
@@ -0,0 +1,237 @@
+//! The layer that actually moves bytes to and from the API. The async [`Client`](super::Client) sends every request
+//! through a [`Transport`], which is normally [`ReqwestTransport`] but can be swapped for a decorator such as
+//! [`FaultInjector`](super::fault::FaultInjector) or a test double.
+
+use super::error::Error;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+
+/// The future returned by [`Transport::send`].
+pub type ResponseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send + 'a>>;
+
+/// An HTTP method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+/// An HTTP request, ready to send.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpRequest {
+    pub method: Method,
+
+    /// The full URL, including the query.
+    pub url: String,
+
+    /// The request headers, as name and value.
+    pub headers: Vec<(String, String)>,
+
+    /// The request body, if any.
+    pub body: Option<Vec<u8>>,
+
+    /// Which try at the call this is, counting from 1, so that retries can be told apart from first attempts.
+    pub attempt: u32,
+}
+
+impl HttpRequest {
+    /// This request as an `http::Request`, e.g. to compare against one built by other code. Fails if the URL or a
+    /// header isn't valid for `http`.
+    pub fn to_http(&self) -> Result<http::Request<Vec<u8>>, http::Error> {
+        let mut builder = http::Request::builder();
+        builder.method(match self.method {
+            Method::Get => http::Method::GET,
+            Method::Post => http::Method::POST,
+        });
+        builder.uri(self.url.as_str());
+        for (name, value) in &self.headers {
+            builder.header(name.as_str(), value.as_str());
+        }
+        builder.body(self.body.clone().unwrap_or_default())
+    }
+
+    /// Adds each of `headers` that this request doesn't already set, compared case-insensitively.
+    pub(crate) fn add_default_headers(&mut self, headers: &[(String, String)]) {
+        for (name, value) in headers {
+            if !self
+                .headers
+                .iter()
+                .any(|(key, _)| key.eq_ignore_ascii_case(name))
+            {
+                self.headers.push((name.clone(), value.clone()));
+            }
+        }
+    }
+}
+
+/// An HTTP response, with its body read in full.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+
+    /// The response headers, as name and value.
+    pub headers: Vec<(String, String)>,
+
+    pub body: Vec<u8>,
+
+    /// Where the response came from.
+    pub source: ResponseSource,
+}
+
+/// Where a response came from. Decorators that answer from storage say so here, so callers can tell fresh data from
+/// stored data.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ResponseSource {
+    /// Scryfall sent it.
+    Network,
+
+    /// A stored copy that Scryfall confirmed is still current, as with a `304 Not Modified`.
+    Cache,
+
+    /// A stored copy served without Scryfall confirming it, because Scryfall couldn't be reached. It may be out of date.
+    Stale,
+}
+
+impl HttpResponse {
+    /// The value of the first header called `name`, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Something that can send an [`HttpRequest`]. Transports are shared by every clone of a client, so they must be
+/// `Send + Sync`.
+pub trait Transport: Debug + Send + Sync {
+    /// Sends `request` and reads the whole response. Error statuses are responses, not errors; only failures to get a
+    /// response at all are errors.
+    fn send(&self, request: HttpRequest) -> ResponseFuture<'_>;
+}
+
+impl<T: Transport + ?Sized> Transport for std::sync::Arc<T> {
+    fn send(&self, request: HttpRequest) -> ResponseFuture<'_> {
+        (**self).send(request)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn send(&self, request: HttpRequest) -> ResponseFuture<'_> {
+        (**self).send(request)
+    }
+}
+
+/// The default transport, backed by a `reqwest::Client`.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    http: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// A transport with reqwest's default settings.
+    pub fn new() -> Self {
+        ReqwestTransport::default()
+    }
+}
+
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(http: reqwest::Client) -> Self {
+        ReqwestTransport { http }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> ResponseFuture<'_> {
+        Box::pin(async move {
+            let mut builder = match request.method {
+                Method::Get => self.http.get(&request.url),
+                Method::Post => self.http.post(&request.url),
+            };
+            for (name, value) in &request.headers {
+                builder = builder.header(name.as_str(), value.as_str());
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            let body = response.bytes().await?.to_vec();
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+                source: ResponseSource::Network,
+            })
+        })
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_header() {
+        use super::{HttpResponse, ResponseSource};
+
+        let response = HttpResponse {
+            status: 429,
+            headers: vec![("retry-after".to_string(), "2".to_string())],
+            body: Vec::new(),
+            source: ResponseSource::Network,
+        };
+        assert_eq!(Some("2"), response.header("Retry-After"));
+        assert_eq!(None, response.header("ETag"));
+    }
+
+    #[test]
+    fn test_add_default_headers() {
+        use super::{HttpRequest, Method};
+
+        let mut request = HttpRequest {
+            method: Method::Get,
+            url: "https://api.scryfall.com/sets".to_string(),
+            headers: vec![("Accept".to_string(), "application/json".to_string())],
+            body: None,
+            attempt: 1,
+        };
+        request.add_default_headers(&[
+            ("User-Agent".to_string(), "deckbuilder/1.0".to_string()),
+            ("accept".to_string(), "*/*".to_string()),
+        ]);
+        assert_eq!(
+            vec![
+                ("Accept".to_string(), "application/json".to_string()),
+                ("User-Agent".to_string(), "deckbuilder/1.0".to_string()),
+            ],
+            request.headers
+        );
+    }
+
+    #[test]
+    fn test_to_http() {
+        use super::{HttpRequest, Method};
+
+        let request = HttpRequest {
+            method: Method::Post,
+            url: "https://api.scryfall.com/cards/collection".to_string(),
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: Some(b"{}".to_vec()),
+            attempt: 1,
+        }
+        .to_http()
+        .unwrap();
+        assert_eq!(http::Method::POST, *request.method());
+        assert_eq!("/cards/collection", request.uri().path());
+        assert_eq!("application/json", request.headers()["content-type"]);
+        assert_eq!(b"{}", request.body().as_slice());
+    }
+}
@@ -0,0 +1,110 @@
+use crate::types::card::{Card, Finish};
+
+/// The physical condition of an owned copy of a card, on TCGplayer's grading scale.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Condition {
+    Mint,
+    NearMint,
+    LightlyPlayed,
+    ModeratelyPlayed,
+    HeavilyPlayed,
+    Damaged,
+}
+
+impl Condition {
+    /// The condition name TCGplayer uses in its listing files.
+    pub fn tcgplayer_name(self) -> &'static str {
+        match self {
+            Condition::Mint | Condition::NearMint => "Near Mint",
+            Condition::LightlyPlayed => "Lightly Played",
+            Condition::ModeratelyPlayed => "Moderately Played",
+            Condition::HeavilyPlayed => "Heavily Played",
+            Condition::Damaged => "Damaged",
+        }
+    }
+
+    /// The closest grade on Cardmarket's scale (MT, NM, EX, GD, LP, PL, PO).
+    pub fn cardmarket_code(self) -> &'static str {
+        match self {
+            Condition::Mint => "MT",
+            Condition::NearMint => "NM",
+            Condition::LightlyPlayed => "EX",
+            Condition::ModeratelyPlayed => "GD",
+            Condition::HeavilyPlayed => "PL",
+            Condition::Damaged => "PO",
+        }
+    }
+}
+
+/// One line of a resolved collection: a specific printing, along with the details that distinguish owned copies.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CollectionEntry {
+    /// The printing these copies are of. The language of the copies is the language of this printing.
+    pub card: Card,
+
+    /// How many copies are owned.
+    pub quantity: u32,
+
+    /// Which finish the copies are.
+    pub finish: Finish,
+
+    /// What condition the copies are in.
+    pub condition: Condition,
+
+    /// The asking price per copy, if the owner has set one. Exporters fall back to Scryfall's market price.
+    pub price: Option<f64>,
+}
+
+impl CollectionEntry {
+    /// A near mint, non-foil entry with no asking price.
+    pub fn new(card: Card, quantity: u32) -> Self {
+        CollectionEntry {
+            card,
+            quantity,
+            finish: Finish::Nonfoil,
+            condition: Condition::NearMint,
+            price: None,
+        }
+    }
+
+    /// The asking price if set, otherwise Scryfall's USD price for this entry's finish.
+    pub fn usd_price(&self) -> Option<f64> {
+        let prices = &self.card.prices;
+        let market = match self.finish {
+            Finish::Nonfoil => prices.usd,
+            Finish::Foil => prices.usd_foil,
+            Finish::Etched => prices.usd_etched,
+        };
+
+        self.price.or_else(|| market.map(|price| price.0))
+    }
+
+    /// The asking price if set, otherwise Scryfall's EUR price for this entry's finish.
+    pub fn eur_price(&self) -> Option<f64> {
+        let prices = &self.card.prices;
+        let market = match self.finish {
+            Finish::Nonfoil => prices.eur,
+            Finish::Foil | Finish::Etched => prices.eur_foil,
+        };
+
+        self.price.or_else(|| market.map(|price| price.0))
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_usd_price() {
+        use super::CollectionEntry;
+        use crate::testing;
+        use crate::types::card::Finish;
+
+        let mut entry = CollectionEntry::new(testing::card(), 4);
+        assert_eq!(Some(2.47), entry.usd_price());
+
+        entry.finish = Finish::Foil;
+        assert_eq!(Some(14.91), entry.usd_price());
+
+        entry.price = Some(20.0);
+        assert_eq!(Some(20.0), entry.usd_price());
+    }
+}
@@ -0,0 +1,179 @@
+//! Deckstats.net's text deck format.
+//!
+//! ```text
+//! //NAME: Burn
+//! //Main
+//! 4 [M10#146] Lightning Bolt
+//! 20 Mountain
+//!
+//! //Sideboard
+//! SB: 2 [ICE] Pyroblast
+//! ```
+//!
+//! Lines starting with `//` are comments, except for the `//NAME:` header and the `//Main` and `//Sideboard` section
+//! markers. A `[SET]` or `[SET#NUMBER]` hint before the name pins a printing, and trailing `# ...` annotations are
+//! ignored.
+
+use super::{split_quantity, strip_sideboard_marker, Deck, DeckEntry, ParseError};
+
+/// Parses a Deckstats text export.
+pub fn parse(text: &str) -> Result<Deck, ParseError> {
+    let mut deck = Deck::default();
+    let mut in_sideboard = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix("//") {
+            let comment = comment.trim();
+            if let Some(name) = comment.strip_prefix("NAME:") {
+                deck.name = Some(name.trim().to_string());
+            } else if comment.eq_ignore_ascii_case("main") {
+                in_sideboard = false;
+            } else if comment.eq_ignore_ascii_case("sideboard") {
+                in_sideboard = true;
+            }
+            continue;
+        }
+
+        let line = match line.find(" #") {
+            Some(annotation) => line[..annotation].trim_end(),
+            None => line,
+        };
+
+        let (marked, line) = strip_sideboard_marker(line);
+        let (quantity, rest) = split_quantity(line)
+            .ok_or_else(|| ParseError::new(i + 1, "expected a quantity and card name"))?;
+        let entry = parse_card(quantity, rest)
+            .ok_or_else(|| ParseError::new(i + 1, "malformed set hint"))?;
+
+        if in_sideboard || marked {
+            deck.sideboard.push(entry);
+        } else {
+            deck.main.push(entry);
+        }
+    }
+
+    Ok(deck)
+}
+
+fn parse_card(quantity: u32, rest: &str) -> Option<DeckEntry> {
+    let (hint, name) = match rest.strip_prefix('[') {
+        Some(hinted) => {
+            let close = hinted.find(']')?;
+            (Some(&hinted[..close]), hinted[close + 1..].trim())
+        }
+        None => (None, rest),
+    };
+
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut entry = DeckEntry::new(quantity, name);
+    if let Some(hint) = hint {
+        let mut parts = hint.splitn(2, '#');
+        entry.set = parts.next().map(|set| set.trim().to_string());
+        entry.collector_number = parts.next().map(|number| number.trim().to_string());
+    }
+
+    Some(entry)
+}
+
+/// Formats a deck as Deckstats text, marking sideboard lines with `SB:`.
+pub fn format(deck: &Deck) -> String {
+    let mut out = String::new();
+
+    if let Some(name) = &deck.name {
+        out.push_str(&format!("//NAME: {}\n", name));
+    }
+
+    out.push_str("//Main\n");
+    for entry in &deck.main {
+        format_entry(&mut out, entry, "");
+    }
+
+    if !deck.sideboard.is_empty() {
+        out.push_str("\n//Sideboard\n");
+        for entry in &deck.sideboard {
+            format_entry(&mut out, entry, "SB: ");
+        }
+    }
+
+    out
+}
+
+fn format_entry(out: &mut String, entry: &DeckEntry, prefix: &str) {
+    out.push_str(&format!("{}{} ", prefix, entry.quantity));
+    match (&entry.set, &entry.collector_number) {
+        (Some(set), Some(number)) => out.push_str(&format!("[{}#{}] ", set, number)),
+        (Some(set), None) => out.push_str(&format!("[{}] ", set)),
+        _ => {}
+    }
+    out.push_str(&entry.name);
+    out.push('\n');
+}
+
+mod tests {
+    #[cfg(test)]
+    const BURN: &str = "//NAME: Modern Burn
+//Main
+4 [ZEN#136] Goblin Guide
+4 [KTK#118] Monastery Swiftspear
+4 [M10#146] Lightning Bolt
+4 [CHK] Lava Spike
+20 Mountain
+
+//Sideboard
+SB: 3 [CON#15] Path to Exile
+SB: 2 Pyroblast
+";
+
+    #[test]
+    fn test_parse() {
+        use super::parse;
+        use crate::deck::DeckEntry;
+
+        let deck = parse(BURN).unwrap();
+        assert_eq!(Some("Modern Burn".to_string()), deck.name);
+        assert_eq!(5, deck.main.len());
+        assert_eq!(2, deck.sideboard.len());
+
+        let mut bolt = DeckEntry::new(4, "Lightning Bolt");
+        bolt.set = Some("M10".to_string());
+        bolt.collector_number = Some("146".to_string());
+        assert_eq!(bolt, deck.main[2]);
+        assert_eq!(DeckEntry::new(2, "Pyroblast"), deck.sideboard[1]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        use super::{format, parse};
+
+        assert_eq!(BURN, format(&parse(BURN).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_annotations_and_markers() {
+        use super::parse;
+        use crate::deck::DeckEntry;
+
+        let deck = parse("// a comment\n1 Ajani Vengeant # !Commander\nSB: 1 Pyroblast").unwrap();
+        assert_eq!(vec![DeckEntry::new(1, "Ajani Vengeant")], deck.main);
+        assert_eq!(vec![DeckEntry::new(1, "Pyroblast")], deck.sideboard);
+    }
+
+    #[test]
+    fn test_cross_format() {
+        use super::parse;
+        use crate::deck::mtggoldfish;
+
+        let deck = parse(BURN).unwrap();
+        let goldfish = mtggoldfish::parse(&mtggoldfish::format(&deck)).unwrap();
+        assert_eq!(deck.main.len(), goldfish.main.len());
+        assert_eq!(deck.sideboard.len(), goldfish.sideboard.len());
+    }
+}
@@ -0,0 +1,146 @@
+//! Stable hashes of decklists, for spotting identical lists across sources.
+
+use super::resolve::ResolvedDeck;
+use super::Deck;
+use crate::metagame;
+use sha1::{Digest, Sha1};
+
+const BASE32_DIGITS: &[u8; 32] = b"0123456789abcdefghijklmnopqrstuv";
+
+impl Deck {
+    /// The deck hash Cockatrice shows for this list, e.g. `8lr8c3m3`. Names are compared ignoring case and order
+    /// doesn't matter, so the same 75 gets the same hash however it was written, but differently spelled names (or
+    /// split cards written with one face) hash differently. The deck's name isn't part of the hash.
+    pub fn fingerprint(&self) -> String {
+        let mut cards: Vec<String> = Vec::new();
+        for (prefix, entries) in [("", &self.main), ("SB:", &self.sideboard)].iter() {
+            for entry in entries.iter() {
+                let card = format!("{}{}", prefix, entry.name.trim().to_lowercase());
+                cards.extend(std::iter::repeat_n(card, entry.quantity as usize));
+            }
+        }
+        cards.sort();
+
+        let digest = Sha1::digest(cards.join(";").as_bytes());
+        let number = digest[..5]
+            .iter()
+            .fold(0u64, |number, byte| (number << 8) | u64::from(*byte));
+
+        (0..8)
+            .rev()
+            .map(|digit| BASE32_DIGITS[((number >> (digit * 5)) & 31) as usize] as char)
+            .collect()
+    }
+}
+
+impl ResolvedDeck {
+    /// A hash of the deck's contents by Oracle ID, as 40 hex digits. Unlike `Deck::fingerprint` it ignores how names
+    /// were written and which printings were chosen, so two lists of the same cards always match. Unresolved entries
+    /// aren't part of the hash.
+    pub fn fingerprint(&self) -> String {
+        let mut cards: Vec<String> = Vec::new();
+        for (prefix, entries) in [("", &self.main), ("SB:", &self.sideboard)].iter() {
+            let mut counts: Vec<(&str, u32)> = Vec::new();
+            for entry in entries.iter() {
+                let key = metagame::oracle_key(&entry.card);
+                match counts.iter_mut().find(|(id, _)| *id == key) {
+                    Some((_, count)) => *count += entry.quantity,
+                    None => counts.push((key, entry.quantity)),
+                }
+            }
+            cards.extend(
+                counts
+                    .into_iter()
+                    .map(|(id, count)| format!("{}{} {}", prefix, count, id)),
+            );
+        }
+        cards.sort();
+
+        Sha1::digest(cards.join("\n").as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_cockatrice_hash() {
+        use crate::deck::{Deck, DeckEntry};
+
+        let deck = Deck {
+            name: Some("Burn".to_string()),
+            main: vec![
+                DeckEntry::new(4, "Lightning Bolt"),
+                DeckEntry::new(20, "Mountain"),
+            ],
+            sideboard: vec![DeckEntry::new(2, "Smash to Smithereens")],
+        };
+        let reordered = Deck {
+            name: None,
+            main: vec![
+                DeckEntry::new(20, "mountain"),
+                DeckEntry::new(1, "Lightning Bolt"),
+                DeckEntry::new(3, "LIGHTNING BOLT"),
+            ],
+            sideboard: deck.sideboard.clone(),
+        };
+        let moved = Deck {
+            name: None,
+            main: deck.sideboard.clone(),
+            sideboard: deck.main.clone(),
+        };
+
+        assert_eq!("p0bn9tji", deck.fingerprint());
+        assert_eq!(deck.fingerprint(), reordered.fingerprint());
+        assert_ne!(deck.fingerprint(), moved.fingerprint());
+    }
+
+    #[test]
+    fn test_oracle_hash() {
+        use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+        use crate::testing;
+        use serde_json::json;
+
+        let entry = |quantity, set| ResolvedEntry {
+            quantity,
+            card: testing::card_with(json!({ "set": set })),
+        };
+        let deck = ResolvedDeck {
+            main: vec![entry(4, "m10")],
+            ..ResolvedDeck::default()
+        };
+        let split = ResolvedDeck {
+            name: Some("Burn".to_string()),
+            main: vec![entry(1, "lea"), entry(3, "m10")],
+            ..ResolvedDeck::default()
+        };
+        let sideboarded = ResolvedDeck {
+            sideboard: vec![entry(4, "m10")],
+            ..ResolvedDeck::default()
+        };
+
+        assert_eq!(40, deck.fingerprint().len());
+        assert_eq!(deck.fingerprint(), split.fingerprint());
+        assert_ne!(deck.fingerprint(), sideboarded.fingerprint());
+    }
+
+    #[test]
+    fn test_reversible_oracle_hash() {
+        use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+        use crate::testing;
+
+        let deck = |card| ResolvedDeck {
+            main: vec![ResolvedEntry { quantity: 1, card }],
+            ..ResolvedDeck::default()
+        };
+
+        assert_ne!(
+            deck(testing::reversible_card()).fingerprint(),
+            deck(testing::reversible_card_of(
+                "ffffffff-0000-0000-0000-000000000000"
+            ))
+            .fingerprint()
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Forge's `.dck` deck format.
+//!
+//! ```text
+//! [metadata]
+//! Name=Burn
+//! [Main]
+//! 4 Lightning Bolt|M10
+//! [Sideboard]
+//! 2 Pyroblast|ICE
+//! ```
+//!
+//! Forge pins printings by set code alone, so collector numbers are not written.
+
+use super::{Deck, DeckEntry};
+
+/// Formats a deck as a Forge `.dck` file. Decks without a name are written as "Untitled".
+pub fn format(deck: &Deck) -> String {
+    let mut out = String::new();
+
+    out.push_str("[metadata]\n");
+    out.push_str(&format!(
+        "Name={}\n",
+        deck.name.as_deref().unwrap_or("Untitled")
+    ));
+
+    out.push_str("[Main]\n");
+    for entry in &deck.main {
+        format_entry(&mut out, entry);
+    }
+
+    if !deck.sideboard.is_empty() {
+        out.push_str("[Sideboard]\n");
+        for entry in &deck.sideboard {
+            format_entry(&mut out, entry);
+        }
+    }
+
+    out
+}
+
+fn format_entry(out: &mut String, entry: &DeckEntry) {
+    out.push_str(&format!("{} {}", entry.quantity, entry.name));
+    if let Some(set) = &entry.set {
+        out.push_str(&format!("|{}", set.to_uppercase()));
+    }
+    out.push('\n');
+}
+
+mod tests {
+    #[test]
+    fn test_format() {
+        use super::format;
+        use crate::deck::{Deck, DeckEntry};
+
+        let mut bolt = DeckEntry::new(4, "Lightning Bolt");
+        bolt.set = Some("m10".to_string());
+        bolt.collector_number = Some("146".to_string());
+        let deck = Deck {
+            name: Some("Burn".to_string()),
+            main: vec![bolt, DeckEntry::new(20, "Mountain")],
+            sideboard: vec![DeckEntry::new(2, "Pyroblast")],
+        };
+
+        assert_eq!(
+            "[metadata]\nName=Burn\n[Main]\n4 Lightning Bolt|M10\n20 Mountain\n[Sideboard]\n2 Pyroblast\n",
+            format(&deck)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Choosing basic land printings for a deck.
+
+use super::resolve::{ResolvedDeck, ResolvedEntry};
+use crate::cards;
+use crate::names;
+use crate::query::{Query, Term};
+use crate::types::card::Card;
+
+/// The look a deck's basic lands should share, e.g. the full-art Unstable basics.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BasicLandStyle {
+    /// Only use basics from the set with this code.
+    pub set: Option<String>,
+
+    /// Only use full-art basics.
+    pub full_art: bool,
+}
+
+impl BasicLandStyle {
+    /// The search that finds every basic land printing in this style.
+    pub fn query(&self) -> Query {
+        let mut query = Query::new().and(Term::Type("basic".to_string()));
+        if let Some(set) = &self.set {
+            query = query.and(Term::Set(set.clone()));
+        }
+        if self.full_art {
+            query = query.and(Term::Is("fullart".to_string()));
+        }
+        query
+    }
+
+    /// Whether `card` is a basic land printing in this style.
+    pub fn matches(&self, card: &Card) -> bool {
+        is_basic_land(card)
+            && self
+                .set
+                .as_ref()
+                .is_none_or(|set| card.set.eq_ignore_ascii_case(set))
+            && (!self.full_art || card.full_art)
+    }
+
+    /// The first printing of the basic land `name` among `candidates` that is in this style.
+    pub fn select<'a>(&self, name: &str, candidates: &'a [Card]) -> Option<&'a Card> {
+        let name = names::normalize(name);
+        candidates
+            .iter()
+            .find(|card| names::normalize(&card.name) == name && self.matches(card))
+    }
+
+    /// Swaps every basic land in `deck` to a printing in this style, returning the names of basics that had no
+    /// matching candidate and were left as they were.
+    pub fn fill(&self, deck: &mut ResolvedDeck, candidates: &[Card]) -> Vec<String> {
+        let mut missing = Vec::new();
+
+        for entry in deck.main.iter_mut().chain(deck.sideboard.iter_mut()) {
+            if !is_basic_land(&entry.card) || self.matches(&entry.card) {
+                continue;
+            }
+
+            match self.select(&entry.card.name, candidates) {
+                Some(card) => entry.card = card.clone(),
+                None => missing.push(entry.card.name.clone()),
+            }
+        }
+
+        missing
+    }
+
+    /// Adds `quantity` copies of the basic land `name` to the main deck in this style, merging with an existing entry
+    /// for the same printing. Returns false if no candidate matches.
+    pub fn add(
+        &self,
+        deck: &mut ResolvedDeck,
+        name: &str,
+        quantity: u32,
+        candidates: &[Card],
+    ) -> bool {
+        let card = match self.select(name, candidates) {
+            Some(card) => card,
+            None => return false,
+        };
+
+        match deck.main.iter_mut().find(|entry| entry.card.id == card.id) {
+            Some(entry) => entry.quantity += quantity,
+            None => deck.main.push(ResolvedEntry {
+                quantity,
+                card: card.clone(),
+            }),
+        }
+
+        true
+    }
+}
+
+fn is_basic_land(card: &Card) -> bool {
+    cards::is_basic_land_name(&card.name)
+}
+
+mod tests {
+    #[cfg(test)]
+    fn basic(name: &str, set: &str, full_art: bool) -> crate::types::card::Card {
+        use crate::testing;
+        use serde_json::json;
+
+        testing::card_with(json!({
+            "id": format!("{}-{}-{}", name, set, full_art),
+            "name": name,
+            "set": set,
+            "full_art": full_art,
+            "type_line": format!("Basic Land — {}", name),
+        }))
+    }
+
+    #[test]
+    fn test_query() {
+        use super::BasicLandStyle;
+
+        let style = BasicLandStyle {
+            set: Some("ust".to_string()),
+            full_art: true,
+        };
+        assert_eq!("t:basic set:ust is:fullart", style.query().to_string());
+    }
+
+    #[test]
+    fn test_fill_and_add() {
+        use super::BasicLandStyle;
+        use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+        use crate::testing;
+
+        let candidates = vec![
+            basic("Mountain", "m10", false),
+            basic("Mountain", "ust", true),
+            basic("Island", "m10", false),
+        ];
+        let mut deck = ResolvedDeck::default();
+        deck.main.push(ResolvedEntry {
+            quantity: 4,
+            card: testing::card(),
+        });
+        deck.main.push(ResolvedEntry {
+            quantity: 16,
+            card: candidates[0].clone(),
+        });
+
+        let style = BasicLandStyle {
+            set: Some("UST".to_string()),
+            full_art: true,
+        };
+        assert!(style.fill(&mut deck, &candidates).is_empty());
+        assert_eq!("m10", deck.main[0].card.set);
+        assert_eq!("ust", deck.main[1].card.set);
+
+        assert!(style.add(&mut deck, "Mountain", 2, &candidates));
+        assert_eq!(18, deck.main[1].quantity);
+        assert!(!style.add(&mut deck, "Island", 2, &candidates));
+    }
+}
@@ -0,0 +1,257 @@
+//! Colored mana requirements and land count suggestions for a deck.
+
+use super::resolve::ResolvedDeck;
+use crate::types::card::{Color, Face};
+use std::collections::{BTreeMap, HashMap};
+
+/// The five colors in WUBRG order, used wherever results need a stable order.
+const COLORS: [Color; 5] = [
+    Color::White,
+    Color::Blue,
+    Color::Black,
+    Color::Red,
+    Color::Green,
+];
+
+fn color_of(symbol: &str) -> Option<Color> {
+    match symbol {
+        "W" => Some(Color::White),
+        "U" => Some(Color::Blue),
+        "B" => Some(Color::Black),
+        "R" => Some(Color::Red),
+        "G" => Some(Color::Green),
+        _ => None,
+    }
+}
+
+/// Counts the colored pips in a mana cost such as `{2}{W}{W}`. Hybrid symbols count once toward each of their colors
+/// and Phyrexian symbols count toward their color; generic, colorless, and variable symbols are ignored.
+pub fn count_pips(mana_cost: &str) -> HashMap<Color, u32> {
+    let mut pips = HashMap::new();
+
+    for symbol in mana_cost.split(&['{', '}'][..]).filter(|s| !s.is_empty()) {
+        for color in symbol.split('/').filter_map(color_of) {
+            *pips.entry(color).or_insert(0) += 1;
+        }
+    }
+
+    pips
+}
+
+/// The colored mana a deck's spells ask for.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ManaRequirements {
+    /// Colored pips per color across the main deck's nonland cards, counting every copy.
+    pub pips: HashMap<Color, u32>,
+
+    /// The same pips grouped by the earliest turn each card could be cast on, i.e. its mana value (at least 1).
+    pub pips_by_turn: BTreeMap<u32, HashMap<Color, u32>>,
+
+    /// The most pips of each color found in any single card's cost, e.g. 2 for a deck whose bluest card is `{1}{U}{U}`.
+    pub max_pips_per_card: HashMap<Color, u32>,
+}
+
+impl ManaRequirements {
+    /// Tallies the mana costs of every nonland card in `deck`'s main deck. Multi-faced cards count the costs of all
+    /// their faces, and count as lands only when their front face is one, so a modal spell with a land on its back
+    /// still adds its pips.
+    pub fn from_deck(deck: &ResolvedDeck) -> Self {
+        let mut requirements = ManaRequirements::default();
+
+        for entry in &deck.main {
+            let card = &entry.card;
+            let front = card
+                .face(Face::Front)
+                .map_or(card.type_line.as_str(), |face| face.type_line.as_str());
+            if front.contains("Land") {
+                continue;
+            }
+
+            let cost = match card.combined_mana_cost() {
+                Some(cost) => cost,
+                None => continue,
+            };
+
+            let turn = (card.mana_value().ceil() as u32).max(1);
+            for (color, count) in count_pips(&cost) {
+                let total = count * entry.quantity;
+                *requirements.pips.entry(color).or_insert(0) += total;
+                *requirements
+                    .pips_by_turn
+                    .entry(turn)
+                    .or_default()
+                    .entry(color)
+                    .or_insert(0) += total;
+
+                let max = requirements.max_pips_per_card.entry(color).or_insert(0);
+                *max = (*max).max(count);
+            }
+        }
+
+        requirements
+    }
+
+    /// Splits `total_lands` between the deck's colors in proportion to their pips, rounding so the counts add up to
+    /// `total_lands`. Every color the deck uses gets at least one source when there are enough lands to go around.
+    pub fn suggest_lands(&self, total_lands: u32) -> HashMap<Color, u32> {
+        let used: Vec<(Color, u32)> = COLORS
+            .iter()
+            .filter_map(|color| self.pips.get(color).map(|pips| (*color, *pips)))
+            .filter(|(_, pips)| *pips > 0)
+            .collect();
+        let total_pips: u32 = used.iter().map(|(_, pips)| pips).sum();
+
+        let mut suggestion = HashMap::new();
+        if total_pips == 0 {
+            return suggestion;
+        }
+
+        let mut remainders = Vec::new();
+        let mut assigned = 0;
+        for (color, pips) in &used {
+            let share = u64::from(*pips) * u64::from(total_lands);
+            let count = (share / u64::from(total_pips)) as u32;
+            suggestion.insert(*color, count);
+            remainders.push((share % u64::from(total_pips), *color));
+            assigned += count;
+        }
+
+        // Hand out the lands lost to rounding, largest remainder first.
+        remainders.sort_by_key(|(remainder, _)| std::cmp::Reverse(*remainder));
+        for (_, color) in remainders
+            .iter()
+            .cycle()
+            .take((total_lands - assigned) as usize)
+        {
+            *suggestion.get_mut(color).unwrap() += 1;
+        }
+
+        // Make sure a splash gets a source, taking it from the best-supplied color.
+        if total_lands as usize >= used.len() {
+            for (color, _) in &used {
+                if suggestion[color] == 0 {
+                    let donor = used
+                        .iter()
+                        .map(|(donor, _)| *donor)
+                        .max_by_key(|donor| suggestion[donor])
+                        .unwrap();
+                    *suggestion.get_mut(&donor).unwrap() -= 1;
+                    suggestion.insert(*color, 1);
+                }
+            }
+        }
+
+        suggestion
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_count_pips() {
+        use super::count_pips;
+        use crate::types::card::Color;
+
+        let pips = count_pips("{2}{W}{W}{U/B}{R/P}{2/G}{X}{C}");
+        assert_eq!(Some(&2), pips.get(&Color::White));
+        assert_eq!(Some(&1), pips.get(&Color::Blue));
+        assert_eq!(Some(&1), pips.get(&Color::Black));
+        assert_eq!(Some(&1), pips.get(&Color::Red));
+        assert_eq!(Some(&1), pips.get(&Color::Green));
+        assert!(count_pips("{3}").is_empty());
+    }
+
+    #[test]
+    fn test_requirements() {
+        use super::ManaRequirements;
+        use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+        use crate::testing;
+        use crate::types::card::Color;
+        use serde_json::json;
+
+        let mut deck = ResolvedDeck::default();
+        deck.main.push(ResolvedEntry {
+            quantity: 4,
+            card: testing::card(),
+        });
+        deck.main.push(ResolvedEntry {
+            quantity: 2,
+            card: testing::card_with(json!({ "mana_cost": "{1}{U}{U}", "cmc": 3.0 })),
+        });
+        deck.main.push(ResolvedEntry {
+            quantity: 20,
+            card: testing::card_with(
+                json!({ "mana_cost": null, "type_line": "Basic Land — Mountain" }),
+            ),
+        });
+
+        let requirements = ManaRequirements::from_deck(&deck);
+        assert_eq!(Some(&4), requirements.pips.get(&Color::Red));
+        assert_eq!(Some(&4), requirements.pips.get(&Color::Blue));
+        assert_eq!(Some(&4), requirements.pips_by_turn[&1].get(&Color::Red));
+        assert_eq!(Some(&4), requirements.pips_by_turn[&3].get(&Color::Blue));
+        assert_eq!(Some(&2), requirements.max_pips_per_card.get(&Color::Blue));
+
+        let suggestion = requirements.suggest_lands(17);
+        assert_eq!(17, suggestion.values().sum::<u32>());
+        assert_eq!(9, suggestion[&Color::Blue]);
+        assert_eq!(8, suggestion[&Color::Red]);
+    }
+
+    #[test]
+    fn test_double_faced_requirements() {
+        use super::ManaRequirements;
+        use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+        use crate::testing;
+        use crate::types::card::Color;
+        use serde_json::json;
+
+        let modal = testing::card_with(json!({
+            "name": "Valakut Awakening // Valakut Stoneforge",
+            "layout": "modal_dfc",
+            "mana_cost": null,
+            "cmc": 3.0,
+            "type_line": "Instant // Land",
+            "card_faces": [
+                {"name": "Valakut Awakening", "mana_cost": "{2}{R}", "type_line": "Instant"},
+                {"name": "Valakut Stoneforge", "mana_cost": "", "type_line": "Land"},
+            ],
+        }));
+        let land_back = testing::card_with(json!({
+            "mana_cost": null,
+            "type_line": "Land // Land",
+            "card_faces": [
+                {"name": "Front", "mana_cost": "", "type_line": "Land"},
+                {"name": "Back", "mana_cost": "", "type_line": "Land"},
+            ],
+        }));
+
+        let mut deck = ResolvedDeck::default();
+        for (quantity, card) in [
+            (4, testing::double_faced_card()),
+            (2, modal),
+            (3, land_back),
+        ] {
+            deck.main.push(ResolvedEntry { quantity, card });
+        }
+
+        let requirements = ManaRequirements::from_deck(&deck);
+        assert_eq!(Some(&4), requirements.pips.get(&Color::Blue));
+        assert_eq!(Some(&2), requirements.pips.get(&Color::Red));
+        assert_eq!(Some(&2), requirements.pips_by_turn[&3].get(&Color::Red));
+        assert_eq!(2, requirements.pips.len());
+    }
+
+    #[test]
+    fn test_suggest_splash() {
+        use super::ManaRequirements;
+        use crate::types::card::Color;
+
+        let mut requirements = ManaRequirements::default();
+        requirements.pips.insert(Color::Green, 60);
+        requirements.pips.insert(Color::Blue, 1);
+
+        let suggestion = requirements.suggest_lands(16);
+        assert_eq!(15, suggestion[&Color::Green]);
+        assert_eq!(1, suggestion[&Color::Blue]);
+    }
+}
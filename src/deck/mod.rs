@@ -0,0 +1,167 @@
+//! Decklists and the text formats used to exchange them with other tools.
+
+pub mod deckstats;
+pub mod fingerprint;
+pub mod forge;
+pub mod lands;
+pub mod mana;
+pub mod mtggoldfish;
+pub mod resolve;
+pub mod xmage;
+
+use crate::cards;
+use crate::names;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+/// One line of a decklist: how many copies of a card, and optionally which printing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeckEntry {
+    /// How many copies of the card are in the deck.
+    pub quantity: u32,
+
+    /// The card's name, as written in the list.
+    pub name: String,
+
+    /// The set code of the requested printing, if the list names one.
+    pub set: Option<String>,
+
+    /// The collector number of the requested printing, if the list names one.
+    pub collector_number: Option<String>,
+}
+
+impl DeckEntry {
+    /// An entry for `quantity` copies of `name`, with no printing preference.
+    pub fn new(quantity: u32, name: &str) -> Self {
+        DeckEntry {
+            quantity,
+            name: name.to_string(),
+            set: None,
+            collector_number: None,
+        }
+    }
+}
+
+/// A decklist, split into the main deck and sideboard.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Deck {
+    /// The deck's name, if the list carries one.
+    pub name: Option<String>,
+
+    /// The main deck.
+    pub main: Vec<DeckEntry>,
+
+    /// The sideboard.
+    pub sideboard: Vec<DeckEntry>,
+}
+
+impl Deck {
+    /// Cards with more than `limit` copies across the main deck and sideboard, with their total count. Basic lands are
+    /// exempt. Names are compared by [`names::normalize`], so different spellings of one card count together, and each
+    /// is reported by the spelling it first appears with.
+    pub fn copy_limit_violations(&self, limit: u32) -> Vec<(String, u32)> {
+        let mut counts: HashMap<String, (&str, u32)> = HashMap::new();
+        for entry in self.main.iter().chain(self.sideboard.iter()) {
+            counts
+                .entry(names::normalize(&entry.name))
+                .or_insert((entry.name.as_str(), 0))
+                .1 += entry.quantity;
+        }
+
+        let mut violations: Vec<(String, u32)> = counts
+            .into_values()
+            .filter(|(name, count)| *count > limit && !cards::is_basic_land_name(name))
+            .map(|(name, count)| (name.to_string(), count))
+            .collect();
+        violations.sort();
+        violations
+    }
+}
+
+/// An error encountered while parsing a decklist.
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// The 1-based line number the error occurred on.
+    pub line: usize,
+
+    /// A human-readable explanation of what went wrong.
+    pub message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(line: usize, message: &str) -> Self {
+        ParseError {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Splits a leading quantity (`4 Name` or `4x Name`) off of a decklist line.
+pub(crate) fn split_quantity(line: &str) -> Option<(u32, &str)> {
+    let (quantity, rest) = line.split_at(line.find(char::is_whitespace)?);
+    let quantity = quantity.strip_suffix('x').unwrap_or(quantity);
+
+    Some((quantity.parse().ok()?, rest.trim()))
+}
+
+/// Removes a leading `SB:` sideboard marker, returning whether one was present.
+pub(crate) fn strip_sideboard_marker(line: &str) -> (bool, &str) {
+    match line.strip_prefix("SB:") {
+        Some(rest) => (true, rest.trim()),
+        None => (false, line),
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_copy_limit_violations() {
+        use super::{Deck, DeckEntry};
+
+        let deck = Deck {
+            name: None,
+            main: vec![
+                DeckEntry::new(4, "Lightning Bolt"),
+                DeckEntry::new(18, "Mountain"),
+                DeckEntry::new(3, "Snow-Covered Mountain"),
+                DeckEntry::new(4, "Lava Spike"),
+            ],
+            sideboard: vec![
+                DeckEntry::new(1, "lightning  bolt"),
+                DeckEntry::new(3, "Snow-Covered Mountain"),
+                DeckEntry::new(2, "Aether Vial"),
+                DeckEntry::new(3, "Æther Vial"),
+            ],
+        };
+
+        assert_eq!(
+            vec![
+                ("Aether Vial".to_string(), 5),
+                ("Lightning Bolt".to_string(), 5)
+            ],
+            deck.copy_limit_violations(4)
+        );
+    }
+
+    #[test]
+    fn test_split_quantity() {
+        use super::split_quantity;
+
+        assert_eq!(
+            Some((4, "Lightning Bolt")),
+            split_quantity("4 Lightning Bolt")
+        );
+        assert_eq!(Some((12, "Mountain")), split_quantity("12x  Mountain"));
+        assert_eq!(None, split_quantity("Lightning Bolt"));
+        assert_eq!(None, split_quantity("4"));
+    }
+}
@@ -0,0 +1,167 @@
+//! MTGGoldfish's plain text deck format.
+//!
+//! ```text
+//! 4 Lightning Bolt [M10]
+//! 20 Mountain
+//!
+//! 2 Pyroblast [ICE]
+//! ```
+//!
+//! The main deck comes first and the sideboard follows a blank line or a `Sideboard` header. Lines may also be marked
+//! with `SB:` as in MWS-style lists. The optional `[SET]` suffix pins a printing; MTGGoldfish's `<variant>` tags are
+//! accepted but not preserved.
+
+use super::{split_quantity, strip_sideboard_marker, Deck, DeckEntry, ParseError};
+
+/// Parses an MTGGoldfish text export.
+pub fn parse(text: &str) -> Result<Deck, ParseError> {
+    let mut deck = Deck::default();
+    let mut in_sideboard = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            in_sideboard = in_sideboard || !deck.main.is_empty();
+            continue;
+        }
+
+        if line.eq_ignore_ascii_case("sideboard") || line.eq_ignore_ascii_case("sideboard:") {
+            in_sideboard = true;
+            continue;
+        }
+
+        let (marked, line) = strip_sideboard_marker(line);
+        let (quantity, rest) = split_quantity(line)
+            .ok_or_else(|| ParseError::new(i + 1, "expected a quantity and card name"))?;
+        let entry = parse_card(quantity, rest)
+            .ok_or_else(|| ParseError::new(i + 1, "malformed set hint"))?;
+
+        if in_sideboard || marked {
+            deck.sideboard.push(entry);
+        } else {
+            deck.main.push(entry);
+        }
+    }
+
+    Ok(deck)
+}
+
+fn parse_card(quantity: u32, rest: &str) -> Option<DeckEntry> {
+    let mut name = rest;
+    let mut set = None;
+
+    if let Some(open) = name.rfind('[') {
+        let code = name[open + 1..].strip_suffix(']')?;
+        set = Some(code.trim().to_string());
+        name = name[..open].trim_end();
+    }
+
+    if let Some(open) = name.rfind('<') {
+        name[open + 1..].strip_suffix('>')?;
+        name = name[..open].trim_end();
+    }
+
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut entry = DeckEntry::new(quantity, name);
+    entry.set = set;
+    Some(entry)
+}
+
+/// Formats a deck as MTGGoldfish text, with the sideboard after a blank line.
+pub fn format(deck: &Deck) -> String {
+    let mut out = String::new();
+
+    for entry in &deck.main {
+        format_entry(&mut out, entry);
+    }
+
+    if !deck.sideboard.is_empty() {
+        out.push('\n');
+        for entry in &deck.sideboard {
+            format_entry(&mut out, entry);
+        }
+    }
+
+    out
+}
+
+fn format_entry(out: &mut String, entry: &DeckEntry) {
+    out.push_str(&format!("{} {}", entry.quantity, entry.name));
+    if let Some(set) = &entry.set {
+        out.push_str(&format!(" [{}]", set));
+    }
+    out.push('\n');
+}
+
+mod tests {
+    #[cfg(test)]
+    const BURN: &str = "4 Goblin Guide [ZEN]
+4 Monastery Swiftspear [KTK]
+4 Lightning Bolt [M10]
+4 Lava Spike [CHK]
+4 Rift Bolt [TSP]
+20 Mountain
+
+3 Path to Exile [CON]
+2 Pyroblast [ICE]
+";
+
+    #[test]
+    fn test_parse() {
+        use super::parse;
+        use crate::deck::DeckEntry;
+
+        let deck = parse(BURN).unwrap();
+        assert_eq!(6, deck.main.len());
+        assert_eq!(2, deck.sideboard.len());
+
+        let mut bolt = DeckEntry::new(4, "Lightning Bolt");
+        bolt.set = Some("M10".to_string());
+        assert_eq!(bolt, deck.main[2]);
+        assert_eq!(DeckEntry::new(20, "Mountain"), deck.main[5]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        use super::{format, parse};
+
+        assert_eq!(BURN, format(&parse(BURN).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_sideboard_markers() {
+        use super::parse;
+        use crate::deck::DeckEntry;
+
+        let deck = parse("4 Lightning Bolt\nSB: 2 Pyroblast\n\nSideboard\n1 Smash to Smithereens")
+            .unwrap();
+        assert_eq!(vec![DeckEntry::new(4, "Lightning Bolt")], deck.main);
+        assert_eq!(
+            vec![
+                DeckEntry::new(2, "Pyroblast"),
+                DeckEntry::new(1, "Smash to Smithereens")
+            ],
+            deck.sideboard
+        );
+    }
+
+    #[test]
+    fn test_parse_variant_tags() {
+        use super::parse;
+
+        let deck = parse("1 Fable of the Mirror-Breaker <showcase> [NEO]").unwrap();
+        assert_eq!("Fable of the Mirror-Breaker", deck.main[0].name);
+        assert_eq!(Some("NEO".to_string()), deck.main[0].set);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        use super::parse;
+
+        let err = parse("4 Lightning Bolt\nLava Spike").unwrap_err();
+        assert_eq!(2, err.line);
+    }
+}
@@ -0,0 +1,417 @@
+//! Resolving decklist names into specific card printings.
+
+use super::{Deck, DeckEntry};
+use crate::names;
+use crate::types::card::Card;
+use std::collections::HashMap;
+
+/// Somewhere the resolver can look up every printing of a card by name.
+pub trait PrintingSource {
+    /// All known printings of the card named `name`, in the source's preferred order. An empty result means the name
+    /// is unknown.
+    fn printings(&self, name: &str) -> Vec<Card>;
+}
+
+impl<F: Fn(&str) -> Vec<Card>> PrintingSource for F {
+    fn printings(&self, name: &str) -> Vec<Card> {
+        self(name)
+    }
+}
+
+/// How to choose among the printings of a card when the decklist doesn't pin one.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum PrintingPolicy {
+    /// Take the first printing the source returns, which for Scryfall is its default printing.
+    #[default]
+    Default,
+
+    /// Take the printing with the lowest USD price. Printings without a price are only chosen if none have one.
+    Cheapest,
+
+    /// Take the earliest released printing.
+    Oldest,
+
+    /// Take the most recently released printing.
+    Newest,
+
+    /// Take a printing from the first of these set codes that has one, falling back to the default printing.
+    SetPriority(Vec<String>),
+
+    /// Take the first printing that isn't a promo, falling back to the default printing.
+    PreferNonPromo,
+}
+
+impl PrintingPolicy {
+    /// Chooses a printing from `printings` according to this policy.
+    pub fn choose<'a>(&self, printings: &'a [Card]) -> Option<&'a Card> {
+        match self {
+            PrintingPolicy::Default => printings.first(),
+            PrintingPolicy::Cheapest => {
+                printings
+                    .iter()
+                    .min_by(|a, b| match (a.prices.usd, b.prices.usd) {
+                        (Some(a), Some(b)) => {
+                            a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal)
+                        }
+                        (a, b) => b.is_some().cmp(&a.is_some()),
+                    })
+            }
+            PrintingPolicy::Oldest => printings.iter().min_by_key(|card| card.released_at),
+            PrintingPolicy::Newest => printings.iter().max_by_key(|card| card.released_at),
+            PrintingPolicy::SetPriority(sets) => sets
+                .iter()
+                .find_map(|set| {
+                    printings
+                        .iter()
+                        .find(|card| card.set.eq_ignore_ascii_case(set))
+                })
+                .or_else(|| printings.first()),
+            PrintingPolicy::PreferNonPromo => printings
+                .iter()
+                .find(|card| !card.promo)
+                .or_else(|| printings.first()),
+        }
+    }
+}
+
+/// A decklist line resolved to a specific printing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedEntry {
+    /// How many copies of the card are in the deck.
+    pub quantity: u32,
+
+    /// The chosen printing.
+    pub card: Card,
+}
+
+/// A decklist whose entries have been resolved to printings.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResolvedDeck {
+    /// The deck's name, if the list carried one.
+    pub name: Option<String>,
+
+    /// The resolved main deck.
+    pub main: Vec<ResolvedEntry>,
+
+    /// The resolved sideboard.
+    pub sideboard: Vec<ResolvedEntry>,
+
+    /// Entries from either board whose names the source didn't know.
+    pub unresolved: Vec<DeckEntry>,
+}
+
+/// The printings of one card for a user to choose between, e.g. in an interactive picker.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Disambiguation {
+    /// How many copies the entry asked for.
+    pub quantity: u32,
+
+    /// The source's default printing, standing in for the card as a whole. Use it for anything printing-independent
+    /// such as Oracle text.
+    pub oracle_card: Card,
+
+    /// Every printing, newest first.
+    pub printings: Vec<Card>,
+}
+
+impl Disambiguation {
+    /// One label per printing, in order, e.g. `Magic 2010 (M10) #146, 2009, $2.47`.
+    pub fn labels(&self) -> Vec<String> {
+        self.printings
+            .iter()
+            .map(|card| {
+                let mut label = format!(
+                    "{} ({}) #{}, {}",
+                    card.set_name,
+                    card.set.to_uppercase(),
+                    card.collector_number,
+                    card.released_at.year
+                );
+                if let Some(usd) = card.prices.usd {
+                    label.push_str(&format!(", ${:.2}", usd.0));
+                }
+                label
+            })
+            .collect()
+    }
+
+    /// Resolves the entry to the printing at `index`, as picked from `printings` or `labels`.
+    pub fn choose(&self, index: usize) -> Option<ResolvedEntry> {
+        self.printings.get(index).map(|card| ResolvedEntry {
+            quantity: self.quantity,
+            card: card.clone(),
+        })
+    }
+}
+
+/// The outcome of looking up a single entry without letting the policy pick a printing.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Resolution {
+    /// The entry pinned a printing, or the card only has one.
+    Resolved(ResolvedEntry),
+
+    /// The card has several printings and the entry didn't say which.
+    Ambiguous(Disambiguation),
+
+    /// The source doesn't know the name.
+    Unknown,
+}
+
+/// Resolves decklists against a printing source. Printings are looked up once per name and reused across calls, so
+/// one resolver can work through many decks, or be called again after the policy changes, without repeating lookups.
+/// Names that [`names::normalize`] folds together, like `"Aether Vial"` and `"Æther Vial"`, share a lookup.
+pub struct DeckResolver<S> {
+    source: S,
+    policy: PrintingPolicy,
+    printings: HashMap<String, Vec<Card>>,
+}
+
+impl<S: PrintingSource> DeckResolver<S> {
+    /// A resolver using the default printing policy.
+    pub fn new(source: S) -> Self {
+        DeckResolver {
+            source,
+            policy: PrintingPolicy::default(),
+            printings: HashMap::new(),
+        }
+    }
+
+    /// Sets the policy used for entries that don't pin a printing.
+    pub fn policy(mut self, policy: PrintingPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Changes the policy used by subsequent calls.
+    pub fn set_policy(&mut self, policy: PrintingPolicy) {
+        self.policy = policy;
+    }
+
+    /// Resolves every entry in `deck`. Entries with a set (and collector number) hint get that printing when the
+    /// source has it; everything else is chosen by the policy.
+    pub fn resolve(&mut self, deck: &Deck) -> ResolvedDeck {
+        let mut resolved = ResolvedDeck {
+            name: deck.name.clone(),
+            ..ResolvedDeck::default()
+        };
+
+        for entry in &deck.main {
+            match self.resolve_entry(entry) {
+                Some(entry) => resolved.main.push(entry),
+                None => resolved.unresolved.push(entry.clone()),
+            }
+        }
+
+        for entry in &deck.sideboard {
+            match self.resolve_entry(entry) {
+                Some(entry) => resolved.sideboard.push(entry),
+                None => resolved.unresolved.push(entry.clone()),
+            }
+        }
+
+        resolved
+    }
+
+    /// Resolves a single entry, or returns `None` if the source doesn't know its name.
+    pub fn resolve_entry(&mut self, entry: &DeckEntry) -> Option<ResolvedEntry> {
+        let policy = &self.policy;
+        let printings = Self::printings(&self.source, &mut self.printings, &entry.name);
+
+        hinted(entry, printings)
+            .or_else(|| policy.choose(printings))
+            .map(|card| ResolvedEntry {
+                quantity: entry.quantity,
+                card: card.clone(),
+            })
+    }
+
+    /// Like `resolve_entry`, but instead of applying the policy when the card has several printings and the entry
+    /// doesn't pin one, returns them all so the caller can ask the user.
+    pub fn disambiguate(&mut self, entry: &DeckEntry) -> Resolution {
+        let printings = Self::printings(&self.source, &mut self.printings, &entry.name);
+        let resolved = |card: &Card| {
+            Resolution::Resolved(ResolvedEntry {
+                quantity: entry.quantity,
+                card: card.clone(),
+            })
+        };
+
+        if let Some(card) = hinted(entry, printings) {
+            return resolved(card);
+        }
+
+        match printings.as_slice() {
+            [] => Resolution::Unknown,
+            [only] => resolved(only),
+            [default, ..] => {
+                let mut printings = printings.clone();
+                printings.sort_by_key(|card| std::cmp::Reverse(card.released_at));
+                Resolution::Ambiguous(Disambiguation {
+                    quantity: entry.quantity,
+                    oracle_card: default.clone(),
+                    printings,
+                })
+            }
+        }
+    }
+
+    /// The printings of `name`, looked up from the source the first time they're needed.
+    fn printings<'a>(
+        source: &S,
+        cache: &'a mut HashMap<String, Vec<Card>>,
+        name: &str,
+    ) -> &'a Vec<Card> {
+        cache
+            .entry(names::normalize(name))
+            .or_insert_with(|| source.printings(name))
+    }
+}
+
+/// The printing `entry` pins with its set and collector number hints, if it has them and it exists.
+fn hinted<'a>(entry: &DeckEntry, printings: &'a [Card]) -> Option<&'a Card> {
+    entry.set.as_ref().and_then(|set| {
+        printings.iter().find(|card| {
+            card.set.eq_ignore_ascii_case(set)
+                && entry
+                    .collector_number
+                    .as_ref()
+                    .is_none_or(|number| &card.collector_number == number)
+        })
+    })
+}
+
+mod tests {
+    #[cfg(test)]
+    fn printings(name: &str) -> Vec<crate::types::card::Card> {
+        use crate::testing;
+        use serde_json::json;
+
+        if name != "Lightning Bolt" {
+            return Vec::new();
+        }
+
+        vec![
+            testing::card(),
+            testing::card_with(json!({
+                "set": "lea", "collector_number": "161", "released_at": "1993-08-05",
+                "prices": { "usd": "450.00" }
+            })),
+            testing::card_with(json!({
+                "set": "sld", "collector_number": "1", "released_at": "2021-03-01", "promo": true,
+                "prices": { "usd": "1.25" }
+            })),
+            testing::card_with(json!({
+                "set": "clb", "collector_number": "187", "released_at": "2022-06-10",
+                "prices": { "usd": null }
+            })),
+        ]
+    }
+
+    #[test]
+    fn test_policies() {
+        use super::PrintingPolicy;
+
+        let printings = printings("Lightning Bolt");
+        let set_of = |policy: PrintingPolicy| policy.choose(&printings).unwrap().set.clone();
+
+        assert_eq!("m10", set_of(PrintingPolicy::Default));
+        assert_eq!("sld", set_of(PrintingPolicy::Cheapest));
+        assert_eq!("lea", set_of(PrintingPolicy::Oldest));
+        assert_eq!("clb", set_of(PrintingPolicy::Newest));
+        assert_eq!(
+            "clb",
+            set_of(PrintingPolicy::SetPriority(vec![
+                "2xm".to_string(),
+                "CLB".to_string()
+            ]))
+        );
+        assert_eq!(
+            "m10",
+            set_of(PrintingPolicy::SetPriority(vec!["2xm".to_string()]))
+        );
+        assert_eq!("m10", set_of(PrintingPolicy::PreferNonPromo));
+    }
+
+    #[test]
+    fn test_resolve() {
+        use super::{DeckResolver, PrintingPolicy};
+        use crate::deck::{Deck, DeckEntry};
+
+        let mut pinned = DeckEntry::new(1, "Lightning Bolt");
+        pinned.set = Some("LEA".to_string());
+        let deck = Deck {
+            name: None,
+            main: vec![DeckEntry::new(3, "Lightning Bolt"), pinned],
+            sideboard: vec![DeckEntry::new(2, "Not A Card")],
+        };
+
+        let mut resolver = DeckResolver::new(printings).policy(PrintingPolicy::Newest);
+        let resolved = resolver.resolve(&deck);
+        assert_eq!(3, resolved.main[0].quantity);
+        assert_eq!("clb", resolved.main[0].card.set);
+        assert_eq!("lea", resolved.main[1].card.set);
+        assert!(resolved.sideboard.is_empty());
+        assert_eq!(vec![DeckEntry::new(2, "Not A Card")], resolved.unresolved);
+
+        resolver.set_policy(PrintingPolicy::Oldest);
+        assert_eq!("lea", resolver.resolve(&deck).main[0].card.set);
+    }
+
+    #[test]
+    fn test_disambiguate() {
+        use super::{DeckResolver, Resolution};
+        use crate::deck::DeckEntry;
+
+        let mut resolver = DeckResolver::new(printings);
+        let disambiguation = match resolver.disambiguate(&DeckEntry::new(4, "Lightning Bolt")) {
+            Resolution::Ambiguous(disambiguation) => disambiguation,
+            other => panic!("expected a disambiguation, got {:?}", other),
+        };
+
+        assert_eq!("m10", disambiguation.oracle_card.set);
+        assert_eq!(
+            vec![
+                "Magic 2010 (CLB) #187, 2022",
+                "Magic 2010 (SLD) #1, 2021, $1.25",
+                "Magic 2010 (M10) #146, 2009, $2.47",
+                "Magic 2010 (LEA) #161, 1993, $450.00",
+            ],
+            disambiguation.labels()
+        );
+        assert_eq!("lea", disambiguation.choose(3).unwrap().card.set);
+        assert_eq!(4, disambiguation.choose(3).unwrap().quantity);
+        assert_eq!(None, disambiguation.choose(4));
+
+        let mut pinned = DeckEntry::new(1, "Lightning Bolt");
+        pinned.set = Some("sld".to_string());
+        match resolver.disambiguate(&pinned) {
+            Resolution::Resolved(resolved) => assert_eq!("sld", resolved.card.set),
+            other => panic!("expected a resolved entry, got {:?}", other),
+        }
+
+        assert_eq!(
+            Resolution::Unknown,
+            resolver.disambiguate(&DeckEntry::new(1, "Not A Card"))
+        );
+    }
+
+    #[test]
+    fn test_lookups_shared_across_spellings() {
+        use super::DeckResolver;
+        use crate::deck::DeckEntry;
+        use std::cell::Cell;
+
+        let lookups = Cell::new(0);
+        let mut resolver = DeckResolver::new(|name: &str| {
+            lookups.set(lookups.get() + 1);
+            printings(name)
+        });
+        assert!(resolver
+            .resolve_entry(&DeckEntry::new(1, "Lightning Bolt"))
+            .is_some());
+        assert!(resolver
+            .resolve_entry(&DeckEntry::new(1, "lightning  bolt"))
+            .is_some());
+        assert_eq!(1, lookups.get());
+    }
+}
@@ -0,0 +1,65 @@
+//! XMage's `.dck` deck format.
+//!
+//! ```text
+//! NAME:Burn
+//! 4 [M10:146] Lightning Bolt
+//! 20 Mountain
+//! SB: 2 [ICE:212] Pyroblast
+//! ```
+//!
+//! XMage identifies a printing by set code and collector number together, so the `[SET:NUMBER]` hint is only written
+//! for entries that carry both. Other entries are left for XMage to match by name.
+
+use super::{Deck, DeckEntry};
+
+/// Formats a deck as an XMage `.dck` file.
+pub fn format(deck: &Deck) -> String {
+    let mut out = String::new();
+
+    if let Some(name) = &deck.name {
+        out.push_str(&format!("NAME:{}\n", name));
+    }
+
+    for entry in &deck.main {
+        format_entry(&mut out, entry, "");
+    }
+
+    for entry in &deck.sideboard {
+        format_entry(&mut out, entry, "SB: ");
+    }
+
+    out
+}
+
+fn format_entry(out: &mut String, entry: &DeckEntry, prefix: &str) {
+    out.push_str(&format!("{}{} ", prefix, entry.quantity));
+    if let (Some(set), Some(number)) = (&entry.set, &entry.collector_number) {
+        out.push_str(&format!("[{}:{}] ", set.to_uppercase(), number));
+    }
+    out.push_str(&entry.name);
+    out.push('\n');
+}
+
+mod tests {
+    #[test]
+    fn test_format() {
+        use super::format;
+        use crate::deck::{Deck, DeckEntry};
+
+        let mut bolt = DeckEntry::new(4, "Lightning Bolt");
+        bolt.set = Some("m10".to_string());
+        bolt.collector_number = Some("146".to_string());
+        let mut pyroblast = DeckEntry::new(2, "Pyroblast");
+        pyroblast.set = Some("ICE".to_string());
+        let deck = Deck {
+            name: Some("Burn".to_string()),
+            main: vec![bolt, DeckEntry::new(20, "Mountain")],
+            sideboard: vec![pyroblast],
+        };
+
+        assert_eq!(
+            "NAME:Burn\n4 [M10:146] Lightning Bolt\n20 Mountain\nSB: 2 Pyroblast\n",
+            format(&deck)
+        );
+    }
+}
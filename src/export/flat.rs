@@ -0,0 +1,352 @@
+//! Cards as flat records of scalar columns, for CSV files and dataframes.
+//!
+//! The column names are stable: columns are only ever added, never renamed or removed. Each is named after the
+//! Scryfall field it comes from, and fields of nested objects are joined to their parent's name with `_`, e.g.
+//! `prices_usd` and `legalities_modern`. Within a column:
+//!
+//! * Absent values are [`FlatValue::Null`], written to CSV as an empty field.
+//! * Colors are written as their letters in WUBRG order, e.g. `UR`.
+//! * Other lists are joined with `;`, e.g. `nonfoil;foil`.
+//! * Multi-faced cards use the combined mana cost, type line and oracle text of all of their faces.
+
+use super::write_record;
+use crate::types::card::{Card, Color, Format};
+use crate::types::price::Price;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Every column of a [`FlatRecord`], in order.
+pub const COLUMNS: &[&str] = &[
+    "id",
+    "oracle_id",
+    "name",
+    "lang",
+    "set",
+    "set_name",
+    "set_type",
+    "collector_number",
+    "released_at",
+    "layout",
+    "rarity",
+    "mana_cost",
+    "cmc",
+    "type_line",
+    "oracle_text",
+    "power",
+    "toughness",
+    "loyalty",
+    "defense",
+    "colors",
+    "color_identity",
+    "artist",
+    "flavor_text",
+    "border_color",
+    "frame",
+    "frame_effect",
+    "security_stamp",
+    "full_art",
+    "promo",
+    "reprint",
+    "digital",
+    "reserved",
+    "finishes",
+    "games",
+    "edhrec_rank",
+    "arena_id",
+    "mtgo_id",
+    "tcgplayer_id",
+    "cardmarket_id",
+    "prices_usd",
+    "prices_usd_foil",
+    "prices_usd_etched",
+    "prices_eur",
+    "prices_eur_foil",
+    "prices_tix",
+    "legalities_standard",
+    "legalities_future",
+    "legalities_modern",
+    "legalities_legacy",
+    "legalities_pauper",
+    "legalities_vintage",
+    "legalities_penny",
+    "legalities_commander",
+    "legalities_brawl",
+    "legalities_duel",
+    "legalities_oldschool",
+    "legalities_historic",
+    "legalities_timeless",
+    "legalities_gladiator",
+    "legalities_pioneer",
+    "legalities_explorer",
+    "legalities_oathbreaker",
+    "legalities_standardbrawl",
+    "legalities_alchemy",
+    "legalities_paupercommander",
+    "legalities_premodern",
+    "legalities_predh",
+    "image_uris_normal",
+    "scryfall_uri",
+];
+
+/// The legality columns, by format.
+const LEGALITY_COLUMNS: [(Format, &str); 22] = [
+    (Format::Standard, "legalities_standard"),
+    (Format::Future, "legalities_future"),
+    (Format::Modern, "legalities_modern"),
+    (Format::Legacy, "legalities_legacy"),
+    (Format::Pauper, "legalities_pauper"),
+    (Format::Vintage, "legalities_vintage"),
+    (Format::Penny, "legalities_penny"),
+    (Format::Commander, "legalities_commander"),
+    (Format::Brawl, "legalities_brawl"),
+    (Format::Duel, "legalities_duel"),
+    (Format::Oldschool, "legalities_oldschool"),
+    (Format::Historic, "legalities_historic"),
+    (Format::Timeless, "legalities_timeless"),
+    (Format::Gladiator, "legalities_gladiator"),
+    (Format::Pioneer, "legalities_pioneer"),
+    (Format::Explorer, "legalities_explorer"),
+    (Format::Oathbreaker, "legalities_oathbreaker"),
+    (Format::Standardbrawl, "legalities_standardbrawl"),
+    (Format::Alchemy, "legalities_alchemy"),
+    (Format::Paupercommander, "legalities_paupercommander"),
+    (Format::Premodern, "legalities_premodern"),
+    (Format::Predh, "legalities_predh"),
+];
+
+/// One value of a [`FlatRecord`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FlatValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl fmt::Display for FlatValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FlatValue::Null => Ok(()),
+            FlatValue::Bool(value) => write!(f, "{}", value),
+            FlatValue::Int(value) => write!(f, "{}", value),
+            FlatValue::Float(value) => write!(f, "{}", value),
+            FlatValue::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+impl From<bool> for FlatValue {
+    fn from(value: bool) -> Self {
+        FlatValue::Bool(value)
+    }
+}
+
+impl From<u32> for FlatValue {
+    fn from(value: u32) -> Self {
+        FlatValue::Int(value.into())
+    }
+}
+
+impl From<f64> for FlatValue {
+    fn from(value: f64) -> Self {
+        FlatValue::Float(value)
+    }
+}
+
+impl From<Price> for FlatValue {
+    fn from(price: Price) -> Self {
+        FlatValue::Float(price.0)
+    }
+}
+
+impl From<&str> for FlatValue {
+    fn from(value: &str) -> Self {
+        FlatValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for FlatValue {
+    fn from(value: String) -> Self {
+        FlatValue::Text(value)
+    }
+}
+
+impl<T: Into<FlatValue>> From<Option<T>> for FlatValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(FlatValue::Null, Into::into)
+    }
+}
+
+/// A card as one value per column of [`COLUMNS`], in the same order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlatRecord {
+    values: Vec<(&'static str, FlatValue)>,
+}
+
+impl FlatRecord {
+    /// The value of `column`, if it's one of [`COLUMNS`].
+    pub fn get(&self, column: &str) -> Option<&FlatValue> {
+        self.values
+            .iter()
+            .find(|(name, _)| *name == column)
+            .map(|(_, value)| value)
+    }
+
+    /// Each column and its value, in the order of [`COLUMNS`].
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &FlatValue)> {
+        self.values.iter().map(|(name, value)| (*name, value))
+    }
+
+    fn push<V: Into<FlatValue>>(&mut self, column: &'static str, value: V) {
+        self.values.push((column, value.into()));
+    }
+}
+
+impl From<&Card> for FlatRecord {
+    fn from(card: &Card) -> Self {
+        let mut record = FlatRecord {
+            values: Vec::with_capacity(COLUMNS.len()),
+        };
+        record.push("id", card.id.as_str());
+        record.push("oracle_id", card.oracle_identity());
+        record.push("name", card.name.as_str());
+        record.push("lang", card.lang.as_str());
+        record.push("set", card.set.as_str());
+        record.push("set_name", card.set_name.as_str());
+        record.push("set_type", card.set_type.as_str());
+        record.push("collector_number", card.collector_number.as_str());
+        record.push("released_at", card.released_at.to_string());
+        record.push("layout", card.layout.as_str());
+        record.push("rarity", card.rarity.as_str());
+        record.push("mana_cost", card.combined_mana_cost());
+        record.push("cmc", card.mana_value());
+        record.push("type_line", card.combined_type_line());
+        record.push("oracle_text", card.combined_oracle_text());
+        record.push("power", card.power.as_deref());
+        record.push("toughness", card.toughness.as_deref());
+        record.push("loyalty", card.loyalty.as_deref());
+        record.push("defense", card.defense.as_deref());
+        record.push("colors", card.colors.as_ref().map(color_letters));
+        record.push("color_identity", color_letters(&card.color_identity));
+        record.push("artist", card.artist.as_deref());
+        record.push("flavor_text", card.flavor_text.as_deref());
+        record.push("border_color", card.border_color.as_str());
+        record.push("frame", card.frame.as_str());
+        record.push(
+            "frame_effect",
+            card.frame_effect.as_ref().map(|effect| effect.as_str()),
+        );
+        record.push(
+            "security_stamp",
+            card.security_stamp.map(|stamp| stamp.as_str()),
+        );
+        record.push("full_art", card.full_art);
+        record.push("promo", card.promo);
+        record.push("reprint", card.reprint);
+        record.push("digital", card.digital);
+        record.push("reserved", card.reserved);
+        record.push(
+            "finishes",
+            join(card.finishes.iter().map(|finish| finish.as_str())),
+        );
+        record.push("games", join(card.games.iter().map(|game| game.as_str())));
+        record.push("edhrec_rank", card.edhrec_rank);
+        record.push("arena_id", card.arena_id);
+        record.push("mtgo_id", card.mtgo_id);
+        record.push("tcgplayer_id", card.tcgplayer_id);
+        record.push("cardmarket_id", card.cardmarket_id);
+        record.push("prices_usd", card.prices.usd);
+        record.push("prices_usd_foil", card.prices.usd_foil);
+        record.push("prices_usd_etched", card.prices.usd_etched);
+        record.push("prices_eur", card.prices.eur);
+        record.push("prices_eur_foil", card.prices.eur_foil);
+        record.push("prices_tix", card.prices.tix);
+        for (format, column) in LEGALITY_COLUMNS {
+            record.push(column, card.legalities.get(format).as_str());
+        }
+        record.push(
+            "image_uris_normal",
+            card.image_uris
+                .as_ref()
+                .and_then(|uris| uris.normal.as_ref())
+                .map(|uri| uri.as_str()),
+        );
+        record.push("scryfall_uri", card.scryfall_uri.as_str());
+        record
+    }
+}
+
+/// `colors` as their letters in WUBRG order.
+fn color_letters(colors: &HashSet<Color>) -> String {
+    Color::ALL
+        .iter()
+        .filter(|color| colors.contains(color))
+        .map(|color| color.as_str())
+        .collect()
+}
+
+fn join<'a, I: Iterator<Item = &'a str>>(values: I) -> String {
+    values.collect::<Vec<_>>().join(";")
+}
+
+/// A CSV file of `cards`, with a header row of [`COLUMNS`].
+pub fn csv(cards: &[Card]) -> String {
+    let mut csv = String::new();
+    write_record(&mut csv, ',', COLUMNS);
+    for card in cards {
+        let values: Vec<String> = card
+            .to_flat_record()
+            .iter()
+            .map(|(_, value)| value.to_string())
+            .collect();
+        let fields: Vec<&str> = values.iter().map(String::as_str).collect();
+        write_record(&mut csv, ',', &fields);
+    }
+    csv
+}
+
+mod tests {
+    #[test]
+    fn test_columns() {
+        use super::COLUMNS;
+        use crate::testing;
+
+        for card in [testing::card(), testing::double_faced_card()] {
+            let record = card.to_flat_record();
+            let columns: Vec<&str> = record.iter().map(|(column, _)| column).collect();
+            assert_eq!(COLUMNS, columns.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_to_flat_record() {
+        use super::FlatValue;
+        use crate::testing;
+
+        let record = testing::card().to_flat_record();
+        assert_eq!(Some(&FlatValue::from("Lightning Bolt")), record.get("name"));
+        assert_eq!(Some(&FlatValue::Float(1.0)), record.get("cmc"));
+        assert_eq!(Some(&FlatValue::from("R")), record.get("colors"));
+        assert_eq!(
+            Some(&FlatValue::from("legal")),
+            record.get("legalities_modern")
+        );
+        assert_eq!(None, record.get("prices"));
+
+        let record = testing::double_faced_card().to_flat_record();
+        assert_eq!(Some(&FlatValue::from("{U}")), record.get("mana_cost"));
+        assert_eq!(Some(&FlatValue::Null), record.get("power"));
+    }
+
+    #[test]
+    fn test_csv() {
+        use super::{csv, COLUMNS};
+        use crate::testing;
+
+        let csv = csv(&[testing::card()]);
+        let mut lines = csv.split("\r\n");
+        assert_eq!(COLUMNS.join(","), lines.next().unwrap());
+        assert!(lines.next().unwrap().contains(",Lightning Bolt,en,"));
+    }
+}
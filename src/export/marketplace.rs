@@ -0,0 +1,225 @@
+//! Mass-listing files for the TCGplayer and Cardmarket seller tools.
+
+use super::{format_price, write_record};
+use crate::collection::CollectionEntry;
+use crate::types::card::{Finish, Rarity};
+
+/// The result of exporting a collection to a marketplace format.
+#[derive(Debug)]
+pub struct MarketplaceExport<'a> {
+    /// The listing file contents.
+    pub csv: String,
+
+    /// Entries that could not be listed because their printing has no ID on the marketplace.
+    pub skipped: Vec<&'a CollectionEntry>,
+}
+
+/// Cardmarket's numeric language IDs, keyed by Scryfall language code.
+fn cardmarket_language(lang: &str) -> Option<u8> {
+    match lang {
+        "en" => Some(1),
+        "fr" => Some(2),
+        "de" => Some(3),
+        "es" => Some(4),
+        "it" => Some(5),
+        "zhs" => Some(6),
+        "ja" => Some(7),
+        "pt" => Some(8),
+        "ru" => Some(9),
+        "ko" => Some(10),
+        "zht" => Some(11),
+        _ => None,
+    }
+}
+
+/// TCGplayer's rarity code. It lists timeshifted and bonus-sheet cards as special.
+fn tcgplayer_rarity(rarity: &Rarity) -> &'static str {
+    match rarity {
+        Rarity::Common => "C",
+        Rarity::Uncommon => "U",
+        Rarity::Rare => "R",
+        Rarity::Mythic => "M",
+        Rarity::Special | Rarity::Bonus => "S",
+    }
+}
+
+/// Builds a TCGplayer staged-inventory CSV with the columns `TCGplayer Id`, `Product Line`, `Set Name`,
+/// `Product Name`, `Number`, `Rarity`, `Condition`, `Language`, `Add to Quantity`, and `TCG Marketplace Price`.
+/// Foil and etched copies are listed with TCGplayer's " Foil" condition suffix.
+pub fn tcgplayer_csv(entries: &[CollectionEntry]) -> MarketplaceExport<'_> {
+    let mut csv = String::new();
+    let mut skipped = Vec::new();
+
+    write_record(
+        &mut csv,
+        ',',
+        &[
+            "TCGplayer Id",
+            "Product Line",
+            "Set Name",
+            "Product Name",
+            "Number",
+            "Rarity",
+            "Condition",
+            "Language",
+            "Add to Quantity",
+            "TCG Marketplace Price",
+        ],
+    );
+
+    for entry in entries {
+        let card = &entry.card;
+        let id = match card.tcgplayer_id {
+            Some(id) => id,
+            None => {
+                skipped.push(entry);
+                continue;
+            }
+        };
+
+        let mut condition = entry.condition.tcgplayer_name().to_string();
+        if entry.finish != Finish::Nonfoil {
+            condition.push_str(" Foil");
+        }
+
+        write_record(
+            &mut csv,
+            ',',
+            &[
+                &id.to_string(),
+                "Magic",
+                &card.set_name,
+                &card.name,
+                &card.collector_number,
+                tcgplayer_rarity(&card.rarity),
+                &condition,
+                &card.lang,
+                &entry.quantity.to_string(),
+                &format_price(entry.usd_price()),
+            ],
+        );
+    }
+
+    MarketplaceExport { csv, skipped }
+}
+
+/// Builds a Cardmarket stock CSV with the columns `idProduct`, `Count`, `Name`, `Expansion`, `Language`,
+/// `Condition`, `isFoil`, and `Price`. Languages use Cardmarket's numeric IDs, and entries in a language Cardmarket
+/// does not sell are skipped along with entries that lack a Cardmarket ID.
+pub fn cardmarket_csv(entries: &[CollectionEntry]) -> MarketplaceExport<'_> {
+    let mut csv = String::new();
+    let mut skipped = Vec::new();
+
+    write_record(
+        &mut csv,
+        ',',
+        &[
+            "idProduct",
+            "Count",
+            "Name",
+            "Expansion",
+            "Language",
+            "Condition",
+            "isFoil",
+            "Price",
+        ],
+    );
+
+    for entry in entries {
+        let card = &entry.card;
+        let (id, language) = match (card.cardmarket_id, cardmarket_language(&card.lang)) {
+            (Some(id), Some(language)) => (id, language),
+            _ => {
+                skipped.push(entry);
+                continue;
+            }
+        };
+
+        let is_foil = if entry.finish == Finish::Nonfoil {
+            ""
+        } else {
+            "X"
+        };
+
+        write_record(
+            &mut csv,
+            ',',
+            &[
+                &id.to_string(),
+                &entry.quantity.to_string(),
+                &card.name,
+                &card.set_name,
+                &language.to_string(),
+                entry.condition.cardmarket_code(),
+                is_foil,
+                &format_price(entry.eur_price()),
+            ],
+        );
+    }
+
+    MarketplaceExport { csv, skipped }
+}
+
+mod tests {
+    #[test]
+    fn test_tcgplayer_csv() {
+        use super::tcgplayer_csv;
+        use crate::collection::{CollectionEntry, Condition};
+        use crate::testing;
+        use crate::types::card::Finish;
+
+        let mut foil = CollectionEntry::new(testing::card(), 1);
+        foil.finish = Finish::Foil;
+        foil.condition = Condition::LightlyPlayed;
+        let unlisted = CollectionEntry::new(
+            testing::card_with(serde_json::json!({ "tcgplayer_id": null })),
+            2,
+        );
+        let entries = vec![CollectionEntry::new(testing::card(), 4), foil, unlisted];
+
+        let export = tcgplayer_csv(&entries);
+        let lines: Vec<&str> = export.csv.lines().collect();
+        assert_eq!(3, lines.len());
+        assert_eq!(
+            "33660,Magic,Magic 2010,Lightning Bolt,146,C,Near Mint,en,4,2.47",
+            lines[1]
+        );
+        assert_eq!(
+            "33660,Magic,Magic 2010,Lightning Bolt,146,C,Lightly Played Foil,en,1,14.91",
+            lines[2]
+        );
+        assert_eq!(1, export.skipped.len());
+        assert_eq!(2, export.skipped[0].quantity);
+    }
+
+    #[test]
+    fn test_cardmarket_csv() {
+        use super::cardmarket_csv;
+        use crate::collection::{CollectionEntry, Condition};
+        use crate::testing;
+
+        let mut german =
+            CollectionEntry::new(testing::card_with(serde_json::json!({ "lang": "de" })), 3);
+        german.condition = Condition::Mint;
+        german.price = Some(3.5);
+        let phyrexian =
+            CollectionEntry::new(testing::card_with(serde_json::json!({ "lang": "ph" })), 1);
+        let entries = vec![german, phyrexian];
+
+        let export = cardmarket_csv(&entries);
+        let lines: Vec<&str> = export.csv.lines().collect();
+        assert_eq!(2, lines.len());
+        assert_eq!("21193,3,Lightning Bolt,Magic 2010,3,MT,,3.50", lines[1]);
+        assert_eq!(1, export.skipped.len());
+    }
+
+    #[test]
+    fn test_tcgplayer_rarity() {
+        use super::tcgplayer_rarity;
+        use crate::types::card::Rarity;
+
+        assert_eq!("M", tcgplayer_rarity(&Rarity::Mythic));
+        assert_eq!("S", tcgplayer_rarity(&Rarity::Special));
+        assert_eq!("S", tcgplayer_rarity(&Rarity::Bonus));
+    }
+}
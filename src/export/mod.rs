@@ -0,0 +1,41 @@
+//! Exporters that turn resolved cards into files other tools accept.
+
+pub mod flat;
+pub mod marketplace;
+
+/// Appends one CSV record to `out`, quoting fields that contain the delimiter, quotes, or line breaks.
+pub(crate) fn write_record(out: &mut String, delimiter: char, fields: &[&str]) {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(delimiter);
+        }
+
+        if field.contains(&[delimiter, '"', '\n', '\r'][..]) {
+            out.push('"');
+            out.push_str(&field.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(field);
+        }
+    }
+
+    out.push_str("\r\n");
+}
+
+/// Formats a price with two decimal places, or an empty field when there is no price.
+pub(crate) fn format_price(price: Option<f64>) -> String {
+    price
+        .map(|price| format!("{:.2}", price))
+        .unwrap_or_default()
+}
+
+mod tests {
+    #[test]
+    fn test_write_record() {
+        use super::write_record;
+
+        let mut out = String::new();
+        write_record(&mut out, ',', &["plain", "with, comma", "with \"quotes\""]);
+        assert_eq!("plain,\"with, comma\",\"with \"\"quotes\"\"\"\r\n", out);
+    }
+}
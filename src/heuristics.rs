@@ -0,0 +1,236 @@
+//! Heuristics for how powerful a Commander deck is.
+//!
+//! These are rules of thumb, not a bracket ruling: cards are flagged from curated name lists and oracle text
+//! patterns, and every flag is reported alongside the score so callers can show (or second-guess) the reasoning.
+
+use crate::deck::resolve::ResolvedDeck;
+use crate::names;
+use crate::types::card::Card;
+use std::collections::HashSet;
+
+/// Cards that produce more mana than they cost, or produce it ahead of schedule.
+pub const FAST_MANA: &[&str] = &[
+    "Ancient Tomb",
+    "Chrome Mox",
+    "Dark Ritual",
+    "Elvish Spirit Guide",
+    "Gemstone Caverns",
+    "Grim Monolith",
+    "Jeweled Lotus",
+    "Lion's Eye Diamond",
+    "Lotus Petal",
+    "Mana Crypt",
+    "Mana Vault",
+    "Mox Amber",
+    "Mox Diamond",
+    "Mox Opal",
+    "Simian Spirit Guide",
+    "Sol Ring",
+];
+
+/// Tutors whose oracle text doesn't follow the usual "search your library for a card" wording.
+pub const TUTORS: &[&str] = &[
+    "Demonic Consultation",
+    "Tainted Pact",
+    "Natural Order",
+    "Survival of the Fittest",
+];
+
+/// Well-known two-card combos that win the game or generate infinite resources.
+pub const COMBOS: &[(&str, &str)] = &[
+    ("Thassa's Oracle", "Demonic Consultation"),
+    ("Thassa's Oracle", "Tainted Pact"),
+    ("Dramatic Reversal", "Isochron Scepter"),
+    ("Heliod, Sun-Crowned", "Walking Ballista"),
+    ("Kiki-Jiki, Mirror Breaker", "Zealous Conscripts"),
+    ("Dualcaster Mage", "Twinflame"),
+    ("Exquisite Blood", "Sanguine Bond"),
+    ("Underworld Breach", "Brain Freeze"),
+    ("Food Chain", "Eternal Scourge"),
+    ("Niv-Mizzet, Parun", "Curiosity"),
+];
+
+/// What a flagged card contributes to a deck's power.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Category {
+    Tutor,
+    FastMana,
+    ExtraTurn,
+}
+
+impl Category {
+    /// How many points one card in this category adds to the score.
+    pub fn weight(self) -> u32 {
+        match self {
+            Category::Tutor => 2,
+            Category::FastMana => 2,
+            Category::ExtraTurn => 3,
+        }
+    }
+}
+
+/// Why a card was flagged.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Reason {
+    /// The card is on one of this module's curated lists.
+    KnownCard,
+
+    /// The card's oracle text contains this phrase.
+    OracleText(&'static str),
+}
+
+/// A single card flagged by the heuristics.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Finding {
+    pub card: String,
+    pub category: Category,
+    pub reason: Reason,
+}
+
+/// How many points a complete combo adds to the score.
+pub const COMBO_WEIGHT: u32 = 5;
+
+/// Everything the heuristics found in a deck.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PowerBreakdown {
+    /// Every flagged card, in deck order.
+    pub findings: Vec<Finding>,
+
+    /// Every complete combo in the deck.
+    pub combos: Vec<(String, String)>,
+}
+
+impl PowerBreakdown {
+    /// The flagged cards in one category.
+    pub fn in_category(&self, category: Category) -> impl Iterator<Item = &Finding> {
+        self.findings
+            .iter()
+            .filter(move |finding| finding.category == category)
+    }
+
+    /// The sum of every finding's category weight plus `COMBO_WEIGHT` per combo.
+    pub fn score(&self) -> u32 {
+        let cards: u32 = self
+            .findings
+            .iter()
+            .map(|finding| finding.category.weight())
+            .sum();
+        cards + COMBO_WEIGHT * self.combos.len() as u32
+    }
+}
+
+const TUTOR_TEXT: &str = "search your library for a card";
+const EXTRA_TURN_TEXT: &str = "extra turn after this one";
+
+fn oracle_text(card: &Card) -> String {
+    card.oracle_text
+        .as_deref()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Whether `list` has a card named `name`, comparing names by [`names::normalize`].
+fn listed(list: &[&str], name: &str) -> bool {
+    list.iter().any(|listed| names::normalize(listed) == name)
+}
+
+/// Runs every heuristic over the main deck and sideboard (where Commander lists usually keep the commander). Names are
+/// compared by [`names::normalize`], so the curated lists match however the deck's cards are spelled.
+pub fn analyze(deck: &ResolvedDeck) -> PowerBreakdown {
+    let mut breakdown = PowerBreakdown::default();
+    let mut seen = HashSet::new();
+
+    for entry in deck.main.iter().chain(deck.sideboard.iter()) {
+        let card = &entry.card;
+        let name = card.name.as_str();
+        let normalized = names::normalize(name);
+        if !seen.insert(normalized.clone()) {
+            continue;
+        }
+
+        let text = oracle_text(card);
+        let mut flag = |category: Category, reason: Reason| {
+            breakdown.findings.push(Finding {
+                card: name.to_string(),
+                category,
+                reason,
+            })
+        };
+
+        if listed(FAST_MANA, &normalized) {
+            flag(Category::FastMana, Reason::KnownCard);
+        }
+
+        if listed(TUTORS, &normalized) {
+            flag(Category::Tutor, Reason::KnownCard);
+        } else if text.contains(TUTOR_TEXT) {
+            flag(Category::Tutor, Reason::OracleText(TUTOR_TEXT));
+        }
+
+        if text.contains(EXTRA_TURN_TEXT) {
+            flag(Category::ExtraTurn, Reason::OracleText(EXTRA_TURN_TEXT));
+        }
+    }
+
+    for (a, b) in COMBOS {
+        if seen.contains(&names::normalize(a)) && seen.contains(&names::normalize(b)) {
+            breakdown.combos.push((a.to_string(), b.to_string()));
+        }
+    }
+
+    breakdown
+}
+
+mod tests {
+    #[test]
+    fn test_analyze() {
+        use super::{analyze, Category, Reason};
+        use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+        use crate::testing;
+        use serde_json::json;
+
+        let cards = vec![
+            testing::card_with(json!({ "name": "Sol Ring", "oracle_text": "{T}: Add {C}{C}." })),
+            testing::card_with(json!({
+                "name": "Demonic Tutor",
+                "oracle_text": "Search your library for a card, put that card into your hand, then shuffle."
+            })),
+            testing::card_with(
+                json!({ "name": "Time Warp", "oracle_text": "Target player takes an extra turn after this one." }),
+            ),
+            // Spellings that differ from the curated lists still match them.
+            testing::card_with(json!({ "name": "Thassa’s Oracle", "oracle_text": "" })),
+            testing::card_with(json!({ "name": "SOL RING", "oracle_text": "{T}: Add {C}{C}." })),
+            testing::card_with(json!({ "name": "Demonic Consultation", "oracle_text": "" })),
+            testing::card(),
+        ];
+        let deck = ResolvedDeck {
+            main: cards
+                .into_iter()
+                .map(|card| ResolvedEntry { quantity: 1, card })
+                .collect(),
+            ..ResolvedDeck::default()
+        };
+
+        let breakdown = analyze(&deck);
+        assert_eq!(1, breakdown.in_category(Category::FastMana).count());
+        assert_eq!(2, breakdown.in_category(Category::Tutor).count());
+        assert_eq!(1, breakdown.in_category(Category::ExtraTurn).count());
+        assert_eq!(
+            Reason::OracleText("search your library for a card"),
+            breakdown
+                .in_category(Category::Tutor)
+                .next()
+                .unwrap()
+                .reason
+        );
+        assert_eq!(
+            vec![(
+                "Thassa's Oracle".to_string(),
+                "Demonic Consultation".to_string()
+            )],
+            breakdown.combos
+        );
+        assert_eq!(2 + 2 * 2 + 3 + 5, breakdown.score());
+    }
+}
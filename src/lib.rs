@@ -1,5 +1,35 @@
+pub mod archive;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod bulk;
+pub mod cache;
+pub mod cards;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod collection;
+pub mod deck;
+pub mod export;
+#[cfg(feature = "heuristics")]
+pub mod heuristics;
+pub mod limited;
+pub mod links;
+pub mod lore;
+pub mod metagame;
+pub mod names;
+pub mod oracle;
+pub mod previews;
+pub mod query;
+pub mod quiz;
+pub mod sets;
+pub mod stats;
+pub mod storage;
+#[cfg(feature = "tui")]
+pub mod tui;
 pub mod types;
 
+#[cfg(test)]
+mod testing;
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -0,0 +1,314 @@
+//! Per-set booster layouts and opening packs from a set's cards.
+
+use crate::cards;
+use crate::types::card::{Card, Rarity};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+/// What kind of card can appear in a booster slot.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SlotKind {
+    /// A nonbasic card of exactly this rarity.
+    Rarity(Rarity),
+
+    /// A rare, upgraded to a mythic rare with probability `mythic_rate`.
+    RareOrMythic { mythic_rate: f64 },
+
+    /// A legendary creature or planeswalker of uncommon rarity or higher.
+    Legendary,
+
+    /// A basic land.
+    BasicLand,
+
+    /// Any nonbasic card, e.g. a wildcard or foil slot.
+    Any,
+}
+
+/// A group of identical slots in a booster.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoosterSlot {
+    /// What can appear in these slots.
+    pub kind: SlotKind,
+
+    /// How many of these slots the booster has.
+    pub count: u32,
+}
+
+impl BoosterSlot {
+    /// `count` slots that each hold a card of kind `kind`.
+    pub fn new(kind: SlotKind, count: u32) -> Self {
+        BoosterSlot { kind, count }
+    }
+}
+
+/// The slot structure of one kind of booster.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoosterConfig {
+    /// The booster's slots, filled in this order when it's opened.
+    pub slots: Vec<BoosterSlot>,
+}
+
+fn is_basic_land(card: &Card) -> bool {
+    cards::is_basic_land_name(&card.name)
+}
+
+fn fits(kind: &SlotKind, card: &Card, mythic: bool) -> bool {
+    match kind {
+        SlotKind::Rarity(rarity) => !is_basic_land(card) && card.rarity == *rarity,
+        SlotKind::RareOrMythic { .. } if mythic => card.rarity == Rarity::Mythic,
+        SlotKind::RareOrMythic { .. } => card.rarity == Rarity::Rare,
+        SlotKind::Legendary => {
+            card.type_line.contains("Legendary")
+                && (card.type_line.contains("Creature") || card.type_line.contains("Planeswalker"))
+                && card.rarity != Rarity::Common
+        }
+        SlotKind::BasicLand => is_basic_land(card),
+        SlotKind::Any => !is_basic_land(card),
+    }
+}
+
+impl BoosterConfig {
+    /// A draft booster as printed before play boosters: ten commons, three uncommons, a rare or mythic, and a land.
+    pub fn draft_booster() -> Self {
+        BoosterConfig {
+            slots: vec![
+                BoosterSlot::new(SlotKind::Rarity(Rarity::Common), 10),
+                BoosterSlot::new(SlotKind::Rarity(Rarity::Uncommon), 3),
+                BoosterSlot::new(
+                    SlotKind::RareOrMythic {
+                        mythic_rate: 1.0 / 8.0,
+                    },
+                    1,
+                ),
+                BoosterSlot::new(SlotKind::BasicLand, 1),
+            ],
+        }
+    }
+
+    /// A draft booster where one uncommon slot is a guaranteed legendary, as in Dominaria.
+    pub fn legendary_draft_booster() -> Self {
+        BoosterConfig {
+            slots: vec![
+                BoosterSlot::new(SlotKind::Rarity(Rarity::Common), 10),
+                BoosterSlot::new(SlotKind::Rarity(Rarity::Uncommon), 2),
+                BoosterSlot::new(SlotKind::Legendary, 1),
+                BoosterSlot::new(
+                    SlotKind::RareOrMythic {
+                        mythic_rate: 1.0 / 8.0,
+                    },
+                    1,
+                ),
+                BoosterSlot::new(SlotKind::BasicLand, 1),
+            ],
+        }
+    }
+
+    /// A play booster's playable cards: seven commons, three uncommons, a rare or mythic, a land, a wildcard, and a
+    /// foil of any rarity.
+    pub fn play_booster() -> Self {
+        BoosterConfig {
+            slots: vec![
+                BoosterSlot::new(SlotKind::Rarity(Rarity::Common), 7),
+                BoosterSlot::new(SlotKind::Rarity(Rarity::Uncommon), 3),
+                BoosterSlot::new(
+                    SlotKind::RareOrMythic {
+                        mythic_rate: 1.0 / 7.0,
+                    },
+                    1,
+                ),
+                BoosterSlot::new(SlotKind::BasicLand, 1),
+                BoosterSlot::new(SlotKind::Any, 2),
+            ],
+        }
+    }
+
+    /// The number of cards in a booster with this configuration.
+    pub fn size(&self) -> u32 {
+        self.slots.iter().map(|slot| slot.count).sum()
+    }
+
+    /// Opens a booster from `cards`, a set's printings. No printing appears twice in one booster, and slots that
+    /// `cards` has nothing left for are left empty, so the booster may be smaller than `size()`.
+    pub fn open<R: Rng + ?Sized>(&self, cards: &[Card], rng: &mut R) -> Vec<Card> {
+        let mut booster: Vec<Card> = Vec::new();
+        let mut taken = HashSet::new();
+
+        for slot in &self.slots {
+            for _ in 0..slot.count {
+                let mythic = match slot.kind {
+                    SlotKind::RareOrMythic { mythic_rate } => {
+                        rng.gen_bool(mythic_rate.clamp(0.0, 1.0))
+                    }
+                    _ => false,
+                };
+
+                let candidates = |mythic: bool| -> Vec<&Card> {
+                    cards
+                        .iter()
+                        .filter(|card| !taken.contains(&card.id) && fits(&slot.kind, card, mythic))
+                        .collect()
+                };
+
+                let mut choices = candidates(mythic);
+                if choices.is_empty() && mythic {
+                    choices = candidates(false);
+                }
+
+                if let Some(card) = choices.choose(rng) {
+                    taken.insert(card.id.clone());
+                    booster.push((*card).clone());
+                }
+            }
+        }
+
+        booster
+    }
+}
+
+/// Booster configurations by set code, with a fallback for sets that haven't been registered.
+#[derive(Clone, Debug)]
+pub struct BoosterRegistry {
+    configs: HashMap<String, BoosterConfig>,
+    fallback: BoosterConfig,
+}
+
+impl BoosterRegistry {
+    /// A registry with no set-specific configurations, falling back to the classic draft booster.
+    pub fn new() -> Self {
+        BoosterRegistry {
+            configs: HashMap::new(),
+            fallback: BoosterConfig::draft_booster(),
+        }
+    }
+
+    /// A registry that knows the legendary-slot Dominaria-era sets and the play booster sets from Murders at Karlov
+    /// Manor onwards.
+    pub fn with_defaults() -> Self {
+        let mut registry = BoosterRegistry::new();
+
+        for set in &["dom", "dmu"] {
+            registry.register(set, BoosterConfig::legendary_draft_booster());
+        }
+
+        for set in &["mkm", "otj", "blb", "dsk", "fdn", "dft", "tdm"] {
+            registry.register(set, BoosterConfig::play_booster());
+        }
+
+        registry
+    }
+
+    /// Registers `config` for the set with code `set`, replacing any existing configuration.
+    pub fn register(&mut self, set: &str, config: BoosterConfig) {
+        self.configs.insert(set.to_lowercase(), config);
+    }
+
+    /// Changes the configuration used for unregistered sets.
+    pub fn set_fallback(&mut self, config: BoosterConfig) {
+        self.fallback = config;
+    }
+
+    /// The configuration for the set with code `set`, or the fallback if none is registered.
+    pub fn get(&self, set: &str) -> &BoosterConfig {
+        self.configs
+            .get(&set.to_lowercase())
+            .unwrap_or(&self.fallback)
+    }
+
+    /// Whether the set with code `set` has a configuration of its own.
+    pub fn contains(&self, set: &str) -> bool {
+        self.configs.contains_key(&set.to_lowercase())
+    }
+}
+
+impl Default for BoosterRegistry {
+    fn default() -> Self {
+        BoosterRegistry::with_defaults()
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    fn set_cards() -> Vec<crate::types::card::Card> {
+        use crate::testing;
+        use serde_json::json;
+
+        let mut cards = Vec::new();
+        let mut add = |count: usize, rarity: &str, type_line: &str| {
+            for _ in 0..count {
+                let id = format!("card-{}", cards.len());
+                let name = type_line.rsplit(' ').next().unwrap();
+                cards.push(testing::card_with(
+                    json!({ "id": id, "name": name, "rarity": rarity, "type_line": type_line }),
+                ));
+            }
+        };
+
+        add(20, "common", "Instant");
+        add(10, "uncommon", "Creature — Goblin");
+        add(2, "uncommon", "Legendary Creature — Human Wizard");
+        add(5, "rare", "Sorcery");
+        add(2, "mythic", "Legendary Planeswalker — Chandra");
+        add(5, "common", "Basic Land — Mountain");
+        cards
+    }
+
+    #[test]
+    fn test_open() {
+        use super::BoosterConfig;
+        use crate::types::card::Rarity;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let cards = set_cards();
+        let mut rng = StdRng::seed_from_u64(7);
+        let config = BoosterConfig::legendary_draft_booster();
+
+        for _ in 0..20 {
+            let booster = config.open(&cards, &mut rng);
+            assert_eq!(config.size() as usize, booster.len());
+
+            let count =
+                |rarity: Rarity| booster.iter().filter(|card| card.rarity == rarity).count();
+            assert_eq!(11, count(Rarity::Common));
+            assert!(booster
+                .iter()
+                .any(|card| card.type_line.starts_with("Legendary")));
+            assert_eq!(
+                1,
+                booster
+                    .iter()
+                    .filter(|card| card.type_line.starts_with("Basic"))
+                    .count()
+            );
+        }
+    }
+
+    #[test]
+    fn test_open_short_pool() {
+        use super::BoosterConfig;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let cards = vec![crate::testing::card()];
+        let booster = BoosterConfig::draft_booster().open(&cards, &mut StdRng::seed_from_u64(1));
+        assert_eq!(1, booster.len());
+    }
+
+    #[test]
+    fn test_registry() {
+        use super::{BoosterConfig, BoosterRegistry, BoosterSlot, SlotKind};
+
+        let mut registry = BoosterRegistry::with_defaults();
+        assert_eq!(&BoosterConfig::play_booster(), registry.get("MKM"));
+        assert_eq!(&BoosterConfig::draft_booster(), registry.get("m10"));
+        assert!(!registry.contains("m10"));
+
+        let cube = BoosterConfig {
+            slots: vec![BoosterSlot::new(SlotKind::Any, 15)],
+        };
+        registry.register("m10", cube.clone());
+        assert_eq!(&cube, registry.get("m10"));
+        assert_eq!(15, registry.get("m10").size());
+    }
+}
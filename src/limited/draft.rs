@@ -0,0 +1,395 @@
+//! Simulating a booster draft around a table.
+
+use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+use crate::types::card::Card;
+use std::error;
+use std::fmt;
+
+/// Which way packs are passed after each pick.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PassDirection {
+    /// To the next seat, i.e. seat `n` passes to seat `n + 1`.
+    Left,
+
+    /// To the previous seat, i.e. seat `n` passes to seat `n - 1`.
+    Right,
+}
+
+/// A record of one card taken during the draft.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pick {
+    /// The 0-based round (pack number) the pick was made in.
+    pub round: usize,
+
+    /// The 0-based pick number within the round.
+    pub pick: usize,
+
+    /// The seat that made the pick.
+    pub seat: usize,
+
+    /// The card that was taken.
+    pub card: Card,
+}
+
+/// Ways a draft or a pick can be rejected.
+#[derive(Debug, Eq, PartialEq)]
+pub enum DraftError {
+    /// The draft has no rounds, or its first round has no packs, so there's no one to seat.
+    Empty,
+
+    /// A round doesn't have one pack per seat.
+    SeatCount {
+        /// The 0-based round.
+        round: usize,
+
+        /// How many packs it has.
+        packs: usize,
+    },
+
+    /// A pack is empty, or not the same size as the other packs of its round.
+    PackSize {
+        /// The 0-based round.
+        round: usize,
+
+        /// The seat that opens the pack.
+        seat: usize,
+    },
+
+    /// The draft has no packs left to pick from.
+    Finished,
+
+    /// There is no seat with this number.
+    NoSuchSeat(usize),
+
+    /// The seat has already picked from its current pack and is waiting for the others.
+    AlreadyPicked(usize),
+
+    /// The pack in front of the seat has no card at this index.
+    NoSuchCard(usize),
+}
+
+impl fmt::Display for DraftError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DraftError::Empty => write!(f, "the draft has no packs"),
+            DraftError::SeatCount { round, packs } => {
+                write!(f, "round {} has {} packs, not one per seat", round, packs)
+            }
+            DraftError::PackSize { round, seat } => write!(
+                f,
+                "seat {}'s pack in round {} is empty or a different size from the others",
+                seat, round
+            ),
+            DraftError::Finished => write!(f, "the draft is finished"),
+            DraftError::NoSuchSeat(seat) => write!(f, "there is no seat {}", seat),
+            DraftError::AlreadyPicked(seat) => {
+                write!(f, "seat {} has already picked this turn", seat)
+            }
+            DraftError::NoSuchCard(index) => write!(f, "the pack has no card at index {}", index),
+        }
+    }
+}
+
+impl error::Error for DraftError {}
+
+/// The state of a draft: the packs in front of each seat, the packs still to be opened, and every pick so far.
+///
+/// Seats pick simultaneously. Once every seat has picked from its current pack, the packs are passed — left in the
+/// first round, right in the second, and so on — and the next pick begins. A round ends when its packs are empty.
+#[derive(Clone, Debug)]
+pub struct DraftState {
+    unopened: Vec<Vec<Vec<Card>>>,
+    packs: Vec<Vec<Card>>,
+    picked: Vec<bool>,
+    pools: Vec<Vec<Card>>,
+    picks: Vec<Pick>,
+    round: usize,
+    pick: usize,
+}
+
+impl DraftState {
+    /// Starts a draft from `rounds`, where `rounds[r][s]` is the pack seat `s` opens in round `r`. Fails unless there's
+    /// at least one round and seat, every round has one pack per seat, and the packs of each round are all the same,
+    /// non-zero size.
+    pub fn new(rounds: Vec<Vec<Vec<Card>>>) -> Result<Self, DraftError> {
+        let seats = rounds.first().map_or(0, |packs| packs.len());
+        if seats == 0 {
+            return Err(DraftError::Empty);
+        }
+        for (round, packs) in rounds.iter().enumerate() {
+            if packs.len() != seats {
+                return Err(DraftError::SeatCount {
+                    round,
+                    packs: packs.len(),
+                });
+            }
+            let size = packs[0].len();
+            if let Some(seat) = packs
+                .iter()
+                .position(|pack| pack.is_empty() || pack.len() != size)
+            {
+                return Err(DraftError::PackSize { round, seat });
+            }
+        }
+
+        let mut unopened = rounds;
+        unopened.reverse();
+
+        let mut state = DraftState {
+            unopened,
+            packs: vec![Vec::new(); seats],
+            picked: vec![false; seats],
+            pools: vec![Vec::new(); seats],
+            picks: Vec::new(),
+            round: 0,
+            pick: 0,
+        };
+        state.open_next_round();
+        Ok(state)
+    }
+
+    fn open_next_round(&mut self) {
+        if let Some(packs) = self.unopened.pop() {
+            self.packs = packs;
+        }
+    }
+
+    /// The number of seats at the table.
+    pub fn seats(&self) -> usize {
+        self.pools.len()
+    }
+
+    /// The current 0-based round.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// The direction packs are passed in the current round.
+    pub fn direction(&self) -> PassDirection {
+        if self.round.is_multiple_of(2) {
+            PassDirection::Left
+        } else {
+            PassDirection::Right
+        }
+    }
+
+    /// Whether every pack has been picked clean.
+    pub fn is_finished(&self) -> bool {
+        self.packs.iter().all(|pack| pack.is_empty()) && self.unopened.is_empty()
+    }
+
+    /// The pack currently in front of `seat`.
+    pub fn pack(&self, seat: usize) -> Option<&[Card]> {
+        self.packs.get(seat).map(|pack| pack.as_slice())
+    }
+
+    /// The cards `seat` has taken so far.
+    pub fn pool(&self, seat: usize) -> Option<&[Card]> {
+        self.pools.get(seat).map(|pool| pool.as_slice())
+    }
+
+    /// Every pick made so far, in order.
+    pub fn picks(&self) -> &[Pick] {
+        &self.picks
+    }
+
+    /// Takes the card at `index` from the pack in front of `seat`. The packs are passed once every seat has picked.
+    pub fn pick(&mut self, seat: usize, index: usize) -> Result<&Card, DraftError> {
+        if seat >= self.seats() {
+            return Err(DraftError::NoSuchSeat(seat));
+        }
+        if self.is_finished() {
+            return Err(DraftError::Finished);
+        }
+        if self.picked[seat] {
+            return Err(DraftError::AlreadyPicked(seat));
+        }
+        if index >= self.packs[seat].len() {
+            return Err(DraftError::NoSuchCard(index));
+        }
+
+        let card = self.packs[seat].remove(index);
+        self.picks.push(Pick {
+            round: self.round,
+            pick: self.pick,
+            seat,
+            card: card.clone(),
+        });
+        self.pools[seat].push(card);
+        self.picked[seat] = true;
+
+        let picks_made = self.picks.len();
+        if self.picked.iter().all(|picked| *picked) {
+            self.pass();
+        }
+
+        Ok(&self.picks[picks_made - 1].card)
+    }
+
+    /// Has every seat pick once, using `choose(seat, pack)` to pick an index from each pack.
+    pub fn pick_all<F: FnMut(usize, &[Card]) -> usize>(
+        &mut self,
+        mut choose: F,
+    ) -> Result<(), DraftError> {
+        for seat in 0..self.seats() {
+            let index = choose(seat, &self.packs[seat]);
+            self.pick(seat, index)?;
+        }
+        Ok(())
+    }
+
+    /// Runs the rest of the draft with `choose(seat, pack)` picking for every seat, e.g. to simulate a table of bots.
+    pub fn run<F: FnMut(usize, &[Card]) -> usize>(
+        &mut self,
+        mut choose: F,
+    ) -> Result<(), DraftError> {
+        while !self.is_finished() {
+            self.pick_all(&mut choose)?;
+        }
+        Ok(())
+    }
+
+    fn pass(&mut self) {
+        self.picked.iter_mut().for_each(|picked| *picked = false);
+        self.pick += 1;
+
+        match self.direction() {
+            PassDirection::Left => self.packs.rotate_right(1),
+            PassDirection::Right => self.packs.rotate_left(1),
+        }
+
+        if self.packs.iter().all(|pack| pack.is_empty()) && !self.unopened.is_empty() {
+            self.round += 1;
+            self.pick = 0;
+            self.open_next_round();
+        }
+    }
+
+    /// `seat`'s pool as a deck, with duplicate printings combined into one entry.
+    pub fn pool_deck(&self, seat: usize) -> Option<ResolvedDeck> {
+        let pool = self.pools.get(seat)?;
+        let mut deck = ResolvedDeck::default();
+
+        for card in pool {
+            match deck.main.iter_mut().find(|entry| entry.card.id == card.id) {
+                Some(entry) => entry.quantity += 1,
+                None => deck.main.push(ResolvedEntry {
+                    quantity: 1,
+                    card: card.clone(),
+                }),
+            }
+        }
+
+        Some(deck)
+    }
+
+    /// Every seat's pool as a deck.
+    pub fn pool_decks(&self) -> Vec<ResolvedDeck> {
+        (0..self.seats())
+            .filter_map(|seat| self.pool_deck(seat))
+            .collect()
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    fn rounds(rounds: usize, seats: usize, size: usize) -> Vec<Vec<Vec<crate::types::card::Card>>> {
+        use crate::testing;
+        use serde_json::json;
+
+        (0..rounds)
+            .map(|r| {
+                (0..seats)
+                    .map(|s| {
+                        (0..size)
+                            .map(|c| {
+                                testing::card_with(json!({ "id": format!("{}-{}-{}", r, s, c) }))
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_passing() {
+        use super::{DraftState, PassDirection};
+
+        let mut draft = DraftState::new(rounds(2, 3, 2)).unwrap();
+        assert_eq!(PassDirection::Left, draft.direction());
+
+        draft.pick_all(|_, _| 0).unwrap();
+        // Seat 1 now holds what's left of seat 0's first pack.
+        assert_eq!("0-0-1", draft.pack(1).unwrap()[0].id);
+
+        draft.pick_all(|_, _| 0).unwrap();
+        assert_eq!(1, draft.round());
+        assert_eq!(PassDirection::Right, draft.direction());
+
+        draft.pick_all(|_, _| 0).unwrap();
+        // Seat 1 now holds what's left of seat 2's second pack.
+        assert_eq!("1-2-1", draft.pack(1).unwrap()[0].id);
+    }
+
+    #[test]
+    fn test_run() {
+        use super::DraftState;
+
+        let mut draft = DraftState::new(rounds(3, 4, 5)).unwrap();
+        draft.run(|_, pack| pack.len() - 1).unwrap();
+
+        assert!(draft.is_finished());
+        assert_eq!(60, draft.picks().len());
+        for deck in draft.pool_decks() {
+            assert_eq!(
+                15,
+                deck.main.iter().map(|entry| entry.quantity).sum::<u32>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        use super::{DraftError, DraftState};
+
+        let mut draft = DraftState::new(rounds(1, 2, 1)).unwrap();
+        assert_eq!(Some(DraftError::NoSuchSeat(2)), draft.pick(2, 0).err());
+        assert_eq!(Some(DraftError::NoSuchCard(1)), draft.pick(0, 1).err());
+        draft.pick(0, 0).unwrap();
+        assert_eq!(Some(DraftError::AlreadyPicked(0)), draft.pick(0, 0).err());
+        draft.pick(1, 0).unwrap();
+        assert_eq!(Some(DraftError::Finished), draft.pick(0, 0).err());
+        assert_eq!(Some(DraftError::NoSuchSeat(5)), draft.pick(5, 0).err());
+    }
+
+    #[test]
+    fn test_shape() {
+        use super::{DraftError, DraftState};
+
+        assert_eq!(Some(DraftError::Empty), DraftState::new(Vec::new()).err());
+        assert_eq!(
+            Some(DraftError::Empty),
+            DraftState::new(vec![Vec::new()]).err()
+        );
+
+        let mut uneven = rounds(2, 3, 2);
+        uneven[1].pop();
+        assert_eq!(
+            Some(DraftError::SeatCount { round: 1, packs: 2 }),
+            DraftState::new(uneven).err()
+        );
+
+        let mut short = rounds(2, 3, 2);
+        short[0][2].pop();
+        assert_eq!(
+            Some(DraftError::PackSize { round: 0, seat: 2 }),
+            DraftState::new(short).err()
+        );
+
+        assert_eq!(
+            Some(DraftError::PackSize { round: 0, seat: 0 }),
+            DraftState::new(rounds(1, 2, 0)).err()
+        );
+    }
+}
@@ -0,0 +1,4 @@
+//! Limited play: drafting and opening packs.
+
+pub mod booster;
+pub mod draft;
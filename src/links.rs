@@ -0,0 +1,313 @@
+//! Turning card links pasted by users back into identifiers Scryfall's API can look up.
+
+use crate::types::uri::{percent_decode, percent_encode};
+use crate::types::uuid::Uuid;
+use std::fmt;
+
+/// Language codes Scryfall uses in card URLs.
+const LANGUAGES: [&str; 17] = [
+    "en", "es", "fr", "de", "it", "pt", "ja", "ko", "ru", "zhs", "zht", "he", "la", "grc", "ar",
+    "sa", "ph",
+];
+
+/// A card, identified the way a link identified it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CardLink {
+    /// A card by its Scryfall ID.
+    Id(Uuid),
+
+    /// A printing by set code and collector number, in a specific language if the link named one.
+    SetNumber {
+        set: String,
+        collector_number: String,
+        lang: Option<String>,
+    },
+
+    /// A printing by its Gatherer multiverse ID.
+    Multiverse(u32),
+
+    /// A printing by its TCGplayer product ID.
+    Tcgplayer(u32),
+}
+
+impl CardLink {
+    /// The path of the API endpoint that returns this card, e.g. `/cards/m10/146`.
+    pub fn api_path(&self) -> String {
+        match self {
+            CardLink::Id(id) => format!("/cards/{}", id),
+            CardLink::SetNumber {
+                set,
+                collector_number,
+                lang: None,
+            } => format!("/cards/{}/{}", set, percent_encode(collector_number)),
+            CardLink::SetNumber {
+                set,
+                collector_number,
+                lang: Some(lang),
+            } => format!(
+                "/cards/{}/{}/{}",
+                set,
+                percent_encode(collector_number),
+                lang
+            ),
+            CardLink::Multiverse(id) => format!("/cards/multiverse/{}", id),
+            CardLink::Tcgplayer(id) => format!("/cards/tcgplayer/{}", id),
+        }
+    }
+}
+
+impl fmt::Display for CardLink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "https://api.scryfall.com{}", self.api_path())
+    }
+}
+
+/// Parses a card link into the card it points to. Understood links are:
+///
+/// - Scryfall card pages (`scryfall.com/card/m10/146/lightning-bolt`, optionally with a language), API card URLs, and
+///   card image URLs.
+/// - Gatherer card pages, both the classic `Details.aspx?multiverseid=...` form and the newer
+///   `gatherer.wizards.com/M10/en-us/146/lightning-bolt` form.
+/// - TCGplayer product pages (`tcgplayer.com/product/33660/...`).
+///
+/// Returns `None` for anything else.
+pub fn parse(url: &str) -> Option<CardLink> {
+    let url = split_url(url)?;
+    let segments: Vec<&str> = url.segments.iter().map(String::as_str).collect();
+    let parameter = |name: &str| {
+        url.query
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    };
+
+    match (url.host.as_str(), segments.as_slice()) {
+        ("scryfall.com", ["card", set, number, rest @ ..])
+        | ("api.scryfall.com", ["cards", set, number, rest @ ..])
+            if !set.is_empty() && !number.is_empty() =>
+        {
+            let lang = rest
+                .first()
+                .filter(|lang| LANGUAGES.contains(lang))
+                .map(|lang| lang.to_string());
+            Some(CardLink::SetNumber {
+                set: set.to_lowercase(),
+                collector_number: number.to_string(),
+                lang,
+            })
+        }
+        ("api.scryfall.com", ["cards", id]) if is_uuid(id) => Some(CardLink::Id(id.to_lowercase())),
+        ("cards.scryfall.io", [.., file]) | ("c1.scryfall.com", [.., file]) => {
+            let id = file.split('.').next()?;
+            if is_uuid(id) {
+                Some(CardLink::Id(id.to_lowercase()))
+            } else {
+                None
+            }
+        }
+        ("gatherer.wizards.com", ["Pages", ..]) | ("gatherer.wizards.com", ["Handlers", ..]) => {
+            parameter("multiverseid")?
+                .parse()
+                .ok()
+                .map(CardLink::Multiverse)
+        }
+        ("gatherer.wizards.com", [set, locale, number, ..]) if locale.contains('-') => {
+            Some(CardLink::SetNumber {
+                set: set.to_lowercase(),
+                collector_number: number.to_string(),
+                lang: gatherer_language(locale).map(str::to_string),
+            })
+        }
+        ("tcgplayer.com", ["product", id, ..]) | ("shop.tcgplayer.com", ["product", id, ..]) => {
+            id.parse().ok().map(CardLink::Tcgplayer)
+        }
+        _ => None,
+    }
+}
+
+/// The Scryfall language code for a Gatherer locale such as `ja-jp`. English maps to `None`, since Scryfall assumes
+/// English when no language is given.
+fn gatherer_language(locale: &str) -> Option<&'static str> {
+    match locale.to_lowercase().as_str() {
+        "zh-cn" | "zh-hans" => Some("zhs"),
+        "zh-tw" | "zh-hant" => Some("zht"),
+        "pt-br" | "pt-pt" => Some("pt"),
+        locale => LANGUAGES
+            .iter()
+            .find(|lang| locale.split('-').next() == Some(lang))
+            .filter(|lang| **lang != "en")
+            .copied(),
+    }
+}
+
+/// The parts of a URL that identify a card.
+struct ParsedUrl {
+    /// The lowercase host, without any `www.`.
+    host: String,
+
+    /// The percent-decoded, non-empty path segments.
+    segments: Vec<String>,
+
+    /// The percent-decoded query parameters.
+    query: Vec<(String, String)>,
+}
+
+/// Splits a URL into its parts. The scheme is optional, and the fragment is dropped.
+fn split_url(url: &str) -> Option<ParsedUrl> {
+    let url = url.trim();
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let rest = rest.split('#').next()?;
+    let (rest, query) = match rest.find('?') {
+        Some(at) => (&rest[..at], &rest[at + 1..]),
+        None => (rest, ""),
+    };
+
+    let mut parts = rest.split('/');
+    let host = parts.next()?.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host).to_string();
+    if host.is_empty() {
+        return None;
+    }
+
+    let segments = parts
+        .filter(|part| !part.is_empty())
+        .map(percent_decode)
+        .collect();
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.find('=') {
+            Some(at) => (percent_decode(&pair[..at]), percent_decode(&pair[at + 1..])),
+            None => (percent_decode(pair), String::new()),
+        })
+        .collect();
+    Some(ParsedUrl {
+        host,
+        segments,
+        query,
+    })
+}
+
+/// Whether `text` is a UUID in its usual hyphenated form, in either case.
+pub(crate) fn is_uuid(text: &str) -> bool {
+    let groups: Vec<&str> = text.split('-').collect();
+    groups
+        .iter()
+        .map(|group| group.len())
+        .eq([8, 4, 4, 4, 12].iter().copied())
+        && groups
+            .iter()
+            .all(|group| group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+mod tests {
+    #[test]
+    fn test_parse_website() {
+        use super::{parse, CardLink};
+
+        let bolt = CardLink::SetNumber {
+            set: "m10".to_string(),
+            collector_number: "146".to_string(),
+            lang: None,
+        };
+        assert_eq!(
+            Some(bolt.clone()),
+            parse("https://scryfall.com/card/m10/146/lightning-bolt")
+        );
+        assert_eq!(
+            Some(bolt.clone()),
+            parse("scryfall.com/card/M10/146?utm_source=discord")
+        );
+        assert_eq!(Some(bolt), parse("https://api.scryfall.com/cards/m10/146"));
+
+        assert_eq!(
+            Some(CardLink::SetNumber {
+                set: "war".to_string(),
+                collector_number: "1★".to_string(),
+                lang: Some("ja".to_string()),
+            }),
+            parse("https://scryfall.com/card/war/1%E2%98%85/ja/%E5%B9%B3%E7%A9%8F")
+        );
+    }
+
+    #[test]
+    fn test_parse_ids() {
+        use super::{parse, CardLink};
+
+        let id = CardLink::Id("e3285e6b-3e79-4d7c-bf96-d920f973b80d".to_string());
+        assert_eq!(
+            Some(id.clone()),
+            parse("https://api.scryfall.com/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d")
+        );
+        assert_eq!(
+            Some(id),
+            parse("https://cards.scryfall.io/normal/front/e/3/e3285e6b-3e79-4d7c-bf96-d920f973b80d.jpg?1562442158")
+        );
+
+        assert_eq!(None, parse("https://scryfall.com/sets/m10"));
+        assert_eq!(None, parse("https://example.com/card/m10/146"));
+        assert_eq!(None, parse(""));
+    }
+
+    #[test]
+    fn test_parse_other_sites() {
+        use super::{parse, CardLink};
+
+        assert_eq!(
+            Some(CardLink::Multiverse(191089)),
+            parse("https://gatherer.wizards.com/Pages/Card/Details.aspx?printed=false&multiverseId=191089")
+        );
+        assert_eq!(
+            Some(CardLink::SetNumber {
+                set: "m10".to_string(),
+                collector_number: "146".to_string(),
+                lang: None,
+            }),
+            parse("https://gatherer.wizards.com/M10/en-us/146/lightning-bolt")
+        );
+        assert_eq!(
+            Some(CardLink::SetNumber {
+                set: "m10".to_string(),
+                collector_number: "146".to_string(),
+                lang: Some("zhs".to_string()),
+            }),
+            parse("gatherer.wizards.com/M10/zh-cn/146")
+        );
+        assert_eq!(
+            Some(CardLink::Tcgplayer(33660)),
+            parse("https://www.tcgplayer.com/product/33660/magic-magic-2010-lightning-bolt?Language=English")
+        );
+
+        assert_eq!(
+            "/cards/multiverse/191089",
+            CardLink::Multiverse(191089).api_path()
+        );
+        assert_eq!(
+            "/cards/tcgplayer/33660",
+            CardLink::Tcgplayer(33660).api_path()
+        );
+        assert_eq!(
+            None,
+            parse("https://gatherer.wizards.com/Pages/Default.aspx")
+        );
+    }
+
+    #[test]
+    fn test_api_path() {
+        use super::CardLink;
+
+        let link = CardLink::SetNumber {
+            set: "war".to_string(),
+            collector_number: "1★".to_string(),
+            lang: Some("ja".to_string()),
+        };
+        assert_eq!("/cards/war/1%E2%98%85/ja", link.api_path());
+        assert_eq!(
+            "https://api.scryfall.com/cards/war/1%E2%98%85/ja",
+            link.to_string()
+        );
+    }
+}
@@ -0,0 +1,339 @@
+//! Flavor text, artist credits and Story Spotlight chronology, for lore and trivia tools. The flavor and artist
+//! filters are the local counterparts of the `ft:` and `a:` search terms, for filtering bulk data without a round trip.
+
+use crate::types::card::Card;
+use crate::types::date::Date;
+use crate::types::set::Set;
+use std::collections::BTreeMap;
+
+/// The flavor text printed on `card`: the card's own, or each face's in order for multi-faced cards.
+pub fn flavor_texts(card: &Card) -> Vec<&str> {
+    match &card.flavor_text {
+        Some(text) => vec![text.as_str()],
+        None => card
+            .card_faces
+            .iter()
+            .flatten()
+            .filter_map(|face| face.flavor_text.as_deref())
+            .collect(),
+    }
+}
+
+/// The artists credited on `card`: the card's own, or each face's for multi-faced cards, without repeats.
+pub fn artists(card: &Card) -> Vec<&str> {
+    let mut artists: Vec<&str> = Vec::new();
+    let credits = card.artist.as_deref().into_iter().chain(
+        card.card_faces
+            .iter()
+            .flatten()
+            .filter_map(|face| face.artist.as_deref()),
+    );
+    for artist in credits {
+        if !artists.contains(&artist) {
+            artists.push(artist);
+        }
+    }
+    artists
+}
+
+/// The cards whose flavor text contains `text`, ignoring case, like `ft:`.
+pub fn with_flavor_text<'a, I>(cards: I, text: &str) -> Vec<&'a Card>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    let text = text.to_lowercase();
+    cards
+        .into_iter()
+        .filter(|card| {
+            flavor_texts(card)
+                .iter()
+                .any(|flavor| flavor.to_lowercase().contains(&text))
+        })
+        .collect()
+}
+
+/// The cards with an artist credit containing `artist`, ignoring case, like `a:`. Collaborations such as
+/// `"Alayna Danner & Titus Lunter"` match either name.
+pub fn by_artist<'a, I>(cards: I, artist: &str) -> Vec<&'a Card>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    let artist = artist.to_lowercase();
+    cards
+        .into_iter()
+        .filter(|card| {
+            artists(card)
+                .iter()
+                .any(|credit| credit.to_lowercase().contains(&artist))
+        })
+        .collect()
+}
+
+/// Every distinct flavor text printed on the card with `oracle_id`, in the order the printings appear in `cards`. Pass
+/// every printing, e.g. from the default cards bulk file, to see how a card's flavor changed across reprints.
+pub fn flavor_history<'a, I>(cards: I, oracle_id: &str) -> Vec<&'a str>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    let mut texts: Vec<&str> = Vec::new();
+    for card in cards
+        .into_iter()
+        .filter(|card| card.oracle_identity() == Some(oracle_id))
+    {
+        for text in flavor_texts(card) {
+            if !texts.contains(&text) {
+                texts.push(text);
+            }
+        }
+    }
+    texts
+}
+
+/// One set's Story Spotlight cards: a chapter of the story so far.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoryChapter<'a> {
+    /// The set's code.
+    pub set: &'a str,
+
+    /// The set's name.
+    pub set_name: &'a str,
+
+    /// When the set was released.
+    pub released_at: Date,
+
+    /// The set's Story Spotlight cards, in collector number order.
+    pub cards: Vec<&'a Card>,
+}
+
+/// A block's chapters, or a single set's for sets outside any block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoryArc<'a> {
+    /// The block's name, or the set's name for sets outside any block.
+    pub name: &'a str,
+
+    /// The chapters, in release order.
+    pub chapters: Vec<StoryChapter<'a>>,
+}
+
+/// The Story Spotlight cards in `cards`, in release order and then collector number order.
+pub fn story_spotlights<'a, I>(cards: I) -> Vec<&'a Card>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    story_so_far(cards)
+        .into_iter()
+        .flat_map(|chapter| chapter.cards)
+        .collect()
+}
+
+/// The Story Spotlight cards in `cards` grouped by set, in release order. Sets released the same day are ordered by
+/// code. Pass one printing per card, e.g. from the default cards bulk file, to avoid repeats.
+pub fn story_so_far<'a, I>(cards: I) -> Vec<StoryChapter<'a>>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    let mut chapters: BTreeMap<(Date, &str), StoryChapter> = BTreeMap::new();
+    for card in cards.into_iter().filter(|card| card.story_spotlight) {
+        chapters
+            .entry((card.released_at, card.set.as_str()))
+            .or_insert_with(|| StoryChapter {
+                set: &card.set,
+                set_name: &card.set_name,
+                released_at: card.released_at,
+                cards: Vec::new(),
+            })
+            .cards
+            .push(card);
+    }
+
+    let mut chapters: Vec<StoryChapter> = chapters.into_values().collect();
+    for chapter in &mut chapters {
+        chapter.cards.sort_by(|a, b| {
+            collector_key(&a.collector_number).cmp(&collector_key(&b.collector_number))
+        });
+    }
+    chapters
+}
+
+/// The story so far grouped into arcs by the block each set belongs to in `sets`, ordered by each arc's first
+/// release. Sets missing from `sets` or outside any block form an arc of their own.
+pub fn story_arcs<'a, I>(cards: I, sets: &'a [Set]) -> Vec<StoryArc<'a>>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    let mut arcs: Vec<StoryArc> = Vec::new();
+    for chapter in story_so_far(cards) {
+        let name = sets
+            .iter()
+            .find(|set| set.code == chapter.set)
+            .and_then(|set| set.block.as_deref())
+            .unwrap_or(chapter.set_name);
+        match arcs.iter_mut().find(|arc| arc.name == name) {
+            Some(arc) => arc.chapters.push(chapter),
+            None => arcs.push(StoryArc {
+                name,
+                chapters: vec![chapter],
+            }),
+        }
+    }
+    arcs
+}
+
+/// A key ordering collector numbers numerically, so `"9"` comes before `"10"`, then by any suffix like `"a"` or `"★"`.
+fn collector_key(number: &str) -> (u32, &str) {
+    let digits = number.len()
+        - number
+            .trim_start_matches(|c: char| c.is_ascii_digit())
+            .len();
+    (
+        number[..digits].parse().unwrap_or(u32::MAX),
+        &number[digits..],
+    )
+}
+
+mod tests {
+    #[cfg(test)]
+    fn printing(flavor_text: Option<&str>, artist: &str) -> crate::types::card::Card {
+        use crate::testing;
+        use serde_json::json;
+
+        testing::card_with(json!({ "flavor_text": flavor_text, "artist": artist }))
+    }
+
+    #[test]
+    fn test_filters() {
+        use super::{by_artist, with_flavor_text};
+
+        let cards = vec![
+            printing(
+                Some("The sparkmage shrieked, calling on the rage of the storms."),
+                "Christopher Moeller",
+            ),
+            printing(
+                Some("Spark, flame, and fury."),
+                "Alayna Danner & Titus Lunter",
+            ),
+            printing(None, "Christopher Rush"),
+        ];
+
+        assert_eq!(1, with_flavor_text(&cards, "SPARKMAGE").len());
+        assert_eq!(2, with_flavor_text(&cards, "spark").len());
+        assert_eq!(2, by_artist(&cards, "christopher").len());
+        assert_eq!(1, by_artist(&cards, "Titus Lunter").len());
+    }
+
+    #[test]
+    fn test_flavor_history() {
+        use super::flavor_history;
+        use crate::testing;
+
+        let bolt = testing::card();
+        let cards = vec![
+            printing(Some("First."), "A"),
+            printing(None, "B"),
+            printing(Some("Second."), "C"),
+            printing(Some("First."), "D"),
+            testing::card_with(serde_json::json!({
+                "oracle_id": "00000000-0000-0000-0000-000000000000",
+                "flavor_text": "Another card.",
+            })),
+        ];
+
+        assert_eq!(
+            vec!["First.", "Second."],
+            flavor_history(&cards, bolt.oracle_identity().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_multi_faced_credits() {
+        use super::{artists, flavor_texts};
+        use crate::testing;
+        use serde_json::json;
+
+        let card = testing::card_with(json!({
+            "name": "Fire // Ice",
+            "flavor_text": null,
+            "artist": null,
+            "card_faces": [
+                {"name": "Fire", "mana_cost": "{1}{R}", "type_line": "Instant", "artist": "Franz Vohwinkel"},
+                {
+                    "name": "Ice",
+                    "mana_cost": "{1}{U}",
+                    "type_line": "Instant",
+                    "artist": "Franz Vohwinkel",
+                    "flavor_text": "Cold as the void.",
+                },
+            ],
+        }));
+
+        assert_eq!(vec!["Cold as the void."], flavor_texts(&card));
+        assert_eq!(vec!["Franz Vohwinkel"], artists(&card));
+    }
+
+    #[cfg(test)]
+    fn spotlight(set: &str, released_at: &str, number: &str) -> crate::types::card::Card {
+        use crate::testing;
+        use serde_json::json;
+
+        testing::card_with(json!({
+            "set": set,
+            "set_name": set.to_uppercase(),
+            "released_at": released_at,
+            "collector_number": number,
+            "story_spotlight": true,
+        }))
+    }
+
+    #[test]
+    fn test_story_so_far() {
+        use super::{story_so_far, story_spotlights};
+        use crate::testing;
+
+        let cards = vec![
+            spotlight("hou", "2017-07-14", "10"),
+            spotlight("akh", "2017-04-28", "25"),
+            spotlight("hou", "2017-07-14", "9"),
+            testing::card(),
+        ];
+
+        let chapters = story_so_far(&cards);
+        assert_eq!(
+            vec!["akh", "hou"],
+            chapters
+                .iter()
+                .map(|chapter| chapter.set)
+                .collect::<Vec<_>>()
+        );
+        let numbers: Vec<&str> = chapters[1]
+            .cards
+            .iter()
+            .map(|card| card.collector_number.as_str())
+            .collect();
+        assert_eq!(vec!["9", "10"], numbers);
+        assert_eq!(3, story_spotlights(&cards).len());
+    }
+
+    #[test]
+    fn test_story_arcs() {
+        use super::story_arcs;
+        use crate::testing;
+        use serde_json::json;
+
+        let cards = vec![
+            spotlight("akh", "2017-04-28", "25"),
+            spotlight("hou", "2017-07-14", "9"),
+            spotlight("blb", "2024-08-02", "1"),
+        ];
+        let sets = vec![
+            testing::set_with(json!({"code": "akh", "block": "Amonkhet", "block_code": "akh"})),
+            testing::set_with(json!({"code": "hou", "block": "Amonkhet", "block_code": "akh"})),
+        ];
+
+        let arcs = story_arcs(&cards, &sets);
+        assert_eq!(2, arcs.len());
+        assert_eq!("Amonkhet", arcs[0].name);
+        assert_eq!(2, arcs[0].chapters.len());
+        assert_eq!("BLB", arcs[1].name);
+    }
+}
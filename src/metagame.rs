@@ -0,0 +1,268 @@
+//! Metagame analysis over many resolved decks: which cards are played together, and which decks look alike.
+//!
+//! Cards are compared by Oracle ID, so different printings of a card count as the same card. Only main decks are
+//! considered, since sideboards say more about the expected field than about what a deck is.
+
+use crate::deck::resolve::ResolvedDeck;
+use crate::types::card::Card;
+use std::collections::{BTreeSet, HashMap};
+
+/// The distinct Oracle IDs in `deck`'s main deck.
+pub fn oracle_ids(deck: &ResolvedDeck) -> BTreeSet<&str> {
+    deck.main
+        .iter()
+        .map(|entry| oracle_key(&entry.card))
+        .collect()
+}
+
+/// The key a card is compared by: its Oracle ID, or its own ID for the rare card without one.
+pub(crate) fn oracle_key(card: &Card) -> &str {
+    card.oracle_identity().unwrap_or(&card.id)
+}
+
+/// The Jaccard similarity of two sets of cards: how many they share over how many they have between them, from 0 for
+/// nothing in common to 1 for identical. Two empty sets are considered identical.
+pub fn jaccard(a: &BTreeSet<&str>, b: &BTreeSet<&str>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// How often cards appear in the same deck.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cooccurrence {
+    /// Every card seen, by Oracle ID, in order of first appearance. Other fields index into this.
+    pub cards: Vec<String>,
+
+    /// The name of each card in `cards`.
+    pub names: Vec<String>,
+
+    /// How many decks each card in `cards` appears in.
+    pub decks: Vec<u32>,
+
+    /// How many decks each pair of cards appears in together, keyed by their indices with the smaller first. Pairs
+    /// that never appear together are absent.
+    pub pairs: HashMap<(usize, usize), u32>,
+}
+
+impl Cooccurrence {
+    /// Counts co-occurrences across `decks`.
+    pub fn new(decks: &[ResolvedDeck]) -> Self {
+        let mut matrix = Cooccurrence::default();
+        let mut indices: HashMap<String, usize> = HashMap::new();
+
+        for deck in decks {
+            let mut present: Vec<usize> = Vec::new();
+            for entry in &deck.main {
+                let key = oracle_key(&entry.card);
+                let index = *indices.entry(key.to_string()).or_insert_with(|| {
+                    matrix.cards.push(key.to_string());
+                    matrix.names.push(entry.card.name.clone());
+                    matrix.decks.push(0);
+                    matrix.cards.len() - 1
+                });
+                present.push(index);
+            }
+            present.sort_unstable();
+            present.dedup();
+
+            for (i, &a) in present.iter().enumerate() {
+                matrix.decks[a] += 1;
+                for &b in &present[i + 1..] {
+                    *matrix.pairs.entry((a, b)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        matrix
+    }
+
+    /// The index of the card with `oracle_id`, if it was seen.
+    pub fn index_of(&self, oracle_id: &str) -> Option<usize> {
+        self.cards.iter().position(|card| card == oracle_id)
+    }
+
+    /// How many decks cards `a` and `b` (by index) appear in together.
+    pub fn together(&self, a: usize, b: usize) -> u32 {
+        let key = if a < b { (a, b) } else { (b, a) };
+        self.pairs.get(&key).copied().unwrap_or(0)
+    }
+
+    /// The cards most often played alongside card `index`, with the share of its decks they appear in, most common
+    /// first.
+    pub fn companions(&self, index: usize) -> Vec<(usize, f64)> {
+        let total = f64::from(self.decks[index].max(1));
+        let mut companions: Vec<(usize, f64)> = (0..self.cards.len())
+            .filter(|&other| other != index)
+            .map(|other| (other, f64::from(self.together(index, other)) / total))
+            .filter(|&(_, share)| share > 0.0)
+            .collect();
+        companions.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        companions
+    }
+}
+
+/// A group of similar decks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cluster {
+    /// The decks in this cluster, by index into the decks that were clustered.
+    pub decks: Vec<usize>,
+
+    /// The Oracle IDs played in at least half of the cluster's decks, most common first. These are what the decks
+    /// have in common, and usually what names the archetype.
+    pub core: Vec<String>,
+}
+
+/// Groups `decks` so that any two decks whose main decks have a Jaccard similarity of at least `threshold` end up in
+/// the same cluster (single-linkage clustering). Clusters are ordered largest first, then by their first deck.
+pub fn cluster(decks: &[ResolvedDeck], threshold: f64) -> Vec<Cluster> {
+    let ids: Vec<BTreeSet<&str>> = decks.iter().map(oracle_ids).collect();
+
+    let mut parents: Vec<usize> = (0..decks.len()).collect();
+    for a in 0..decks.len() {
+        for b in a + 1..decks.len() {
+            if jaccard(&ids[a], &ids[b]) >= threshold {
+                let (root_a, root_b) = (find(&mut parents, a), find(&mut parents, b));
+                parents[root_a.max(root_b)] = root_a.min(root_b);
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for deck in 0..decks.len() {
+        let root = find(&mut parents, deck);
+        groups.entry(root).or_default().push(deck);
+    }
+
+    let mut clusters: Vec<Cluster> = groups
+        .into_values()
+        .map(|members| {
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for &deck in &members {
+                for id in &ids[deck] {
+                    *counts.entry(id).or_insert(0) += 1;
+                }
+            }
+
+            let mut core: Vec<(&str, usize)> = counts
+                .into_iter()
+                .filter(|&(_, count)| count * 2 >= members.len())
+                .collect();
+            core.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+            Cluster {
+                decks: members,
+                core: core.into_iter().map(|(id, _)| id.to_string()).collect(),
+            }
+        })
+        .collect();
+
+    clusters.sort_by(|a, b| {
+        b.decks
+            .len()
+            .cmp(&a.decks.len())
+            .then(a.decks[0].cmp(&b.decks[0]))
+    });
+    clusters
+}
+
+fn find(parents: &mut [usize], mut node: usize) -> usize {
+    while parents[node] != node {
+        parents[node] = parents[parents[node]];
+        node = parents[node];
+    }
+    node
+}
+
+mod tests {
+    #[cfg(test)]
+    fn deck(cards: &[&str]) -> crate::deck::resolve::ResolvedDeck {
+        use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+        use crate::testing;
+        use serde_json::json;
+
+        ResolvedDeck {
+            main: cards
+                .iter()
+                .map(|name| ResolvedEntry {
+                    quantity: 4,
+                    card: testing::card_with(
+                        json!({ "name": name, "oracle_id": format!("id-{}", name) }),
+                    ),
+                })
+                .collect(),
+            ..ResolvedDeck::default()
+        }
+    }
+
+    #[test]
+    fn test_cooccurrence() {
+        use super::Cooccurrence;
+
+        let decks = vec![deck(&["A", "B", "C"]), deck(&["A", "B"]), deck(&["A", "D"])];
+        let matrix = Cooccurrence::new(&decks);
+
+        let a = matrix.index_of("id-A").unwrap();
+        let b = matrix.index_of("id-B").unwrap();
+        assert_eq!(3, matrix.decks[a]);
+        assert_eq!(2, matrix.together(b, a));
+        assert_eq!("B", matrix.names[matrix.companions(a)[0].0]);
+        assert!((matrix.companions(a)[0].1 - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cluster() {
+        use super::{cluster, jaccard, oracle_ids};
+
+        let decks = vec![
+            deck(&["Bolt", "Goblin", "Mountain"]),
+            deck(&["Counterspell", "Island", "Brainstorm"]),
+            deck(&["Bolt", "Goblin", "Mountain", "Shock"]),
+            deck(&["Counterspell", "Island", "Ponder"]),
+            deck(&["Forest"]),
+        ];
+        assert!((jaccard(&oracle_ids(&decks[0]), &oracle_ids(&decks[2])) - 0.75).abs() < 1e-9);
+
+        let clusters = cluster(&decks, 0.5);
+        let members: Vec<Vec<usize>> = clusters
+            .iter()
+            .map(|cluster| cluster.decks.clone())
+            .collect();
+        assert_eq!(vec![vec![0, 2], vec![1, 3], vec![4]], members);
+        assert_eq!(
+            vec!["id-Bolt", "id-Goblin", "id-Mountain", "id-Shock"],
+            clusters[0].core
+        );
+    }
+
+    #[test]
+    fn test_reversible_cards() {
+        use super::Cooccurrence;
+        use crate::deck::resolve::{ResolvedDeck, ResolvedEntry};
+        use crate::testing;
+
+        let entry = |card| ResolvedEntry { quantity: 1, card };
+        let decks = vec![ResolvedDeck {
+            main: vec![
+                entry(testing::reversible_card()),
+                entry(testing::reversible_card_of(
+                    "ffffffff-0000-0000-0000-000000000000",
+                )),
+            ],
+            ..ResolvedDeck::default()
+        }];
+
+        let matrix = Cooccurrence::new(&decks);
+        assert_eq!(2, matrix.cards.len());
+        assert!(matrix
+            .index_of("3ac8bdcc-3712-573d-837a-894f9a029618")
+            .is_some());
+    }
+}
@@ -0,0 +1,138 @@
+//! Prefix completion of card names.
+
+use super::normalize;
+use crate::types::card::Card;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+/// How many suggestions Scryfall's own autocomplete endpoint returns.
+pub const DEFAULT_LIMIT: usize = 20;
+
+/// A prefix index over card names, matching at the start of the name or at the start of any word in it, so `"bolt"`
+/// completes to `"Lightning Bolt"`. Lookups are a binary search over a sorted table, with no allocation beyond the
+/// results.
+#[derive(Clone, Debug, Default)]
+pub struct Autocomplete {
+    names: Vec<String>,
+
+    /// Every suffix of every normalized name that starts at a word boundary, along with the index of the name and
+    /// which word the suffix starts at. Sorted by suffix.
+    suffixes: Vec<(String, usize, usize)>,
+}
+
+impl Autocomplete {
+    /// Builds an index over `names`. Duplicate names are only indexed once.
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut seen = HashSet::new();
+        let names: Vec<String> = names
+            .into_iter()
+            .map(Into::into)
+            .filter(|name| seen.insert(name.clone()))
+            .collect();
+
+        let mut suffixes = Vec::new();
+        for (index, name) in names.iter().enumerate() {
+            let folded = normalize(name);
+            let starts = std::iter::once(0).chain(folded.match_indices(' ').map(|(i, _)| i + 1));
+            for (word, start) in starts.enumerate() {
+                suffixes.push((folded[start..].to_string(), index, word));
+            }
+        }
+        suffixes.sort();
+
+        Autocomplete { names, suffixes }
+    }
+
+    /// Builds an index over the names of `cards`.
+    pub fn from_cards(cards: &[Card]) -> Self {
+        Autocomplete::new(cards.iter().map(|card| card.name.clone()))
+    }
+
+    /// Up to `limit` names completing `query`. Names that start with the query come first, then names with a later
+    /// word that does; within each group shorter names rank higher, then alphabetical order.
+    pub fn suggest(&self, query: &str, limit: usize) -> Vec<&str> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let start = self
+            .suffixes
+            .partition_point(|(suffix, _, _)| suffix.as_str() < query.as_str());
+        let mut matches: Vec<(usize, usize)> = self.suffixes[start..]
+            .iter()
+            .take_while(|(suffix, _, _)| suffix.starts_with(&query))
+            .map(|&(_, index, word)| (index, word))
+            .collect();
+
+        // Keep only the earliest matching word of each name.
+        matches.sort_unstable();
+        matches.dedup_by_key(|(index, _)| *index);
+
+        let mut ranked: Vec<(bool, &str)> = matches
+            .into_iter()
+            .map(|(index, word)| (word > 0, self.names[index].as_str()))
+            .collect();
+        ranked.sort_by(|a, b| (a.0, a.1.len(), a.1).cmp(&(b.0, b.1.len(), b.1)));
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(_, name)| name)
+            .collect()
+    }
+
+    /// How many distinct names are indexed.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether no names are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Autocomplete {
+    fn from_iter<I: IntoIterator<Item = S>>(names: I) -> Self {
+        Autocomplete::new(names)
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_suggest() {
+        use super::{Autocomplete, DEFAULT_LIMIT};
+
+        let index: Autocomplete = vec![
+            "Lightning Bolt",
+            "Lightning Helix",
+            "Chain Lightning",
+            "Boltwave",
+            "Lightning Bolt",
+            "Æther Vial",
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(5, index.len());
+
+        assert_eq!(
+            vec!["Lightning Bolt", "Lightning Helix", "Chain Lightning"],
+            index.suggest("light", DEFAULT_LIMIT)
+        );
+        assert_eq!(
+            vec!["Boltwave", "Lightning Bolt"],
+            index.suggest("BOLT", DEFAULT_LIMIT)
+        );
+        assert_eq!(
+            vec!["Lightning Bolt"],
+            index.suggest("lightning b", DEFAULT_LIMIT)
+        );
+        assert_eq!(vec!["Æther Vial"], index.suggest("aeth", DEFAULT_LIMIT));
+        assert_eq!(vec!["Lightning Bolt"], index.suggest("light", 1));
+        assert!(index.suggest("  ", DEFAULT_LIMIT).is_empty());
+    }
+}
@@ -0,0 +1,322 @@
+//! Typo-tolerant lookup of card names.
+
+use super::normalize;
+use crate::types::card::Card;
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use std::iter::FromIterator;
+
+/// The edit distance lookups tolerate by default, which covers most single typos and transpositions.
+pub const DEFAULT_MAX_DISTANCE: usize = 2;
+
+/// A name close to a lookup query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate<'a> {
+    /// The card name, as it was indexed.
+    pub name: &'a str,
+
+    /// The Levenshtein distance between the normalized query and the normalized name.
+    pub distance: usize,
+
+    /// How similar the two are, from 0 to 1, where 1 means they normalize to the same string.
+    pub score: f64,
+}
+
+/// The error returned when a name doesn't match any card exactly.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotFound {
+    /// The name that was looked up.
+    pub query: String,
+
+    /// The closest card names, best first. May be empty.
+    pub suggestions: Vec<String>,
+}
+
+impl fmt::Display for NotFound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no card named \"{}\"", self.query)?;
+        match self.suggestions.as_slice() {
+            [] => Ok(()),
+            [only] => write!(f, "; did you mean {}?", only),
+            [rest @ .., last] => write!(f, "; did you mean {} or {}?", rest.join(", "), last),
+        }
+    }
+}
+
+impl error::Error for NotFound {}
+
+#[derive(Clone, Debug)]
+struct Node {
+    key: String,
+    names: Vec<usize>,
+    children: HashMap<usize, usize>,
+}
+
+/// A BK-tree over normalized card names. Lookups only visit the parts of the tree that could be within the requested
+/// distance, so an edit-distance-2 query over every card name touches a small fraction of them.
+#[derive(Clone, Debug, Default)]
+pub struct FuzzyIndex {
+    names: Vec<String>,
+    nodes: Vec<Node>,
+}
+
+impl FuzzyIndex {
+    /// Builds an index over `names`. Names that normalize to the same string share a node.
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut index = FuzzyIndex::default();
+        for name in names {
+            index.insert(name.into());
+        }
+        index
+    }
+
+    /// Builds an index over the names of `cards`.
+    pub fn from_cards(cards: &[Card]) -> Self {
+        FuzzyIndex::new(cards.iter().map(|card| card.name.clone()))
+    }
+
+    /// Adds `name` to the index, unless it's already there.
+    pub fn insert(&mut self, name: String) {
+        let key = normalize(&name);
+        if self.nodes.is_empty() {
+            self.names.push(name);
+            self.nodes.push(Node {
+                key,
+                names: vec![0],
+                children: HashMap::new(),
+            });
+            return;
+        }
+
+        let mut current = 0;
+        loop {
+            let distance = levenshtein(&key, &self.nodes[current].key);
+            if distance == 0 {
+                if !self.nodes[current]
+                    .names
+                    .iter()
+                    .any(|&i| self.names[i] == name)
+                {
+                    self.nodes[current].names.push(self.names.len());
+                    self.names.push(name);
+                }
+                return;
+            }
+
+            match self.nodes[current].children.get(&distance) {
+                Some(&child) => current = child,
+                None => {
+                    let node = self.nodes.len();
+                    self.nodes[current].children.insert(distance, node);
+                    self.nodes.push(Node {
+                        key,
+                        names: vec![self.names.len()],
+                        children: HashMap::new(),
+                    });
+                    self.names.push(name);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Every indexed name within `max_distance` edits of `query`, closest first, then by score and name.
+    pub fn lookup(&self, query: &str, max_distance: usize) -> Vec<Candidate<'_>> {
+        let query = normalize(query);
+        let query_length = query.chars().count();
+        let mut candidates = Vec::new();
+        let mut pending = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+
+        while let Some(current) = pending.pop() {
+            let node = &self.nodes[current];
+            let distance = levenshtein(&query, &node.key);
+            if distance <= max_distance {
+                let longest = query_length.max(node.key.chars().count()).max(1);
+                let score = 1.0 - distance as f64 / longest as f64;
+                candidates.extend(node.names.iter().map(|&i| Candidate {
+                    name: &self.names[i],
+                    distance,
+                    score,
+                }));
+            }
+
+            let low = distance.saturating_sub(max_distance);
+            let high = distance + max_distance;
+            pending.extend(
+                node.children
+                    .iter()
+                    .filter(|(d, _)| (low..=high).contains(*d))
+                    .map(|(_, &child)| child),
+            );
+        }
+
+        candidates.sort_by(|a, b| {
+            a.distance
+                .cmp(&b.distance)
+                .then(
+                    b.score
+                        .partial_cmp(&a.score)
+                        .unwrap_or(std::cmp::Ordering::Equal),
+                )
+                .then(a.name.cmp(b.name))
+        });
+        candidates
+    }
+
+    /// The single name `query` most likely means: an exact match after normalization, or otherwise the only closest
+    /// name within the default distance. Returns `None` when nothing is close or the closest names tie.
+    pub fn best_match(&self, query: &str) -> Option<&str> {
+        let candidates = self.lookup(query, DEFAULT_MAX_DISTANCE);
+        match candidates.as_slice() {
+            [only] => Some(only.name),
+            [first, second, ..] if first.distance < second.distance => Some(first.name),
+            _ => None,
+        }
+    }
+
+    /// Resolves `query` to an indexed name, requiring an exact match after normalization. On failure the error carries
+    /// up to `suggestions` of the closest names, so callers can ask "did you mean ...?".
+    pub fn resolve(&self, query: &str, suggestions: usize) -> Result<&str, NotFound> {
+        // Allow roughly one typo per three characters when looking for suggestions, but always at least the default.
+        let max_distance = (normalize(query).chars().count() / 3).max(DEFAULT_MAX_DISTANCE);
+        let candidates = self.lookup(query, max_distance);
+
+        match candidates.first() {
+            Some(candidate) if candidate.distance == 0 => Ok(candidate.name),
+            _ => Err(NotFound {
+                query: query.to_string(),
+                suggestions: candidates
+                    .into_iter()
+                    .take(suggestions)
+                    .map(|candidate| candidate.name.to_string())
+                    .collect(),
+            }),
+        }
+    }
+
+    /// How many distinct names are indexed.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether no names are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for FuzzyIndex {
+    fn from_iter<I: IntoIterator<Item = S>>(names: I) -> Self {
+        FuzzyIndex::new(names)
+    }
+}
+
+/// The Levenshtein distance between `a` and `b`, counted in characters.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+mod tests {
+    #[test]
+    fn test_levenshtein() {
+        use super::levenshtein;
+
+        assert_eq!(0, levenshtein("bolt", "bolt"));
+        assert_eq!(3, levenshtein("kitten", "sitting"));
+        assert_eq!(4, levenshtein("", "bolt"));
+    }
+
+    #[test]
+    fn test_lookup() {
+        use super::{FuzzyIndex, DEFAULT_MAX_DISTANCE};
+
+        let index: FuzzyIndex = vec![
+            "Lightning Bolt",
+            "Lightning Bolt",
+            "Lightning Helix",
+            "Counterspell",
+            "Æther Vial",
+            "Ather Vial",
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(5, index.len());
+
+        let candidates = index.lookup("ligthning bolt", DEFAULT_MAX_DISTANCE);
+        assert_eq!(1, candidates.len());
+        assert_eq!("Lightning Bolt", candidates[0].name);
+        assert_eq!(2, candidates[0].distance);
+
+        assert_eq!(Some("Counterspell"), index.best_match("counterspel"));
+        assert_eq!(Some("Æther Vial"), index.best_match("AEther Vial"));
+        assert_eq!(Some("Ather Vial"), index.best_match("athr vial"));
+        assert_eq!(None, index.best_match("aather vial"));
+        assert_eq!(None, index.best_match("Black Lotus"));
+    }
+
+    #[test]
+    fn test_resolve() {
+        use super::FuzzyIndex;
+
+        let index: FuzzyIndex = vec![
+            "Lightning Bolt",
+            "Lightning Helix",
+            "Lightning Axe",
+            "Counterspell",
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(Ok("Lightning Bolt"), index.resolve("lightning bolt", 3));
+
+        let err = index.resolve("Lightning Blot", 2).unwrap_err();
+        assert_eq!(vec!["Lightning Bolt", "Lightning Helix"], err.suggestions);
+        assert_eq!(
+            "no card named \"Lightning Blot\"; did you mean Lightning Bolt or Lightning Helix?",
+            err.to_string()
+        );
+
+        let err = index.resolve("Black Lotus", 3).unwrap_err();
+        assert!(err.suggestions.is_empty());
+        assert_eq!("no card named \"Black Lotus\"", err.to_string());
+    }
+
+    #[test]
+    fn test_non_latin_names() {
+        use super::FuzzyIndex;
+
+        let index: FuzzyIndex = vec!["あいうえおかきくこ", "あいうえおさしすせ"]
+            .into_iter()
+            .collect();
+
+        // Nine characters allow three edits, however many bytes they take.
+        let err = index.resolve("あいうえおかきくけ", 5).unwrap_err();
+        assert_eq!(vec!["あいうえおかきくこ"], err.suggestions);
+
+        let candidates = index.lookup("あいうえおかきくけ", 1);
+        assert_eq!(1, candidates.len());
+        assert!((candidates[0].score - 8.0 / 9.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,69 @@
+//! Offline lookups over card names, for when a round trip to Scryfall is too slow or not possible.
+
+pub mod autocomplete;
+pub mod fuzzy;
+pub mod references;
+
+pub use autocomplete::Autocomplete;
+pub use fuzzy::{FuzzyIndex, NotFound};
+pub use references::{Reference, ReferenceExtractor};
+
+/// Folds a card name into the key every local name lookup in the crate compares, from the name indexes here to deck
+/// resolution, so a name matches the same cards everywhere: lowercase letters and digits separated by single spaces.
+/// Accents are dropped from Latin letters, `Æ` becomes `ae`, hyphens and slashes separate words, and other punctuation
+/// is removed, so `"Æther Vial"`, `"aether vial"` and `"Aether-Vial"` all fold to `"aether vial"`. Letters and digits
+/// of other scripts are kept as they are, so printed names in languages such as Japanese or Russian still fold to
+/// something.
+pub fn normalize(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    let mut space = false;
+
+    for c in name.chars().flat_map(char::to_lowercase) {
+        let mut buffer = [0; 4];
+        let replacement: &str = match c {
+            'a'..='z' | '0'..='9' => c.encode_utf8(&mut buffer),
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => "a",
+            'æ' => "ae",
+            'ç' => "c",
+            'è' | 'é' | 'ê' | 'ë' => "e",
+            'ì' | 'í' | 'î' | 'ï' => "i",
+            'ñ' => "n",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => "o",
+            'ù' | 'ú' | 'û' | 'ü' => "u",
+            'ý' | 'ÿ' => "y",
+            c if c.is_whitespace() || c == '-' || c == '/' => {
+                space = true;
+                continue;
+            }
+            c if c.is_alphanumeric() => c.encode_utf8(&mut buffer),
+            _ => continue,
+        };
+
+        if space && !folded.is_empty() {
+            folded.push(' ');
+        }
+        space = false;
+        folded.push_str(replacement);
+    }
+
+    folded
+}
+
+mod tests {
+    #[test]
+    fn test_normalize() {
+        use super::normalize;
+
+        assert_eq!("aether vial", normalize("Æther Vial"));
+        assert_eq!("aether vial", normalize("  Aether-Vial "));
+        assert_eq!(
+            "lim dul the necromancer",
+            normalize("Lim-Dûl the Necromancer")
+        );
+        assert_eq!("urzas saga", normalize("Urza's Saga"));
+        assert_eq!("fire ice", normalize("Fire // Ice"));
+        assert_eq!("稲妻", normalize("稲妻"));
+        assert_eq!("молния", normalize("Молния!"));
+        assert_eq!("ðrjotr", normalize("Ðrjótr"));
+    }
+}
@@ -0,0 +1,211 @@
+//! Finding card references like `[[Lightning Bolt]]` in free text, as chat bots do.
+
+use super::normalize;
+use crate::types::card::Card;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// A card name referenced in a piece of text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reference<'a> {
+    /// The byte range of the whole reference in the text, delimiters included.
+    pub span: Range<usize>,
+
+    /// The referenced name, trimmed of surrounding whitespace.
+    pub name: &'a str,
+
+    /// Which of the extractor's delimiter pairs matched, by index.
+    pub delimiters: usize,
+}
+
+/// A reference along with the card it resolved to, if any.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedReference<'a> {
+    /// Where the reference appeared.
+    pub reference: Reference<'a>,
+
+    /// The card it named, or `None` if the lookup didn't find one.
+    pub card: Option<Card>,
+}
+
+/// Scans text for card names wrapped in delimiter pairs. By default `[[Card Name]]` and `{{Card Name}}` are
+/// recognized; bots commonly use the second for a different kind of reply, such as showing prices.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferenceExtractor {
+    delimiters: Vec<(String, String)>,
+}
+
+impl Default for ReferenceExtractor {
+    fn default() -> Self {
+        ReferenceExtractor {
+            delimiters: vec![
+                ("[[".to_string(), "]]".to_string()),
+                ("{{".to_string(), "}}".to_string()),
+            ],
+        }
+    }
+}
+
+impl ReferenceExtractor {
+    /// An extractor recognizing `[[...]]` and `{{...}}`.
+    pub fn new() -> Self {
+        ReferenceExtractor::default()
+    }
+
+    /// An extractor recognizing only the given delimiter pairs, in priority order when two open at the same place.
+    pub fn with_delimiters<I, S>(delimiters: I) -> Self
+    where
+        I: IntoIterator<Item = (S, S)>,
+        S: Into<String>,
+    {
+        ReferenceExtractor {
+            delimiters: delimiters
+                .into_iter()
+                .map(|(open, close)| (open.into(), close.into()))
+                .filter(|(open, close)| !open.is_empty() && !close.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Every reference in `text`, in order. References can't span lines, and empty ones are skipped.
+    pub fn extract<'a>(&self, text: &'a str) -> Vec<Reference<'a>> {
+        let mut references = Vec::new();
+        let mut position = 0;
+
+        loop {
+            let next = self
+                .delimiters
+                .iter()
+                .enumerate()
+                .filter_map(|(index, (open, _))| {
+                    text[position..]
+                        .find(open.as_str())
+                        .map(|at| (position + at, index))
+                })
+                .min();
+
+            let (start, index) = match next {
+                Some(next) => next,
+                None => return references,
+            };
+
+            let (open, close) = &self.delimiters[index];
+            let inner_start = start + open.len();
+            let line_end = text[inner_start..]
+                .find('\n')
+                .map_or(text.len(), |at| inner_start + at);
+
+            match text[inner_start..line_end].find(close.as_str()) {
+                Some(at) => {
+                    let end = inner_start + at + close.len();
+                    let name = text[inner_start..inner_start + at].trim();
+                    if !name.is_empty() {
+                        references.push(Reference {
+                            span: start..end,
+                            name,
+                            delimiters: index,
+                        });
+                    }
+                    position = end;
+                }
+                None => position = inner_start,
+            }
+        }
+    }
+
+    /// Extracts the references in `text` and resolves them with a single call to `lookup`, which receives each
+    /// distinct name once (compared after normalization) and returns a card or `None` for each, in the same order.
+    /// This fits Scryfall's collection endpoint, which resolves many names per request.
+    pub fn resolve<'a, F>(&self, text: &'a str, lookup: F) -> Vec<ResolvedReference<'a>>
+    where
+        F: FnOnce(&[&str]) -> Vec<Option<Card>>,
+    {
+        let references = self.extract(text);
+
+        let mut slots = HashMap::new();
+        let mut names = Vec::new();
+        let indices: Vec<usize> = references
+            .iter()
+            .map(|reference| {
+                *slots.entry(normalize(reference.name)).or_insert_with(|| {
+                    names.push(reference.name);
+                    names.len() - 1
+                })
+            })
+            .collect();
+
+        let cards = if names.is_empty() {
+            Vec::new()
+        } else {
+            lookup(&names)
+        };
+
+        references
+            .into_iter()
+            .zip(indices)
+            .map(|(reference, index)| ResolvedReference {
+                reference,
+                card: cards.get(index).cloned().flatten(),
+            })
+            .collect()
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_extract() {
+        use super::ReferenceExtractor;
+
+        let text =
+            "Is [[Lightning Bolt]] better than {{ Chain Lightning }}? [[]] [[broken\n]] [[Shock]]";
+        let references = ReferenceExtractor::new().extract(text);
+        let names: Vec<_> = references
+            .iter()
+            .map(|reference| (reference.name, reference.delimiters))
+            .collect();
+
+        assert_eq!(
+            vec![("Lightning Bolt", 0), ("Chain Lightning", 1), ("Shock", 0)],
+            names
+        );
+        assert_eq!("[[Lightning Bolt]]", &text[references[0].span.clone()]);
+    }
+
+    #[test]
+    fn test_custom_delimiters() {
+        use super::ReferenceExtractor;
+
+        let extractor = ReferenceExtractor::with_delimiters(vec![("<", ">")]);
+        let names: Vec<_> = extractor
+            .extract("<Shock> and [[Bolt]]")
+            .into_iter()
+            .map(|reference| reference.name)
+            .collect();
+
+        assert_eq!(vec!["Shock"], names);
+    }
+
+    #[test]
+    fn test_resolve() {
+        use super::ReferenceExtractor;
+        use crate::testing;
+
+        let mut calls = Vec::new();
+        let resolved = ReferenceExtractor::new().resolve(
+            "[[Lightning Bolt]] [[lightning bolt]] [[Nope]]",
+            |names| {
+                calls.extend(names.iter().map(|name| name.to_string()));
+                names
+                    .iter()
+                    .map(|name| Some(testing::card()).filter(|_| *name == "Lightning Bolt"))
+                    .collect()
+            },
+        );
+
+        assert_eq!(vec!["Lightning Bolt", "Nope"], calls);
+        assert_eq!(3, resolved.len());
+        assert!(resolved[0].card.is_some());
+        assert!(resolved[1].card.is_some());
+        assert!(resolved[2].card.is_none());
+    }
+}
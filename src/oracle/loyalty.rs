@@ -0,0 +1,178 @@
+//! Loyalty abilities of planeswalkers and the defense of battles, which renderers need as numbers rather than text.
+
+use crate::types::card::Card;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// The loyalty cost of a loyalty ability.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LoyaltyCost {
+    /// A fixed change to loyalty: `+1`, `0` or `-3`.
+    Fixed(i32),
+
+    /// `+X`.
+    PlusX,
+
+    /// `−X`.
+    MinusX,
+}
+
+/// One loyalty ability: the loyalty it costs and what it does.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoyaltyAbility {
+    pub cost: LoyaltyCost,
+    pub effect: String,
+}
+
+/// The loyalty abilities in oracle text, in order. Static and triggered abilities are skipped.
+pub fn parse_loyalty_abilities(text: &str) -> Vec<LoyaltyAbility> {
+    // Oracle text writes minus signs as U+2212, but hand-written text usually has a hyphen.
+    static ABILITY: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^\[?([+−-]?)(\d+|X)\]?: (.+)$").unwrap());
+    text.lines()
+        .filter_map(|line| {
+            let captures = ABILITY.captures(line.trim())?;
+            let negative = matches!(&captures[1], "−" | "-");
+            let cost = match &captures[2] {
+                "X" if negative => LoyaltyCost::MinusX,
+                "X" => LoyaltyCost::PlusX,
+                amount => {
+                    let amount: i32 = amount.parse().ok()?;
+                    LoyaltyCost::Fixed(if negative { -amount } else { amount })
+                }
+            };
+
+            Some(LoyaltyAbility {
+                cost,
+                effect: captures[3].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The loyalty abilities of `card`, or of the first of its faces that has any.
+pub fn loyalty_abilities(card: &Card) -> Vec<LoyaltyAbility> {
+    let texts = card
+        .card_faces
+        .iter()
+        .flatten()
+        .filter_map(|face| face.oracle_text.as_deref())
+        .chain(card.oracle_text.as_deref());
+
+    texts
+        .map(parse_loyalty_abilities)
+        .find(|abilities| !abilities.is_empty())
+        .unwrap_or_default()
+}
+
+/// The starting loyalty of `card` or its first face with one, if it's a number. `None` for cards without loyalty and
+/// for non-numeric loyalty like `X`.
+pub fn starting_loyalty(card: &Card) -> Option<u32> {
+    first_number(
+        card.loyalty.as_deref(),
+        card.card_faces
+            .iter()
+            .flatten()
+            .map(|face| face.loyalty.as_deref()),
+    )
+}
+
+/// The defense of `card` or its first face with one, if it's a battle with numeric defense.
+pub fn defense(card: &Card) -> Option<u32> {
+    first_number(
+        card.defense.as_deref(),
+        card.card_faces
+            .iter()
+            .flatten()
+            .map(|face| face.defense.as_deref()),
+    )
+}
+
+/// The first of `value` and `face_values` that's a number.
+fn first_number<'a, I: Iterator<Item = Option<&'a str>>>(
+    value: Option<&'a str>,
+    face_values: I,
+) -> Option<u32> {
+    std::iter::once(value)
+        .chain(face_values)
+        .flatten()
+        .find_map(|value| value.parse().ok())
+}
+
+mod tests {
+    #[test]
+    fn test_parse_loyalty_abilities() {
+        use super::{parse_loyalty_abilities, LoyaltyAbility, LoyaltyCost};
+
+        let abilities = parse_loyalty_abilities(
+            "+2: Each player discards a card.\n−X: Target creature gets -X/-X.\n0: Draw a card.\n−8: You get an emblem.\n[+1]: Scry 1.",
+        );
+        assert_eq!(
+            vec![
+                LoyaltyCost::Fixed(2),
+                LoyaltyCost::MinusX,
+                LoyaltyCost::Fixed(0),
+                LoyaltyCost::Fixed(-8),
+                LoyaltyCost::Fixed(1),
+            ],
+            abilities
+                .iter()
+                .map(|ability| ability.cost)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            LoyaltyAbility {
+                cost: LoyaltyCost::Fixed(0),
+                effect: "Draw a card.".to_string(),
+            },
+            abilities[2]
+        );
+        assert!(parse_loyalty_abilities("Flying\n{T}: Add {G}.").is_empty());
+    }
+
+    #[test]
+    fn test_planeswalker() {
+        use super::{loyalty_abilities, starting_loyalty, LoyaltyCost};
+        use crate::testing;
+
+        let card = testing::card_with(serde_json::json!({
+            "name": "Liliana of the Veil",
+            "type_line": "Legendary Planeswalker — Liliana",
+            "loyalty": "3",
+            "oracle_text": "+1: Each player discards a card.\n−2: Target player sacrifices a creature.\n−6: Separate all permanents target player controls into two piles.",
+        }));
+        let abilities = loyalty_abilities(&card);
+        assert_eq!(3, abilities.len());
+        assert_eq!(LoyaltyCost::Fixed(-6), abilities[2].cost);
+        assert_eq!(Some(3), starting_loyalty(&card));
+        assert!(loyalty_abilities(&testing::card()).is_empty());
+    }
+
+    #[test]
+    fn test_defense() {
+        use super::defense;
+        use crate::testing;
+
+        let card = testing::card_with(serde_json::json!({
+            "name": "Invasion of Zendikar // Awakened Skyclave",
+            "layout": "transform",
+            "card_faces": [
+                {
+                    "name": "Invasion of Zendikar",
+                    "mana_cost": "{3}{G}",
+                    "type_line": "Battle — Siege",
+                    "defense": "3",
+                },
+                {
+                    "name": "Awakened Skyclave",
+                    "mana_cost": "",
+                    "type_line": "Creature — Elemental",
+                    "power": "4",
+                    "toughness": "4",
+                },
+            ],
+        }));
+        assert_eq!(Some(3), defense(&card));
+        assert_eq!(None, defense(&testing::card()));
+    }
+}
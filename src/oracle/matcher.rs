@@ -0,0 +1,166 @@
+//! Combinators for matching oracle text locally.
+//!
+//! ```
+//! use scryfall::oracle::matcher::{ability_matches, contains_phrase};
+//!
+//! // An enters-the-battlefield trigger that draws a card.
+//! let etb_draw = ability_matches(contains_phrase("when ~ enters").and(contains_phrase("draw a card")));
+//! ```
+
+use super::{Ability, OracleText};
+use crate::types::card::Card;
+use regex::Regex;
+
+/// A condition on oracle text, built from the functions in this module.
+#[derive(Clone, Debug)]
+pub enum TextMatcher {
+    /// Some ability contains these tokens in order, with nothing in between.
+    Phrase(Vec<String>),
+
+    /// The regex matches the text, with abilities separated by newlines.
+    Regex(Regex),
+
+    /// A single ability satisfies the inner matcher on its own.
+    Ability(Box<TextMatcher>),
+
+    /// Every inner matcher matches.
+    All(Vec<TextMatcher>),
+
+    /// At least one inner matcher matches.
+    Any(Vec<TextMatcher>),
+
+    /// The inner matcher doesn't match.
+    Not(Box<TextMatcher>),
+}
+
+/// Matches text containing `phrase`, compared word by word, ignoring case and punctuation. Phrases never match across
+/// two abilities.
+pub fn contains_phrase(phrase: &str) -> TextMatcher {
+    TextMatcher::Phrase(super::tokenize(phrase))
+}
+
+/// Matches text the regex `pattern` matches. The card's name appears as `~`.
+pub fn regex(pattern: &str) -> Result<TextMatcher, regex::Error> {
+    Ok(TextMatcher::Regex(Regex::new(pattern)?))
+}
+
+/// Matches text with a single ability that satisfies `matcher` by itself, e.g. one ability that both triggers on
+/// entering and draws a card, rather than one of each.
+pub fn ability_matches(matcher: TextMatcher) -> TextMatcher {
+    TextMatcher::Ability(Box::new(matcher))
+}
+
+impl TextMatcher {
+    /// Matches when both this and `other` match.
+    pub fn and(self, other: TextMatcher) -> TextMatcher {
+        match self {
+            TextMatcher::All(mut matchers) => {
+                matchers.push(other);
+                TextMatcher::All(matchers)
+            }
+            matcher => TextMatcher::All(vec![matcher, other]),
+        }
+    }
+
+    /// Matches when either this or `other` matches.
+    pub fn or(self, other: TextMatcher) -> TextMatcher {
+        match self {
+            TextMatcher::Any(mut matchers) => {
+                matchers.push(other);
+                TextMatcher::Any(matchers)
+            }
+            matcher => TextMatcher::Any(vec![matcher, other]),
+        }
+    }
+
+    /// Matches when this doesn't.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> TextMatcher {
+        TextMatcher::Not(Box::new(self))
+    }
+
+    /// Whether `text` satisfies this matcher.
+    pub fn matches(&self, text: &OracleText) -> bool {
+        self.matches_abilities(&text.abilities)
+    }
+
+    /// Whether `card`'s oracle text satisfies this matcher.
+    pub fn matches_card(&self, card: &Card) -> bool {
+        self.matches(&OracleText::from_card(card))
+    }
+
+    fn matches_abilities(&self, abilities: &[Ability]) -> bool {
+        match self {
+            TextMatcher::Phrase(phrase) => abilities.iter().any(|ability| {
+                phrase.is_empty()
+                    || ability
+                        .tokens
+                        .windows(phrase.len())
+                        .any(|window| window == phrase.as_slice())
+            }),
+            TextMatcher::Regex(regex) => {
+                let text: Vec<&str> = abilities
+                    .iter()
+                    .map(|ability| ability.text.as_str())
+                    .collect();
+                regex.is_match(&text.join("\n"))
+            }
+            TextMatcher::Ability(matcher) => abilities
+                .iter()
+                .any(|ability| matcher.matches_abilities(std::slice::from_ref(ability))),
+            TextMatcher::All(matchers) => matchers
+                .iter()
+                .all(|matcher| matcher.matches_abilities(abilities)),
+            TextMatcher::Any(matchers) => matchers
+                .iter()
+                .any(|matcher| matcher.matches_abilities(abilities)),
+            TextMatcher::Not(matcher) => !matcher.matches_abilities(abilities),
+        }
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_ability_matches() {
+        use super::{ability_matches, contains_phrase};
+        use crate::oracle::OracleText;
+
+        let etb_draw =
+            ability_matches(contains_phrase("when ~ enters").and(contains_phrase("draw a card")));
+        let anywhere = contains_phrase("when ~ enters").and(contains_phrase("draw a card"));
+
+        let visionary = OracleText::parse("When ~ enters, draw a card.");
+        let split = OracleText::parse(
+            "~ enters tapped.\nWhen ~ enters, you gain 1 life.\nWhenever you draw a card, scry 1.",
+        );
+
+        assert!(etb_draw.matches(&visionary));
+        assert!(!etb_draw.matches(&split));
+        assert!(anywhere.matches(&split));
+    }
+
+    #[test]
+    fn test_phrase() {
+        use super::contains_phrase;
+        use crate::oracle::OracleText;
+
+        let text =
+            OracleText::parse("Flying\nWhenever ~ deals combat damage to a player, draw a card.");
+        assert!(contains_phrase("Deals combat damage").matches(&text));
+        assert!(!contains_phrase("flying whenever").matches(&text));
+        assert!(contains_phrase("flying")
+            .not()
+            .or(contains_phrase("draw"))
+            .matches(&text));
+    }
+
+    #[test]
+    fn test_regex() {
+        use super::regex;
+        use crate::testing;
+
+        let damage = regex(r"deals \d+ damage").unwrap();
+        assert!(damage.matches_card(&testing::card()));
+        assert!(regex("(").is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! Working with oracle text offline: tokenizing it into abilities and matching it without the search API.
+
+pub mod loyalty;
+pub mod matcher;
+pub mod structure;
+
+use crate::types::card::Card;
+
+/// One ability (one line) of oracle text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ability {
+    /// The ability's text as printed, with the card's own name replaced by `~`.
+    pub text: String,
+
+    /// The ability's words, lowercased and stripped of surrounding punctuation.
+    pub tokens: Vec<String>,
+}
+
+impl Ability {
+    fn new(text: &str) -> Self {
+        Ability {
+            text: text.to_string(),
+            tokens: tokenize(text),
+        }
+    }
+}
+
+/// Oracle text split into abilities.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OracleText {
+    pub abilities: Vec<Ability>,
+}
+
+impl OracleText {
+    /// Splits `text` into one ability per line.
+    pub fn parse(text: &str) -> Self {
+        OracleText {
+            abilities: text
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(Ability::new)
+                .collect(),
+        }
+    }
+
+    /// The card's oracle text, with references to the card by name replaced by `~` so that text can be matched
+    /// without knowing the name, e.g. "when ~ enters".
+    pub fn from_card(card: &Card) -> Self {
+        let text = card.oracle_text.as_deref().unwrap_or_default();
+        OracleText::parse(&text.replace(card.name.as_str(), "~"))
+    }
+
+    /// The abilities joined back into one string, one per line.
+    pub fn text(&self) -> String {
+        self.abilities
+            .iter()
+            .map(|ability| ability.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Lowercases `text` and splits it into words, dropping punctuation around each word but keeping symbols such as
+/// `{T}`, `+1/+1`, and `~` intact.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c| matches!(c, '.' | ',' | ';' | ':' | '"' | '(' | ')' | '—'))
+        })
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+mod tests {
+    #[test]
+    fn test_tokenize() {
+        use super::tokenize;
+
+        assert_eq!(
+            vec!["{t}", "put", "a", "+1/+1", "counter", "on", "target", "creature"],
+            tokenize("{T}: Put a +1/+1 counter on target creature.")
+        );
+    }
+
+    #[test]
+    fn test_from_card() {
+        use super::OracleText;
+        use crate::testing;
+        use serde_json::json;
+
+        let card = testing::card_with(json!({
+            "name": "Elvish Visionary",
+            "oracle_text": "When Elvish Visionary enters, draw a card.\nReach"
+        }));
+        let text = OracleText::from_card(&card);
+
+        assert_eq!(2, text.abilities.len());
+        assert_eq!("When ~ enters, draw a card.", text.abilities[0].text);
+        assert_eq!(vec!["reach"], text.abilities[1].tokens);
+    }
+}
@@ -0,0 +1,413 @@
+//! The structure some card types give their oracle text: Saga chapters, Class levels, Adventure halves, Leveler
+//! bands and Prototype costs.
+
+use crate::types::card::{Card, CardFace, Layout};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// One chapter ability of a Saga. Some chapter abilities trigger on several chapters, as in `I, II — ...`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Chapter {
+    /// The chapter numbers this ability triggers on, in order.
+    pub numbers: Vec<u32>,
+
+    /// The ability's text, after the dash.
+    pub text: String,
+}
+
+/// One level of a Class.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClassLevel {
+    /// The level, starting from 1.
+    pub level: u32,
+
+    /// The mana cost to gain this level, or `None` for level 1, which a Class starts at.
+    pub cost: Option<String>,
+
+    /// The abilities this level adds, one per line of oracle text.
+    pub abilities: Vec<String>,
+}
+
+/// The two halves of an Adventure card.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Adventure<'a> {
+    /// The permanent half, cast normally.
+    pub card: &'a CardFace,
+
+    /// The Adventure spell half.
+    pub adventure: &'a CardFace,
+}
+
+/// A range of levels of a Leveler card, and what the card is while its level counters are in that range.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LevelBand {
+    /// The lowest level in the band.
+    pub min: u32,
+
+    /// The highest level in the band, or `None` for the last band, which has no upper bound.
+    pub max: Option<u32>,
+
+    pub power: Option<String>,
+    pub toughness: Option<String>,
+
+    /// The abilities the card has in this band, one per line of oracle text.
+    pub abilities: Vec<String>,
+}
+
+/// The level up cost and bands of a Leveler card.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Leveler {
+    /// The cost to put a level counter on the card, from its level up ability.
+    pub level_up_cost: Option<String>,
+
+    /// The bands, from level 0 up. The first is the card as printed, before any `LEVEL` line.
+    pub bands: Vec<LevelBand>,
+}
+
+/// The alternate cost and size of a Prototype card.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Prototype {
+    pub mana_cost: String,
+    pub power: String,
+    pub toughness: String,
+}
+
+/// The chapter abilities in Saga oracle text. Lines that aren't chapters, like the reminder text, are skipped.
+pub fn parse_chapters(text: &str) -> Vec<Chapter> {
+    static CHAPTER: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^([IVX]+(?:, [IVX]+)*) — (.+)$").unwrap());
+    text.lines()
+        .filter_map(|line| {
+            let captures = CHAPTER.captures(line.trim())?;
+            Some(Chapter {
+                numbers: captures[1].split(", ").filter_map(roman).collect(),
+                text: captures[2].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The levels in Class oracle text. Reminder text in parentheses is skipped.
+pub fn parse_class_levels(text: &str) -> Vec<ClassLevel> {
+    static HEADER: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^((?:\{[^}]+\})+): Level (\d+)$").unwrap());
+    let mut levels = vec![ClassLevel {
+        level: 1,
+        cost: None,
+        abilities: Vec::new(),
+    }];
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || (line.starts_with('(') && line.ends_with(')')) {
+            continue;
+        }
+
+        match HEADER.captures(line) {
+            Some(captures) => levels.push(ClassLevel {
+                level: captures[2].parse().unwrap_or_default(),
+                cost: Some(captures[1].to_string()),
+                abilities: Vec::new(),
+            }),
+            None => levels.last_mut().unwrap().abilities.push(line.to_string()),
+        }
+    }
+
+    levels
+}
+
+/// The bands in Leveler oracle text. `power` and `toughness` are the card's printed stats, which apply below the first
+/// `LEVEL` line.
+pub fn parse_leveler(text: &str, power: Option<&str>, toughness: Option<&str>) -> Leveler {
+    static LEVEL_UP: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^Level up ((?:\{[^}]+\})+)").unwrap());
+    static BAND: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^LEVEL (\d+)(?:-(\d+)|\+)$").unwrap());
+    static STATS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([^\s/]+)/([^\s/]+)$").unwrap());
+
+    let mut leveler = Leveler {
+        level_up_cost: None,
+        bands: vec![LevelBand {
+            min: 0,
+            max: None,
+            power: power.map(str::to_string),
+            toughness: toughness.map(str::to_string),
+            abilities: Vec::new(),
+        }],
+    };
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some(captures) = LEVEL_UP.captures(line) {
+            leveler.level_up_cost = Some(captures[1].to_string());
+        } else if let Some(captures) = BAND.captures(line) {
+            let min: u32 = captures[1].parse().unwrap_or_default();
+            if let Some(previous) = leveler.bands.last_mut() {
+                previous.max = Some(min.saturating_sub(1));
+            }
+            leveler.bands.push(LevelBand {
+                min,
+                max: captures.get(2).and_then(|max| max.as_str().parse().ok()),
+                power: None,
+                toughness: None,
+                abilities: Vec::new(),
+            });
+        } else {
+            let current = leveler.bands.last_mut().unwrap();
+            match STATS.captures(line) {
+                Some(captures) if current.power.is_none() && current.min > 0 => {
+                    current.power = Some(captures[1].to_string());
+                    current.toughness = Some(captures[2].to_string());
+                }
+                _ => current.abilities.push(line.to_string()),
+            }
+        }
+    }
+
+    leveler
+}
+
+/// The Prototype cost and size in oracle text, if it has a Prototype ability.
+pub fn parse_prototype(text: &str) -> Option<Prototype> {
+    static PROTOTYPE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"(?m)^Prototype ((?:\{[^}]+\})+) — ([^\s/]+)/([^\s/]+)").unwrap()
+    });
+    let captures = PROTOTYPE.captures(text)?;
+    Some(Prototype {
+        mana_cost: captures[1].to_string(),
+        power: captures[2].to_string(),
+        toughness: captures[3].to_string(),
+    })
+}
+
+/// The level up cost and bands of `card`, if it's a Leveler.
+pub fn leveler(card: &Card) -> Option<Leveler> {
+    if card.layout != Layout::Leveler {
+        return None;
+    }
+
+    Some(parse_leveler(
+        card.oracle_text.as_deref()?,
+        card.power.as_deref(),
+        card.toughness.as_deref(),
+    ))
+}
+
+/// The Prototype cost and size of `card`, if it has one.
+pub fn prototype(card: &Card) -> Option<Prototype> {
+    parse_prototype(card.oracle_text.as_deref()?)
+}
+
+/// The chapters of `card`, if it or one of its faces is a Saga.
+pub fn saga(card: &Card) -> Option<Vec<Chapter>> {
+    oracle_text_of(card, "Saga").map(parse_chapters)
+}
+
+/// The levels of `card`, if it or one of its faces is a Class.
+pub fn class_levels(card: &Card) -> Option<Vec<ClassLevel>> {
+    oracle_text_of(card, "Class").map(parse_class_levels)
+}
+
+/// The halves of `card`, if it's an Adventure card.
+pub fn adventure(card: &Card) -> Option<Adventure<'_>> {
+    match card.card_faces.as_deref()? {
+        [card, adventure] if adventure.type_line.ends_with("— Adventure") => {
+            Some(Adventure { card, adventure })
+        }
+        _ => None,
+    }
+}
+
+/// The oracle text of `card`, or of its first face, whose type line has the subtype `subtype`.
+fn oracle_text_of<'a>(card: &'a Card, subtype: &str) -> Option<&'a str> {
+    let has_subtype = |type_line: &str| {
+        type_line
+            .split(" — ")
+            .nth(1)
+            .is_some_and(|subtypes| subtypes.split_whitespace().any(|word| word == subtype))
+    };
+
+    match &card.card_faces {
+        Some(faces) => faces
+            .iter()
+            .find(|face| has_subtype(&face.type_line))
+            .and_then(|face| face.oracle_text.as_deref()),
+        None if has_subtype(&card.type_line) => card.oracle_text.as_deref(),
+        None => None,
+    }
+}
+
+/// The value of a roman numeral up to the chapters Sagas use.
+fn roman(numeral: &str) -> Option<u32> {
+    let value = |c| match c {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        _ => None,
+    };
+
+    let digits: Vec<u32> = numeral.chars().map(value).collect::<Option<_>>()?;
+    let total = digits.iter().enumerate().fold(0i64, |total, (i, digit)| {
+        if digits.get(i + 1).is_some_and(|next| next > digit) {
+            total - i64::from(*digit)
+        } else {
+            total + i64::from(*digit)
+        }
+    });
+    Some(total as u32)
+}
+
+mod tests {
+    #[test]
+    fn test_saga() {
+        use super::{saga, Chapter};
+        use crate::testing;
+
+        let card = testing::card_with(serde_json::json!({
+            "name": "History of Benalia",
+            "type_line": "Enchantment — Saga",
+            "layout": "saga",
+            "oracle_text": "(As this Saga enters and after your draw step, add a lore counter. Sacrifice after III.)\nI, II — Create a 2/2 white Knight creature token with vigilance.\nIII — Knights you control get +2/+1 until end of turn.",
+        }));
+
+        assert_eq!(
+            Some(vec![
+                Chapter {
+                    numbers: vec![1, 2],
+                    text: "Create a 2/2 white Knight creature token with vigilance.".to_string(),
+                },
+                Chapter {
+                    numbers: vec![3],
+                    text: "Knights you control get +2/+1 until end of turn.".to_string(),
+                },
+            ]),
+            saga(&card)
+        );
+        assert_eq!(None, saga(&testing::card()));
+    }
+
+    #[test]
+    fn test_class_levels() {
+        use super::class_levels;
+        use crate::testing;
+
+        let card = testing::card_with(serde_json::json!({
+            "name": "Wizard Class",
+            "type_line": "Enchantment — Class",
+            "layout": "class",
+            "oracle_text": "(Gain the next level as a sorcery to add its ability.)\nYou have no maximum hand size.\n{2}{U}: Level 2\nWhen this Class becomes level 2, draw two cards.\n{4}{U}: Level 3\nWhenever you draw a card, put a +1/+1 counter on target creature you control.",
+        }));
+
+        let levels = class_levels(&card).unwrap();
+        assert_eq!(3, levels.len());
+        assert_eq!(None, levels[0].cost);
+        assert_eq!(vec!["You have no maximum hand size."], levels[0].abilities);
+        assert_eq!(2, levels[1].level);
+        assert_eq!(Some("{2}{U}"), levels[1].cost.as_deref());
+        assert_eq!(Some("{4}{U}"), levels[2].cost.as_deref());
+        assert_eq!(1, levels[2].abilities.len());
+    }
+
+    #[test]
+    fn test_adventure() {
+        use super::adventure;
+        use crate::testing;
+
+        let card = testing::card_with(serde_json::json!({
+            "name": "Bonecrusher Giant // Stomp",
+            "layout": "adventure",
+            "type_line": "Creature — Giant // Instant — Adventure",
+            "card_faces": [
+                {
+                    "name": "Bonecrusher Giant",
+                    "mana_cost": "{2}{R}",
+                    "type_line": "Creature — Giant",
+                    "oracle_text": "Whenever Bonecrusher Giant becomes the target of a spell, Bonecrusher Giant deals 2 damage to that spell's controller.",
+                },
+                {
+                    "name": "Stomp",
+                    "mana_cost": "{1}{R}",
+                    "type_line": "Instant — Adventure",
+                    "oracle_text": "Damage can't be prevented this turn. Stomp deals 2 damage to any target.",
+                },
+            ],
+        }));
+
+        let halves = adventure(&card).unwrap();
+        assert_eq!("Bonecrusher Giant", halves.card.name);
+        assert_eq!("Stomp", halves.adventure.name);
+        assert_eq!(None, adventure(&testing::double_faced_card()));
+    }
+
+    #[test]
+    fn test_roman() {
+        use super::roman;
+
+        assert_eq!(Some(4), roman("IV"));
+        assert_eq!(Some(6), roman("VI"));
+        assert_eq!(None, roman("L"));
+    }
+
+    #[test]
+    fn test_leveler() {
+        use super::{leveler, LevelBand};
+        use crate::testing;
+
+        let card = testing::card_with(serde_json::json!({
+            "name": "Student of Warfare",
+            "layout": "leveler",
+            "type_line": "Creature — Human Knight",
+            "power": "1",
+            "toughness": "1",
+            "oracle_text": "Level up {W} ({W}: Put a level counter on this. Level up only as a sorcery.)\nLEVEL 2-6\n3/3\nFirst strike\nLEVEL 7+\n4/4\nDouble strike",
+        }));
+
+        let leveler = leveler(&card).unwrap();
+        assert_eq!(Some("{W}"), leveler.level_up_cost.as_deref());
+        assert_eq!(
+            vec![
+                LevelBand {
+                    min: 0,
+                    max: Some(1),
+                    power: Some("1".to_string()),
+                    toughness: Some("1".to_string()),
+                    abilities: Vec::new(),
+                },
+                LevelBand {
+                    min: 2,
+                    max: Some(6),
+                    power: Some("3".to_string()),
+                    toughness: Some("3".to_string()),
+                    abilities: vec!["First strike".to_string()],
+                },
+                LevelBand {
+                    min: 7,
+                    max: None,
+                    power: Some("4".to_string()),
+                    toughness: Some("4".to_string()),
+                    abilities: vec!["Double strike".to_string()],
+                },
+            ],
+            leveler.bands
+        );
+        assert_eq!(None, super::leveler(&testing::card()));
+    }
+
+    #[test]
+    fn test_prototype() {
+        use super::{prototype, Prototype};
+        use crate::testing;
+
+        let card = testing::card_with(serde_json::json!({
+            "name": "Phyrexian Fleshgorger",
+            "layout": "prototype",
+            "oracle_text": "Prototype {1}{B}{B} — 3/3 (You may cast this spell with different mana cost, color, and size. It keeps its abilities and types.)\nMenace, lifelink\nWard—Pay life equal to Phyrexian Fleshgorger's power.",
+        }));
+
+        assert_eq!(
+            Some(Prototype {
+                mana_cost: "{1}{B}{B}".to_string(),
+                power: "3".to_string(),
+                toughness: "3".to_string(),
+            }),
+            prototype(&card)
+        );
+        assert_eq!(None, prototype(&testing::card()));
+    }
+}
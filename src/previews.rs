@@ -0,0 +1,152 @@
+//! Grouping and filtering cards by their preview details, e.g. for spoiler-season dashboards.
+
+use crate::types::card::Card;
+use crate::types::date::Date;
+use std::collections::BTreeMap;
+
+/// The outlet that previewed `card`, if it was previewed and the source is known.
+pub fn source(card: &Card) -> Option<&str> {
+    card.preview
+        .as_ref()
+        .and_then(|preview| preview.source.as_deref())
+        .filter(|source| !source.is_empty())
+}
+
+/// The date `card` was previewed, if it was.
+pub fn previewed_at(card: &Card) -> Option<Date> {
+    card.preview
+        .as_ref()
+        .and_then(|preview| preview.previewed_at)
+}
+
+/// The cards previewed by `outlet`, compared ignoring case.
+pub fn from_source<'a, I>(cards: I, outlet: &str) -> Vec<&'a Card>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    cards
+        .into_iter()
+        .filter(|card| source(card).is_some_and(|source| source.eq_ignore_ascii_case(outlet)))
+        .collect()
+}
+
+/// The cards previewed between `from` and `to`, inclusive.
+pub fn previewed_between<'a, I>(cards: I, from: Date, to: Date) -> Vec<&'a Card>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    cards
+        .into_iter()
+        .filter(|card| previewed_at(card).is_some_and(|date| from <= date && date <= to))
+        .collect()
+}
+
+/// The cards previewed on `date`.
+pub fn previewed_on<'a, I>(cards: I, date: Date) -> Vec<&'a Card>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    previewed_between(cards, date, date)
+}
+
+/// Previewed cards grouped by outlet, in alphabetical order of outlet. Cards without a known source are left out.
+pub fn by_source<'a, I>(cards: I) -> BTreeMap<&'a str, Vec<&'a Card>>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    let mut groups: BTreeMap<&str, Vec<&Card>> = BTreeMap::new();
+    for card in cards {
+        if let Some(source) = source(card) {
+            groups.entry(source).or_default().push(card);
+        }
+    }
+    groups
+}
+
+/// Previewed cards grouped by preview date, oldest first. Cards without a preview date are left out.
+pub fn by_date<'a, I>(cards: I) -> BTreeMap<Date, Vec<&'a Card>>
+where
+    I: IntoIterator<Item = &'a Card>,
+{
+    let mut groups: BTreeMap<Date, Vec<&Card>> = BTreeMap::new();
+    for card in cards {
+        if let Some(date) = previewed_at(card) {
+            groups.entry(date).or_default().push(card);
+        }
+    }
+    groups
+}
+
+mod tests {
+    #[cfg(test)]
+    fn previewed(name: &str, source: &str, date: &str) -> crate::types::card::Card {
+        use crate::testing;
+        use serde_json::json;
+
+        testing::card_with(json!({
+            "name": name,
+            "preview": { "source": source, "source_uri": "", "previewed_at": date }
+        }))
+    }
+
+    #[test]
+    fn test_revealed_today_by_outlet() {
+        use super::{by_source, previewed_on};
+        use crate::testing;
+        use crate::types::date::Date;
+
+        let cards = vec![
+            previewed("A", "Wizards of the Coast", "2024-07-09"),
+            previewed("B", "MTGGoldfish", "2024-07-09"),
+            previewed("C", "Wizards of the Coast", "2024-07-09"),
+            previewed("D", "Wizards of the Coast", "2024-07-10"),
+            testing::card(),
+        ];
+
+        let today = Date {
+            year: 2024,
+            month: 7,
+            day: 9,
+        };
+        let groups = by_source(previewed_on(&cards, today));
+        let names: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|(source, cards)| {
+                (
+                    *source,
+                    cards.iter().map(|card| card.name.as_str()).collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("MTGGoldfish", vec!["B"]),
+                ("Wizards of the Coast", vec!["A", "C"])
+            ],
+            names
+        );
+    }
+
+    #[test]
+    fn test_filters() {
+        use super::{by_date, from_source, previewed_between};
+        use crate::types::date::Date;
+
+        let cards = vec![
+            previewed("A", "Wizards of the Coast", "2024-07-08"),
+            previewed("B", "MTGGoldfish", "2024-07-09"),
+            previewed("C", "", "2024-07-12"),
+        ];
+
+        assert_eq!(1, from_source(&cards, "mtggoldfish").len());
+        let from = Date {
+            year: 2024,
+            month: 7,
+            day: 9,
+        };
+        let to = Date { day: 12, ..from };
+        assert_eq!(2, previewed_between(&cards, from, to).len());
+        assert_eq!(3, by_date(&cards).len());
+    }
+}
@@ -0,0 +1,344 @@
+//! Typed construction of Scryfall search queries.
+
+use std::error;
+use std::fmt;
+
+/// A single term in a Scryfall search query.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Term {
+    /// Matches card names containing the given words.
+    Name(String),
+
+    /// `t:` — matches cards whose type line contains the given type.
+    Type(String),
+
+    /// `set:` — matches printings from the set with the given code.
+    Set(String),
+
+    /// `is:` — matches cards with the given property, e.g. `fullart` or `commander`.
+    Is(String),
+
+    /// `ft:` — matches cards whose flavor text contains the given words.
+    FlavorText(String),
+
+    /// `a:` — matches cards illustrated by an artist whose name contains the given words.
+    Artist(String),
+
+    /// `-` — matches cards that don't match the inner term.
+    Not(Box<Term>),
+}
+
+/// Writes a term's value, quoting it when it contains whitespace.
+fn write_value(f: &mut fmt::Formatter, value: &str) -> fmt::Result {
+    if value.contains(char::is_whitespace) {
+        write!(f, "\"{}\"", value.replace('"', "\\\""))
+    } else {
+        f.write_str(value)
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Term::Name(name) => write_value(f, name),
+            Term::Type(value) => {
+                f.write_str("t:")?;
+                write_value(f, value)
+            }
+            Term::Set(value) => {
+                f.write_str("set:")?;
+                write_value(f, value)
+            }
+            Term::Is(value) => {
+                f.write_str("is:")?;
+                write_value(f, value)
+            }
+            Term::FlavorText(value) => {
+                f.write_str("ft:")?;
+                write_value(f, value)
+            }
+            Term::Artist(value) => {
+                f.write_str("a:")?;
+                write_value(f, value)
+            }
+            Term::Not(term) => write!(f, "-{}", term),
+        }
+    }
+}
+
+/// A search query made of terms that must all match.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Query {
+    terms: Vec<Term>,
+}
+
+impl Query {
+    /// An empty query.
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    /// Adds a term that results must also match.
+    pub fn and(mut self, term: Term) -> Self {
+        self.terms.push(term);
+        self
+    }
+
+    /// The terms in this query.
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, term) in self.terms.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", term)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The longest query Scryfall accepts, in characters.
+pub const MAX_QUERY_LENGTH: usize = 1000;
+
+/// The keywords Scryfall's search syntax understands, in lowercase.
+#[rustfmt::skip]
+const KEYWORDS: &[&str] = &[
+    "a", "art", "artist", "artists", "arttag", "atag", "b", "banned", "block", "border", "c",
+    "cheapest", "ci", "cmc", "cn", "color", "cube", "date", "def", "defense", "devotion", "dir",
+    "direction", "display", "e", "edition", "eur", "f", "flavor", "fo", "format", "frame", "ft",
+    "fulloracle", "function", "game", "has", "id", "identity", "illustrations", "in", "include",
+    "is", "keyword", "kw", "lang", "language", "legal", "lore", "loy", "loyalty", "m", "mana",
+    "manavalue", "mv", "name", "new", "not", "number", "o", "oracle", "oracleid", "oracletag",
+    "order", "otag", "paperprints", "papersets", "pow", "power", "powtou", "prefer", "prints",
+    "produces", "pt", "r", "rarity", "restricted", "s", "set", "sets", "st", "stamp", "t",
+    "tix", "tou", "toughness", "type", "unique", "usd", "wm", "watermark", "year",
+];
+
+/// A problem with a search query that Scryfall would reject, found without sending it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QueryError {
+    /// The query has no terms.
+    Empty,
+
+    /// The query is longer than [`MAX_QUERY_LENGTH`]; holds its length in characters.
+    TooLong(usize),
+
+    /// A quote is never closed; holds the byte offset of the opening quote.
+    UnclosedQuote(usize),
+
+    /// A `/regex/` value is never closed; holds the byte offset of the opening slash.
+    UnclosedRegex(usize),
+
+    /// A parenthesis has no partner; holds its byte offset.
+    UnbalancedParen(usize),
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryError::Empty => write!(f, "the query is empty"),
+            QueryError::TooLong(length) => write!(
+                f,
+                "the query is {} characters long, but Scryfall accepts at most {}",
+                length, MAX_QUERY_LENGTH
+            ),
+            QueryError::UnclosedQuote(at) => {
+                write!(f, "the quote at offset {} is never closed", at)
+            }
+            QueryError::UnclosedRegex(at) => {
+                write!(f, "the regular expression at offset {} is never closed", at)
+            }
+            QueryError::UnbalancedParen(at) => {
+                write!(f, "the parenthesis at offset {} has no partner", at)
+            }
+        }
+    }
+}
+
+impl error::Error for QueryError {}
+
+/// Checks `query` for mistakes Scryfall would reject with a 400, so they can be reported without a round trip. This
+/// catches structural problems, not every invalid query: a known keyword with a nonsensical value still passes, and so
+/// does an unknown keyword, which Scryfall ignores with a warning rather than rejecting. See [`unknown_keywords`].
+pub fn validate(query: &str) -> Result<(), QueryError> {
+    scan(query).map(|_| ())
+}
+
+/// The keywords in `query` this crate doesn't recognize, in lowercase and in the order they appear. Scryfall ignores a
+/// keyword it doesn't know and says so in the result's `warnings`, but it may also know keywords added since this list
+/// was written, so these are worth a warning, not an error. A query [`validate`] rejects has none.
+pub fn unknown_keywords(query: &str) -> Vec<String> {
+    scan(query).unwrap_or_default()
+}
+
+/// Checks `query` for the mistakes [`validate`] reports, returning the unknown keywords found along the way.
+fn scan(query: &str) -> Result<Vec<String>, QueryError> {
+    let length = query.chars().count();
+    if length > MAX_QUERY_LENGTH {
+        return Err(QueryError::TooLong(length));
+    }
+
+    let bytes = query.as_bytes();
+    let mut open_parens = Vec::new();
+    let mut unknown = Vec::new();
+    let mut has_term = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                i = closing(bytes, i, b'"').ok_or(QueryError::UnclosedQuote(i))?;
+                has_term = true;
+            }
+            b'(' => open_parens.push(i),
+            b')' => {
+                open_parens.pop().ok_or(QueryError::UnbalancedParen(i))?;
+            }
+            byte if byte.is_ascii_alphabetic() && starts_word(bytes, i) => {
+                let start = i;
+                while i + 1 < bytes.len() && bytes[i + 1].is_ascii_alphabetic() {
+                    i += 1;
+                }
+                has_term = true;
+
+                let operator = &bytes[i + 1..];
+                let is_operator = [&b":"[..], b"=", b"<", b">", b"!="]
+                    .iter()
+                    .any(|op| operator.starts_with(op));
+                if is_operator {
+                    let keyword = query[start..=i].to_ascii_lowercase();
+                    if !KEYWORDS.contains(&keyword.as_str()) {
+                        unknown.push(keyword);
+                    }
+
+                    // Skip the operator, then a regex value whole, since it can contain quotes and parentheses.
+                    while i + 1 < bytes.len() && b":=<>!".contains(&bytes[i + 1]) {
+                        i += 1;
+                    }
+                    if bytes.get(i + 1) == Some(&b'/') {
+                        i = closing(bytes, i + 1, b'/').ok_or(QueryError::UnclosedRegex(i + 1))?;
+                    }
+                }
+            }
+            byte if !byte.is_ascii_whitespace() && byte != b'-' => has_term = true,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    if let Some(at) = open_parens.pop() {
+        return Err(QueryError::UnbalancedParen(at));
+    }
+    if !has_term {
+        return Err(QueryError::Empty);
+    }
+
+    Ok(unknown)
+}
+
+/// The offset of the `delimiter` closing the one at `open`, skipping backslash escapes.
+fn closing(bytes: &[u8], open: usize, delimiter: u8) -> Option<usize> {
+    let mut i = open + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            byte if byte == delimiter => return Some(i),
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Whether a word starting at `i` is the start of a term, rather than the middle of a word or value.
+fn starts_word(bytes: &[u8], i: usize) -> bool {
+    i == 0 || b" \t\n(-!".contains(&bytes[i - 1])
+}
+
+mod tests {
+    #[test]
+    fn test_query() {
+        use super::{Query, Term};
+
+        let query = Query::new()
+            .and(Term::Type("basic".to_string()))
+            .and(Term::Set("ust".to_string()))
+            .and(Term::Not(Box::new(Term::Is("fullart".to_string()))))
+            .and(Term::Name("Snow-Covered Island".to_string()));
+
+        assert_eq!(
+            "t:basic set:ust -is:fullart \"Snow-Covered Island\"",
+            query.to_string()
+        );
+    }
+
+    #[test]
+    fn test_flavor_and_artist_terms() {
+        use super::{Query, Term};
+
+        let query = Query::new()
+            .and(Term::FlavorText("Urza".to_string()))
+            .and(Term::Artist("Rebecca Guay".to_string()));
+        assert_eq!("ft:Urza a:\"Rebecca Guay\"", query.to_string());
+    }
+
+    #[test]
+    fn test_validate() {
+        use super::validate;
+
+        assert_eq!(Ok(()), validate("t:goblin c>=rg"));
+        assert_eq!(
+            Ok(()),
+            validate("(t:elf or t:goblin) -is:funny \"Llanowar Elves\"")
+        );
+        assert_eq!(Ok(()), validate("o:/^{T}: add (one|two)/ mv<=2"));
+        assert_eq!(Ok(()), validate("!\"Ach! Hans, Run!\""));
+        assert_eq!(Ok(()), validate("Lightning Bolt"));
+        assert_eq!(
+            Ok(()),
+            validate("t:basic set:ust -is:fullart \"Snow-Covered Island\"")
+        );
+        assert_eq!(
+            Ok(()),
+            validate("oracleid:4457ed35-7c10-48c8-9776-456485fdf070 prints>5 oracletag:removal")
+        );
+        assert_eq!(Ok(()), validate("Colour:red t:goblin"));
+    }
+
+    #[test]
+    fn test_unknown_keywords() {
+        use super::unknown_keywords;
+
+        assert!(unknown_keywords("oracleid:4457ed35 prints>5 otag:removal").is_empty());
+        assert_eq!(
+            vec!["colour".to_string(), "flavour".to_string()],
+            unknown_keywords("Colour:red -flavour:\"Urza\" t:goblin")
+        );
+        assert!(unknown_keywords("(colour:red").is_empty());
+    }
+
+    #[test]
+    fn test_validate_errors() {
+        use super::{validate, QueryError};
+
+        assert_eq!(Err(QueryError::Empty), validate("   "));
+        assert_eq!(Err(QueryError::Empty), validate("()"));
+        assert_eq!(Err(QueryError::TooLong(1001)), validate(&"a".repeat(1001)));
+        assert_eq!(
+            Err(QueryError::UnclosedQuote(2)),
+            validate("o:\"draw a card")
+        );
+        assert_eq!(Err(QueryError::UnclosedRegex(2)), validate("o:/draw"));
+        assert_eq!(
+            Err(QueryError::UnbalancedParen(0)),
+            validate("(t:elf or t:goblin")
+        );
+        assert_eq!(Err(QueryError::UnbalancedParen(5)), validate("t:elf)"));
+    }
+}
@@ -0,0 +1,198 @@
+//! Projections of cards for "guess the card" games, with everything that gives the answer away removed.
+
+use crate::types::card::{Card, ImageUris, ImageVersion};
+use crate::types::uri::Uri;
+
+/// What replaces the card's name in redacted text.
+pub const REDACTED_NAME: &str = "CARDNAME";
+
+/// One face of a card with its name removed, for guessing a card from its rules.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TextFace {
+    pub mana_cost: Option<String>,
+    pub type_line: String,
+
+    /// The oracle text, with every reference to the card by name replaced by [`REDACTED_NAME`].
+    pub oracle_text: Option<String>,
+    pub power: Option<String>,
+    pub toughness: Option<String>,
+    pub loyalty: Option<String>,
+}
+
+/// A card's rules with its name removed from every face, for a text-only quiz. Flavor text, artist and printing
+/// details are left out, since they often name the card or make it easy to look up.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TextOnly {
+    /// The card's faces, in order. Single-faced cards have one.
+    pub faces: Vec<TextFace>,
+}
+
+/// The art of a card with nothing else, for an art-only quiz.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArtOnly {
+    /// The art crop of each face that has one, in order.
+    pub art_crops: Vec<Uri>,
+}
+
+/// The card's rules text with every name it could be called by redacted.
+pub fn text_only(card: &Card) -> TextOnly {
+    let names = names(card);
+    let redact = |text: &Option<String>| text.as_deref().map(|text| redact_names(text, &names));
+
+    let faces = match &card.card_faces {
+        Some(faces) => faces
+            .iter()
+            .map(|face| TextFace {
+                mana_cost: Some(face.mana_cost.clone()).filter(|cost| !cost.is_empty()),
+                type_line: face.type_line.clone(),
+                oracle_text: redact(&face.oracle_text),
+                power: face.power.clone(),
+                toughness: face.toughness.clone(),
+                loyalty: face.loyalty.clone(),
+            })
+            .collect(),
+        None => vec![TextFace {
+            mana_cost: card.mana_cost.clone().filter(|cost| !cost.is_empty()),
+            type_line: card.type_line.clone(),
+            oracle_text: redact(&card.oracle_text),
+            power: card.power.clone(),
+            toughness: card.toughness.clone(),
+            loyalty: card.loyalty.clone(),
+        }],
+    };
+
+    TextOnly { faces }
+}
+
+/// The card's art crops, or `None` if it has no art crop at all. Double-faced cards have one per face, while split
+/// and adventure cards share one image.
+pub fn art_only(card: &Card) -> Option<ArtOnly> {
+    let art_crop = |uris: &Option<ImageUris>| {
+        uris.as_ref()
+            .and_then(|uris| uris.get(ImageVersion::ArtCrop))
+            .cloned()
+    };
+
+    let mut art_crops: Vec<Uri> = card
+        .card_faces
+        .iter()
+        .flatten()
+        .filter_map(|face| art_crop(&face.image_uris))
+        .collect();
+    if art_crops.is_empty() {
+        art_crops.extend(art_crop(&card.image_uris));
+    }
+
+    if art_crops.is_empty() {
+        None
+    } else {
+        Some(ArtOnly { art_crops })
+    }
+}
+
+/// Replaces every occurrence of each of `names` in `text` with [`REDACTED_NAME`], trying longer names first so a
+/// full name is redacted whole rather than leaving part of it behind.
+pub fn redact_names(text: &str, names: &[String]) -> String {
+    let mut names: Vec<&String> = names.iter().filter(|name| !name.is_empty()).collect();
+    names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+    names.iter().fold(text.to_string(), |text, name| {
+        text.replace(name.as_str(), REDACTED_NAME)
+    })
+}
+
+/// Every name `card` could be referred to by in its rules text: its full name, the name of each face, and the short
+/// name of legendary faces ("Urza" for "Urza, Lord High Artificer").
+fn names(card: &Card) -> Vec<String> {
+    let mut names = vec![card.name.clone()];
+    names.extend(
+        card.card_faces
+            .iter()
+            .flatten()
+            .map(|face| face.name.clone()),
+    );
+
+    let short_names: Vec<String> = names
+        .iter()
+        .filter_map(|name| name.split_once(", ").map(|(short, _)| short.to_string()))
+        .collect();
+    names.extend(short_names);
+    names
+}
+
+mod tests {
+    #[test]
+    fn test_text_only() {
+        use super::text_only;
+        use crate::testing;
+
+        let card = testing::card_with(serde_json::json!({
+            "name": "Urza, Lord High Artificer",
+            "mana_cost": "{2}{U}{U}",
+            "type_line": "Legendary Creature — Human Artificer",
+            "oracle_text": "When Urza, Lord High Artificer enters, create a 0/0 Construct.\nTap an untapped artifact you control: Add {U}. Urza can't be countered.",
+            "power": "1",
+            "toughness": "4",
+        }));
+
+        let quiz = text_only(&card);
+        assert_eq!(1, quiz.faces.len());
+        assert_eq!(
+            "When CARDNAME enters, create a 0/0 Construct.\nTap an untapped artifact you control: Add {U}. CARDNAME can't be countered.",
+            quiz.faces[0].oracle_text.as_deref().unwrap()
+        );
+        assert_eq!(Some("{2}{U}{U}"), quiz.faces[0].mana_cost.as_deref());
+    }
+
+    #[test]
+    fn test_text_only_faces() {
+        use super::text_only;
+        use crate::testing;
+
+        let card = testing::double_faced_card();
+
+        let quiz = text_only(&card);
+        assert_eq!(2, quiz.faces.len());
+        assert!(quiz.faces[0]
+            .oracle_text
+            .as_deref()
+            .unwrap()
+            .ends_with("transform CARDNAME."));
+        assert_eq!(None, quiz.faces[1].mana_cost);
+        assert_eq!("Creature — Human Insect", quiz.faces[1].type_line);
+
+        let art = super::art_only(&card).unwrap();
+        assert_eq!(2, art.art_crops.len());
+        assert_eq!(
+            "https://cards.scryfall.io/art_crop/back/delver.jpg",
+            art.art_crops[1].as_str()
+        );
+    }
+
+    #[test]
+    fn test_art_only() {
+        use super::art_only;
+        use crate::testing;
+
+        let card = testing::card();
+        assert_eq!(
+            card.image_uris.as_ref().unwrap().art_crop.as_ref(),
+            art_only(&card).unwrap().art_crops.first()
+        );
+        assert_eq!(
+            None,
+            art_only(&testing::card_with(serde_json::json!({"image_uris": null})))
+        );
+    }
+
+    #[test]
+    fn test_redact_names() {
+        use super::redact_names;
+
+        let names = vec!["Fire".to_string(), "Fire // Ice".to_string()];
+        assert_eq!(
+            "CARDNAME deals 2 damage",
+            redact_names("Fire // Ice deals 2 damage", &names)
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Working with Scryfall's list of sets.
+
+use crate::types::date::Date;
+use crate::types::set::Set;
+
+/// Upcoming set releases in date order, e.g. for embedding a release calendar on a site.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReleaseCalendar<'a> {
+    generated: Date,
+    releases: Vec<&'a Set>,
+}
+
+/// The sets in `sets` releasing on or after `from`, ordered by release date and then name. Sets with a parent, such
+/// as promo and token sets, are left out since they release alongside their parent, as are sets without a date.
+pub fn release_calendar(sets: &[Set], from: Date) -> ReleaseCalendar<'_> {
+    let mut releases: Vec<&Set> = sets
+        .iter()
+        .filter(|set| set.parent_set_code.is_none())
+        .filter(|set| set.released_at.is_some_and(|date| date >= from))
+        .collect();
+    releases.sort_by(|a, b| (a.released_at, &a.name).cmp(&(b.released_at, &b.name)));
+
+    ReleaseCalendar {
+        generated: from,
+        releases,
+    }
+}
+
+impl<'a> ReleaseCalendar<'a> {
+    /// The releases, in order.
+    pub fn releases(&self) -> &[&'a Set] {
+        &self.releases
+    }
+
+    /// The calendar as an iCalendar (RFC 5545) document, with one all-day event per release.
+    pub fn to_ics(&self) -> String {
+        let mut ics = String::new();
+        let mut line = |text: String| push_folded(&mut ics, &text);
+
+        line("BEGIN:VCALENDAR".to_string());
+        line("VERSION:2.0".to_string());
+        line("PRODID:-//scryfall-rs//Release Calendar//EN".to_string());
+        line("CALSCALE:GREGORIAN".to_string());
+
+        for set in &self.releases {
+            // Sets without a date were filtered out when building the calendar.
+            let date = set.released_at.unwrap();
+
+            line("BEGIN:VEVENT".to_string());
+            line(format!("UID:{}@scryfall.com", set.id));
+            line(format!("DTSTAMP:{}T000000Z", compact(self.generated)));
+            line(format!("DTSTART;VALUE=DATE:{}", compact(date)));
+            line(format!(
+                "SUMMARY:{} ({})",
+                escape(&set.name),
+                set.code.to_uppercase()
+            ));
+            line(format!("DESCRIPTION:{} cards", set.card_count));
+            line(format!("URL:{}", set.scryfall_uri));
+            line("END:VEVENT".to_string());
+        }
+
+        line("END:VCALENDAR".to_string());
+        ics
+    }
+}
+
+/// A date in iCalendar's basic format, e.g. `20240802`.
+fn compact(date: Date) -> String {
+    format!("{:04}{:02}{:02}", date.year, date.month, date.day)
+}
+
+/// Escapes the characters iCalendar treats specially in text values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends `line` to `ics`, folded so no physical line is longer than 75 bytes, as iCalendar requires.
+fn push_folded(ics: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            ics.push_str("\r\n ");
+            width = 1;
+        }
+        ics.push(c);
+        width += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+mod tests {
+    #[test]
+    fn test_release_calendar() {
+        use super::release_calendar;
+        use crate::testing;
+        use crate::types::date::Date;
+        use serde_json::json;
+
+        let sets = vec![
+            testing::set_with(
+                json!({ "code": "dsk", "name": "Duskmourn: House of Horror", "released_at": "2024-09-27" }),
+            ),
+            testing::set(),
+            testing::set_with(json!({ "code": "pblb", "parent_set_code": "blb" })),
+            testing::set_with(json!({ "code": "otj", "released_at": "2024-04-19" })),
+            testing::set_with(json!({ "code": "ydsk", "released_at": null })),
+        ];
+
+        let from = Date {
+            year: 2024,
+            month: 8,
+            day: 1,
+        };
+        let calendar = release_calendar(&sets, from);
+        let codes: Vec<&str> = calendar
+            .releases()
+            .iter()
+            .map(|set| set.code.as_str())
+            .collect();
+        assert_eq!(vec!["blb", "dsk"], codes);
+
+        let ics = calendar.to_ics();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20240802\r\nSUMMARY:Bloomburrow (BLB)\r\n"));
+        assert!(ics.contains("SUMMARY:Duskmourn: House of Horror (DSK)\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    }
+
+    #[test]
+    fn test_fold_and_escape() {
+        use super::{escape, push_folded};
+
+        assert_eq!(r"a\, b\; c\\", escape(r"a, b; c\"));
+
+        let mut ics = String::new();
+        push_folded(&mut ics, &"x".repeat(80));
+        assert_eq!(format!("{}\r\n {}\r\n", "x".repeat(75), "x".repeat(5)), ics);
+    }
+}
@@ -0,0 +1,206 @@
+//! Card usage statistics across many decklists, the building blocks of metagame reports.
+
+use crate::deck::Deck;
+use crate::export::write_record;
+use crate::names::normalize;
+use std::collections::HashMap;
+
+/// How one card is played across a set of decks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardCount {
+    /// The card's name, as first written in the decks.
+    pub name: String,
+
+    /// How many decks play the card anywhere.
+    pub decks: u32,
+
+    /// How many decks play the card in the main deck.
+    pub main_decks: u32,
+
+    /// How many decks play the card in the sideboard.
+    pub sideboard_decks: u32,
+
+    /// Total copies across every main deck.
+    pub main_copies: u32,
+
+    /// Total copies across every sideboard.
+    pub sideboard_copies: u32,
+}
+
+impl CardCount {
+    /// Total copies across every deck, main deck and sideboard.
+    pub fn copies(&self) -> u32 {
+        self.main_copies + self.sideboard_copies
+    }
+
+    /// The average number of copies in the decks that play the card.
+    pub fn average_copies(&self) -> f64 {
+        f64::from(self.copies()) / f64::from(self.decks.max(1))
+    }
+}
+
+/// Per-card counts across a set of decks.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CardCounts {
+    /// How many decks were counted.
+    pub decks: u32,
+
+    /// One count per card, most played first, then by name.
+    pub cards: Vec<CardCount>,
+}
+
+impl CardCounts {
+    /// The share of decks playing `card`, from 0 to 1.
+    pub fn play_rate(&self, card: &CardCount) -> f64 {
+        f64::from(card.decks) / f64::from(self.decks.max(1))
+    }
+
+    /// The counts as CSV with a header row, one card per line in the same order as `cards`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        write_record(
+            &mut csv,
+            ',',
+            &[
+                "Name",
+                "Decks",
+                "Play Rate",
+                "Average Copies",
+                "Main Decks",
+                "Main Copies",
+                "Sideboard Decks",
+                "Sideboard Copies",
+            ],
+        );
+
+        for card in &self.cards {
+            write_record(
+                &mut csv,
+                ',',
+                &[
+                    &card.name,
+                    &card.decks.to_string(),
+                    &format!("{:.3}", self.play_rate(card)),
+                    &format!("{:.2}", card.average_copies()),
+                    &card.main_decks.to_string(),
+                    &card.main_copies.to_string(),
+                    &card.sideboard_decks.to_string(),
+                    &card.sideboard_copies.to_string(),
+                ],
+            );
+        }
+
+        csv
+    }
+}
+
+/// Counts how every card is played across `decks`. Names are compared after normalization, so `Aether Vial` and
+/// `Æther Vial` count as one card.
+pub fn card_counts(decks: &[Deck]) -> CardCounts {
+    let mut counts: Vec<CardCount> = Vec::new();
+    let mut indices: HashMap<String, usize> = HashMap::new();
+
+    for deck in decks {
+        let mut seen: HashMap<usize, (bool, bool)> = HashMap::new();
+        for (sideboard, entries) in [(false, &deck.main), (true, &deck.sideboard)].iter() {
+            for entry in entries.iter() {
+                let index = *indices.entry(normalize(&entry.name)).or_insert_with(|| {
+                    counts.push(CardCount {
+                        name: entry.name.trim().to_string(),
+                        decks: 0,
+                        main_decks: 0,
+                        sideboard_decks: 0,
+                        main_copies: 0,
+                        sideboard_copies: 0,
+                    });
+                    counts.len() - 1
+                });
+
+                let (in_main, in_sideboard) = seen.entry(index).or_insert((false, false));
+                if *sideboard {
+                    counts[index].sideboard_copies += entry.quantity;
+                    *in_sideboard = true;
+                } else {
+                    counts[index].main_copies += entry.quantity;
+                    *in_main = true;
+                }
+            }
+        }
+
+        for (index, (in_main, in_sideboard)) in seen {
+            let count = &mut counts[index];
+            count.decks += 1;
+            count.main_decks += u32::from(in_main);
+            count.sideboard_decks += u32::from(in_sideboard);
+        }
+    }
+
+    counts.sort_by(|a, b| b.decks.cmp(&a.decks).then_with(|| a.name.cmp(&b.name)));
+    CardCounts {
+        decks: decks.len() as u32,
+        cards: counts,
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_card_counts() {
+        use super::card_counts;
+        use crate::deck::{Deck, DeckEntry};
+
+        let decks = vec![
+            Deck {
+                name: None,
+                main: vec![
+                    DeckEntry::new(4, "Lightning Bolt"),
+                    DeckEntry::new(2, "Aether Vial"),
+                ],
+                sideboard: vec![DeckEntry::new(2, "Smash to Smithereens")],
+            },
+            Deck {
+                name: None,
+                main: vec![DeckEntry::new(3, "Lightning Bolt")],
+                sideboard: vec![
+                    DeckEntry::new(1, "Lightning Bolt"),
+                    DeckEntry::new(1, "Æther Vial"),
+                ],
+            },
+        ];
+
+        let counts = card_counts(&decks);
+        assert_eq!(2, counts.decks);
+
+        let names: Vec<&str> = counts.cards.iter().map(|card| card.name.as_str()).collect();
+        assert_eq!(
+            vec!["Aether Vial", "Lightning Bolt", "Smash to Smithereens"],
+            names
+        );
+
+        let bolt = &counts.cards[1];
+        assert_eq!(
+            (2, 2, 1),
+            (bolt.decks, bolt.main_decks, bolt.sideboard_decks)
+        );
+        assert_eq!((7, 1), (bolt.main_copies, bolt.sideboard_copies));
+        assert_eq!(4.0, bolt.average_copies());
+        assert_eq!(0.5, counts.play_rate(&counts.cards[2]));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        use super::card_counts;
+        use crate::deck::{Deck, DeckEntry};
+
+        let deck = Deck {
+            name: None,
+            main: vec![DeckEntry::new(4, "Fire // Ice")],
+            sideboard: Vec::new(),
+        };
+
+        assert_eq!(
+            "Name,Decks,Play Rate,Average Copies,Main Decks,Main Copies,Sideboard Decks,Sideboard Copies\r\n\
+             Fire // Ice,1,1.000,4.00,1,4,0,0\r\n",
+            card_counts(&[deck]).to_csv()
+        );
+    }
+}
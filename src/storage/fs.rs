@@ -0,0 +1,255 @@
+use super::Storage;
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Encoded keys longer than this are shortened, staying well under the 255-byte file name limit most file systems
+/// have, with room left for a temporary file's suffix.
+const MAX_NAME: usize = 200;
+
+/// How much of a long key's encoding a shortened file name keeps.
+const PREFIX_LEN: usize = 120;
+
+/// Numbers each write's temporary file, so concurrent writes from one process don't share one.
+static TEMPORARY: AtomicU64 = AtomicU64::new(0);
+
+/// Storage in a directory, one file per key. File names are the keys with anything other than ASCII letters, digits,
+/// `-` and `_` percent-encoded, so any key is safe to use. Keys whose encoding is too long for a file name are stored
+/// under a prefix of it and a hash of the key, with the full key on the file's first line. Writes go through a
+/// temporary file and a rename, so readers never see a partly written value.
+#[derive(Clone, Debug)]
+pub struct FsStorage {
+    root: PathBuf,
+}
+
+impl FsStorage {
+    /// Storage in `root`, which is created if it doesn't exist.
+    pub fn new<P: AsRef<Path>>(root: P) -> io::Result<Self> {
+        fs::create_dir_all(root.as_ref())?;
+        Ok(FsStorage {
+            root: root.as_ref().to_path_buf(),
+        })
+    }
+
+    /// The directory the files are kept in.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.root.join(name(key))
+    }
+
+    /// The key stored in the shortened file `name`, from its first line.
+    fn hashed_key(&self, name: &str) -> io::Result<Option<String>> {
+        let contents = fs::read(self.root.join(name))?;
+        Ok(split_header(&contents)
+            .and_then(|(header, _)| std::str::from_utf8(header).ok())
+            .and_then(decode))
+    }
+}
+
+impl Storage for FsStorage {
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        match fs::read(self.path(key)) {
+            Ok(value) if is_hashed(&name(key)) => Ok(split_header(&value)
+                .filter(|(header, _)| *header == encode(key).as_bytes())
+                .map(|(_, value)| value.to_vec())),
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+        let name = name(key);
+        let path = self.root.join(&name);
+        let temporary = self.root.join(format!(
+            "{}.tmp{}-{}",
+            name,
+            std::process::id(),
+            TEMPORARY.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let mut file = fs::File::create(&temporary)?;
+        if is_hashed(&name) {
+            file.write_all(encode(key).as_bytes())?;
+            file.write_all(b"\n")?;
+        }
+        file.write_all(value)?;
+        file.sync_all()?;
+        fs::rename(&temporary, &path)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<bool> {
+        match fs::remove_file(self.path(key)) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn scan(&self, prefix: &str) -> io::Result<Vec<String>> {
+        let mut keys = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let name = entry?.file_name();
+            let key = match name.to_str() {
+                Some(name) if is_hashed(name) => self.hashed_key(name)?,
+                Some(name) => decode(name),
+                None => None,
+            };
+            if let Some(key) = key {
+                if key.starts_with(prefix) {
+                    keys.push(key);
+                }
+            }
+        }
+
+        keys.sort();
+        Ok(keys)
+    }
+}
+
+/// The file name for `key`: its encoding, or if that's too long, a prefix of it, `~` and a hash of the key.
+fn name(key: &str) -> String {
+    let encoded = encode(key);
+    if encoded.len() <= MAX_NAME {
+        return encoded;
+    }
+
+    // Don't cut an escape in half.
+    let mut end = PREFIX_LEN;
+    if let Some(escape) = encoded[end - 2..end].find('%') {
+        end -= 2 - escape;
+    }
+    let digest = Sha1::digest(key.as_bytes());
+    let hash: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}~{}", &encoded[..end], hash)
+}
+
+/// Whether `name` is a shortened file name rather than a temporary file or a full encoding.
+fn is_hashed(name: &str) -> bool {
+    name.contains('~') && !name.contains('.')
+}
+
+/// Splits a shortened file's contents into the encoded key on its first line and the value after it.
+fn split_header(contents: &[u8]) -> Option<(&[u8], &[u8])> {
+    let newline = contents.iter().position(|byte| *byte == b'\n')?;
+    Some((&contents[..newline], &contents[newline + 1..]))
+}
+
+fn encode(key: &str) -> String {
+    let mut name = String::with_capacity(key.len());
+    for byte in key.bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+            name.push(byte as char);
+        } else {
+            name.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    name
+}
+
+/// Reverses `encode`, or returns `None` for files that aren't keys, such as in-progress temporary files.
+fn decode(name: &str) -> Option<String> {
+    let bytes = name.as_bytes();
+    let mut key = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+                key.push(u8::from_str_radix(hex, 16).ok()?);
+                i += 3;
+            }
+            byte if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' => {
+                key.push(byte);
+                i += 1;
+            }
+            _ => return None,
+        }
+    }
+
+    String::from_utf8(key).ok()
+}
+
+mod tests {
+    #[test]
+    fn test_fs_storage() {
+        use super::FsStorage;
+        use crate::storage::check_storage;
+
+        let root =
+            std::env::temp_dir().join(format!("scryfall-storage-test-{}", std::process::id()));
+        let storage = FsStorage::new(&root).unwrap();
+        check_storage(&storage);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_long_keys() {
+        use super::FsStorage;
+        use crate::storage::Storage;
+
+        let root =
+            std::env::temp_dir().join(format!("scryfall-storage-long-test-{}", std::process::id()));
+        let storage = FsStorage::new(&root).unwrap();
+
+        let long = format!("cards/{}", "a".repeat(300));
+        let other = format!("cards/{}b", "a".repeat(299));
+        let escaped = format!("names/{}", "ü".repeat(150));
+        storage.put(&long, b"long").unwrap();
+        storage.put(&other, b"other").unwrap();
+        storage.put(&escaped, b"escaped").unwrap();
+
+        assert_eq!(Some(b"long".to_vec()), storage.get(&long).unwrap());
+        assert_eq!(Some(b"other".to_vec()), storage.get(&other).unwrap());
+        assert_eq!(Some(b"escaped".to_vec()), storage.get(&escaped).unwrap());
+        assert_eq!(vec![long.as_str(), &other], storage.scan("cards/").unwrap());
+        assert_eq!(vec![escaped.as_str()], storage.scan("names/").unwrap());
+        for entry in std::fs::read_dir(&root).unwrap() {
+            assert!(entry.unwrap().file_name().len() <= 200);
+        }
+
+        assert!(storage.remove(&long).unwrap());
+        assert_eq!(None, storage.get(&long).unwrap());
+        assert_eq!(vec![other.as_str()], storage.scan("cards/").unwrap());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_puts() {
+        use super::FsStorage;
+        use crate::storage::Storage;
+
+        let root = std::env::temp_dir().join(format!(
+            "scryfall-storage-concurrent-test-{}",
+            std::process::id()
+        ));
+        let storage = FsStorage::new(&root).unwrap();
+
+        let writers: Vec<_> = (0..8)
+            .map(|i| {
+                let storage = storage.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..20 {
+                        storage.put("cards/a", format!("{}", i).as_bytes()).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        assert!(storage.get("cards/a").unwrap().is_some());
+        assert_eq!(vec!["cards/a"], storage.scan("").unwrap());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,55 @@
+use super::Storage;
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::Mutex;
+
+/// Storage held in memory, which is lost when dropped. Useful for tests and short-lived processes.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    entries: Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl MemoryStorage {
+    /// An empty store.
+    pub fn new() -> Self {
+        MemoryStorage::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        Ok(self.entries.lock().unwrap().get(key).cloned())
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.to_vec());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> io::Result<bool> {
+        Ok(self.entries.lock().unwrap().remove(key).is_some())
+    }
+
+    fn scan(&self, prefix: &str) -> io::Result<Vec<String>> {
+        let entries = self.entries.lock().unwrap();
+        Ok(entries
+            .range(prefix.to_string()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_memory_storage() {
+        use super::MemoryStorage;
+        use crate::storage::check_storage;
+
+        check_storage(&MemoryStorage::new());
+    }
+}
@@ -0,0 +1,89 @@
+//! Pluggable byte storage for anything the crate persists, so embedders can swap in their own backend.
+
+mod fs;
+mod memory;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+pub use self::fs::FsStorage;
+pub use self::memory::MemoryStorage;
+#[cfg(feature = "sqlite")]
+pub use self::sqlite::SqliteStorage;
+
+use std::io;
+
+/// A key-value store of byte strings. Keys are arbitrary strings; callers namespace them with prefixes such as
+/// `images/` so that several users can share one store and `scan` can list each one's keys.
+///
+/// Methods take `&self` so one store can be shared between threads; implementations handle their own locking.
+pub trait Storage: Send + Sync {
+    /// The value stored under `key`, if any.
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>>;
+
+    /// Stores `value` under `key`, replacing any previous value.
+    fn put(&self, key: &str, value: &[u8]) -> io::Result<()>;
+
+    /// Removes `key`, returning whether it was present.
+    fn remove(&self, key: &str) -> io::Result<bool>;
+
+    /// Every key starting with `prefix`, in sorted order.
+    fn scan(&self, prefix: &str) -> io::Result<Vec<String>>;
+}
+
+impl<S: Storage + ?Sized> Storage for Box<S> {
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+        (**self).put(key, value)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<bool> {
+        (**self).remove(key)
+    }
+
+    fn scan(&self, prefix: &str) -> io::Result<Vec<String>> {
+        (**self).scan(prefix)
+    }
+}
+
+impl<S: Storage + ?Sized> Storage for std::sync::Arc<S> {
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+        (**self).put(key, value)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<bool> {
+        (**self).remove(key)
+    }
+
+    fn scan(&self, prefix: &str) -> io::Result<Vec<String>> {
+        (**self).scan(prefix)
+    }
+}
+
+/// Exercises the `Storage` contract, for each implementation's tests.
+#[cfg(test)]
+pub(crate) fn check_storage(storage: &dyn Storage) {
+    assert_eq!(None, storage.get("cards/a").unwrap());
+
+    storage.put("cards/b", b"bee").unwrap();
+    storage.put("cards/a", b"first").unwrap();
+    storage.put("cards/a", b"ay").unwrap();
+    storage.put("images/a", &[0, 159, 255]).unwrap();
+    storage.put("weird key/../ünï", b"").unwrap();
+
+    assert_eq!(Some(b"ay".to_vec()), storage.get("cards/a").unwrap());
+    assert_eq!(Some(vec![0, 159, 255]), storage.get("images/a").unwrap());
+    assert_eq!(Some(Vec::new()), storage.get("weird key/../ünï").unwrap());
+    assert_eq!(vec!["cards/a", "cards/b"], storage.scan("cards/").unwrap());
+    assert_eq!(4, storage.scan("").unwrap().len());
+
+    assert!(storage.remove("cards/a").unwrap());
+    assert!(!storage.remove("cards/a").unwrap());
+    assert_eq!(vec!["cards/b"], storage.scan("cards/").unwrap());
+}
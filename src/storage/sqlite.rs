@@ -0,0 +1,100 @@
+use super::Storage;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Storage in a single SQLite table, for when one file is easier to manage than a directory of them.
+#[derive(Debug)]
+pub struct SqliteStorage {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteStorage {
+    /// Storage in the database at `path`, which is created if it doesn't exist.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::with_connection(Connection::open(path).map_err(to_io)?)
+    }
+
+    /// Storage in a private in-memory database.
+    pub fn in_memory() -> io::Result<Self> {
+        Self::with_connection(Connection::open_in_memory().map_err(to_io)?)
+    }
+
+    fn with_connection(connection: Connection) -> io::Result<Self> {
+        connection
+            .execute(
+                "CREATE TABLE IF NOT EXISTS storage (key TEXT PRIMARY KEY NOT NULL, value BLOB NOT NULL)",
+                [],
+            )
+            .map_err(to_io)?;
+
+        Ok(SqliteStorage {
+            connection: Mutex::new(connection),
+        })
+    }
+}
+
+impl Storage for SqliteStorage {
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        self.connection
+            .lock()
+            .unwrap()
+            .query_row(
+                "SELECT value FROM storage WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(to_io)
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+        self.connection
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO storage (key, value) VALUES (?1, ?2) \
+                 ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+                params![key, value],
+            )
+            .map(|_| ())
+            .map_err(to_io)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<bool> {
+        self.connection
+            .lock()
+            .unwrap()
+            .execute("DELETE FROM storage WHERE key = ?1", params![key])
+            .map(|removed| removed > 0)
+            .map_err(to_io)
+    }
+
+    fn scan(&self, prefix: &str) -> io::Result<Vec<String>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection
+            .prepare("SELECT key FROM storage WHERE substr(key, 1, length(?1)) = ?1 ORDER BY key")
+            .map_err(to_io)?;
+        let keys = statement
+            .query_map(params![prefix], |row| row.get(0))
+            .map_err(to_io)?
+            .collect::<Result<Vec<String>, _>>()
+            .map_err(to_io);
+        keys
+    }
+}
+
+fn to_io(err: rusqlite::Error) -> io::Error {
+    io::Error::other(err)
+}
+
+mod tests {
+    #[test]
+    fn test_sqlite_storage() {
+        use super::SqliteStorage;
+        use crate::storage::check_storage;
+
+        check_storage(&SqliteStorage::in_memory().unwrap());
+    }
+}
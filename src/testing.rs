@@ -0,0 +1,327 @@
+//! Shared fixtures for unit tests across the crate, and golden-file checks over the JSON under `testdata/`.
+//!
+//! `testdata/cards` holds one hand-written card per kind of card the model treats specially, shaped like API
+//! responses but with illustrative IDs, prices and links. `testdata/bulk` holds samples of real cards from Scryfall's
+//! bulk data, as JSON arrays. Every card in either must survive a round trip through the model without losing a field,
+//! and the bulk samples must between them cover every frame and set type. To refresh the sample from a `default_cards`
+//! bulk file, keep a few cards of each layout, frame and set type:
+//!
+//! ```text
+//! jq '[group_by([.layout, .frame, .set_type])[] | .[:8]] | flatten' default-cards.json > testdata/bulk/sample.json
+//! ```
+
+use crate::types::card::Card;
+use crate::types::set::Set;
+use serde_json::Value;
+
+const LIGHTNING_BOLT: &str = include_str!("../testdata/cards/lightning_bolt.json");
+const SOL_RING_REVERSIBLE: &str = include_str!("../testdata/cards/sol_ring_reversible.json");
+const BLOOMBURROW: &str = include_str!("../testdata/sets/bloomburrow.json");
+
+/// A fully-populated Lightning Bolt printing.
+pub fn card() -> Card {
+    card_with(serde_json::json!({}))
+}
+
+/// A Lightning Bolt printing with the top-level fields in `overrides` replaced.
+pub fn card_with(overrides: Value) -> Card {
+    serde_json::from_value(card_json(overrides)).unwrap()
+}
+
+/// The raw JSON of `card_with`, for tests that decode it themselves.
+pub fn card_json(overrides: Value) -> Value {
+    fixture(LIGHTNING_BOLT, overrides)
+}
+
+/// A transforming double-faced card, Delver of Secrets // Insectile Aberration, built on the Lightning Bolt fixture.
+pub fn double_faced_card() -> Card {
+    card_with(serde_json::json!({
+        "name": "Delver of Secrets // Insectile Aberration",
+        "mana_cost": null,
+        "type_line": "Creature — Human Wizard // Creature — Human Insect",
+        "oracle_text": null,
+        "image_uris": null,
+        "card_faces": [
+            {
+                "name": "Delver of Secrets",
+                "mana_cost": "{U}",
+                "type_line": "Creature — Human Wizard",
+                "oracle_text": "At the beginning of your upkeep, look at the top card of your library. You may reveal that card. If an instant or sorcery card is revealed this way, transform Delver of Secrets.",
+                "power": "1",
+                "toughness": "1",
+                "image_uris": {"art_crop": "https://cards.scryfall.io/art_crop/front/delver.jpg"},
+            },
+            {
+                "name": "Insectile Aberration",
+                "mana_cost": "",
+                "type_line": "Creature — Human Insect",
+                "oracle_text": "Flying",
+                "power": "3",
+                "toughness": "2",
+                "image_uris": {"art_crop": "https://cards.scryfall.io/art_crop/back/delver.jpg"},
+            },
+        ],
+    }))
+}
+
+/// A reversible Sol Ring printing. It has no top-level oracle ID; each face carries its own.
+pub fn reversible_card() -> Card {
+    serde_json::from_str(SOL_RING_REVERSIBLE).unwrap()
+}
+
+/// The reversible Sol Ring fixture, with both faces given the oracle ID `oracle_id`, standing in for another card.
+pub fn reversible_card_of(oracle_id: &str) -> Card {
+    let mut card = reversible_card();
+    for face in card.card_faces.iter_mut().flatten() {
+        face.oracle_id = Some(oracle_id.to_string());
+    }
+    card
+}
+
+/// The Bloomburrow set object.
+pub fn set() -> Set {
+    set_with(serde_json::json!({}))
+}
+
+/// The Bloomburrow set object with the top-level fields in `overrides` replaced.
+pub fn set_with(overrides: Value) -> Set {
+    serde_json::from_value(set_json(overrides)).unwrap()
+}
+
+/// The JSON for the Bloomburrow set object with the top-level fields in `overrides` replaced, for mocked responses.
+pub fn set_json(overrides: Value) -> Value {
+    fixture(BLOOMBURROW, overrides)
+}
+
+fn fixture(json: &str, overrides: Value) -> Value {
+    let mut value: Value = serde_json::from_str(json).unwrap();
+    for (key, field) in overrides.as_object().unwrap() {
+        value[key.as_str()] = field.clone();
+    }
+
+    value
+}
+
+mod tests {
+    #[cfg(test)]
+    use serde_json::Value;
+
+    /// Every file in `testdata/<dir>`, with its name. A missing directory has no files.
+    #[cfg(test)]
+    fn golden_files(dir: &str) -> Vec<(String, String)> {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join(dir);
+        let entries = match std::fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return vec![],
+            Err(err) => panic!("{}", err),
+        };
+        let mut files: Vec<(String, String)> = entries
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                let name = path.file_name().unwrap().to_string_lossy().to_string();
+                (name, std::fs::read_to_string(&path).unwrap())
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// Every card in `testdata/cards` and the samples in `testdata/bulk`, labeled with where it came from.
+    #[cfg(test)]
+    fn golden_cards() -> Vec<(String, Value)> {
+        let mut cards: Vec<(String, Value)> = golden_files("cards")
+            .into_iter()
+            .map(|(name, json)| (name, serde_json::from_str(&json).unwrap()))
+            .collect();
+        for (name, json) in golden_files("bulk") {
+            let sample: Vec<Value> = serde_json::from_str(&json).unwrap();
+            cards.extend(
+                sample
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, card)| (format!("{}[{}] {}", name, i, card["name"]), card)),
+            );
+        }
+        cards
+    }
+
+    /// `value` without its null fields. Scryfall leaves out fields that don't apply, while the model writes them as null.
+    #[cfg(test)]
+    fn without_nulls(value: Value) -> Value {
+        match value {
+            Value::Object(fields) => Value::Object(
+                fields
+                    .into_iter()
+                    .filter(|(_, field)| !field.is_null())
+                    .map(|(key, field)| (key, without_nulls(field)))
+                    .collect(),
+            ),
+            Value::Array(items) => Value::Array(items.into_iter().map(without_nulls).collect()),
+            value => value,
+        }
+    }
+
+    /// Fields the model deliberately doesn't keep: `object` only says which type a JSON object is.
+    #[cfg(test)]
+    const IGNORED: &[&str] = &["object"];
+
+    /// Fields the model keeps as sets, so their order isn't preserved.
+    #[cfg(test)]
+    const UNORDERED: &[&str] = &["colors", "color_identity", "color_indicator"];
+
+    /// Adds a line to `differences` for every path where `expected` and `actual` disagree.
+    #[cfg(test)]
+    fn diff(path: &str, expected: &Value, actual: &Value, differences: &mut Vec<String>) {
+        match (expected, actual) {
+            (Value::Object(expected), Value::Object(actual)) => {
+                for (key, field) in expected {
+                    if IGNORED.contains(&key.as_str()) {
+                        continue;
+                    }
+                    let path = format!("{}.{}", path, key);
+                    if let (true, Value::Array(items), Some(Value::Array(other))) =
+                        (UNORDERED.contains(&key.as_str()), field, actual.get(key))
+                    {
+                        let mut items: Vec<String> = items.iter().map(Value::to_string).collect();
+                        let mut other: Vec<String> = other.iter().map(Value::to_string).collect();
+                        items.sort();
+                        other.sort();
+                        if items != other {
+                            differences.push(format!("{}: {:?} became {:?}", path, items, other));
+                        }
+                        continue;
+                    }
+                    match actual.get(key) {
+                        Some(other) => diff(&path, field, other, differences),
+                        None => differences.push(format!("{} is missing", path)),
+                    }
+                }
+                for key in actual
+                    .keys()
+                    .filter(|key| !expected.contains_key(*key) && !IGNORED.contains(&key.as_str()))
+                {
+                    differences.push(format!("{}.{} was added", path, key));
+                }
+            }
+            (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+                for (i, (field, other)) in expected.iter().zip(actual).enumerate() {
+                    diff(&format!("{}[{}]", path, i), field, other, differences);
+                }
+            }
+            (Value::Number(expected), Value::Number(actual))
+                if expected.as_f64() == actual.as_f64() => {}
+            _ if expected == actual => {}
+            _ => differences.push(format!("{}: {} became {}", path, expected, actual)),
+        }
+    }
+
+    #[test]
+    fn test_golden_cards() {
+        use crate::types::card::Card;
+
+        let cards = golden_cards();
+        assert!(!cards.is_empty());
+        let mut differences = Vec::new();
+        for (name, input) in cards {
+            let card: Card = match serde_json::from_value(input.clone()) {
+                Ok(card) => card,
+                Err(err) => {
+                    differences.push(format!("{} failed to parse: {}", name, err));
+                    continue;
+                }
+            };
+
+            // Compare the JSON rather than the parsed cards, so a field the model drops or renames fails the test.
+            let output = serde_json::to_value(card).unwrap();
+            diff(
+                &name,
+                &without_nulls(input),
+                &without_nulls(output),
+                &mut differences,
+            );
+        }
+        assert!(
+            differences.is_empty(),
+            "changed in a round trip:\n{}",
+            differences.join("\n")
+        );
+    }
+
+    #[test]
+    #[ignore = "needs a bulk data sample in testdata/bulk, see the module docs"]
+    fn test_golden_coverage() {
+        use crate::types::card::{Card, Frame};
+        use crate::types::set::SetType;
+
+        let cards: Vec<Card> = golden_files("bulk")
+            .iter()
+            .flat_map(|(_, json)| serde_json::from_str::<Vec<Card>>(json).unwrap())
+            .collect();
+        let mut missing: Vec<String> = Vec::new();
+        for frame in Frame::ALL {
+            if !cards.iter().any(|card| card.frame == *frame) {
+                missing.push(format!("frame {}", frame));
+            }
+        }
+        for set_type in SetType::ALL {
+            if !cards.iter().any(|card| card.set_type == set_type.as_str()) {
+                missing.push(format!("set type {}", set_type));
+            }
+        }
+        for card in &cards {
+            if card.set_type.parse::<SetType>().is_err() {
+                missing.push(format!("a model for set type {}", card.set_type));
+            }
+        }
+        missing.sort();
+        missing.dedup();
+        assert!(
+            missing.is_empty(),
+            "no golden cards for:\n{}",
+            missing.join("\n")
+        );
+    }
+
+    #[test]
+    fn test_golden_layouts() {
+        use crate::types::card::{Card, Layout};
+
+        let layouts: Vec<Layout> = golden_cards()
+            .into_iter()
+            .map(|(_, json)| serde_json::from_value::<Card>(json).unwrap().layout)
+            .collect();
+        for layout in &[
+            Layout::Normal,
+            Layout::Transform,
+            Layout::ModalDfc,
+            Layout::Split,
+            Layout::Flip,
+            Layout::Meld,
+            Layout::Adventure,
+            Layout::Token,
+            Layout::ArtSeries,
+            Layout::ReversibleCard,
+        ] {
+            assert!(layouts.contains(layout), "no golden file for {}", layout);
+        }
+    }
+
+    #[test]
+    fn test_golden_sets() {
+        use crate::types::set::Set;
+
+        let files = golden_files("sets");
+        assert!(!files.is_empty());
+        for (name, json) in files {
+            let set: Result<Set, _> = serde_json::from_str(&json);
+            assert!(
+                set.is_ok(),
+                "{} failed to parse: {}",
+                name,
+                set.err().unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,503 @@
+//! An interactive card browser for the terminal, enabled by the `tui` feature, and opened by `scryfall browse` when the
+//! `cli` feature is on too. Type a query in Scryfall's search syntax, then scroll through the results with each card's
+//! text shown beside them.
+//!
+//! Results are fetched a page at a time, as the selection nears the end of what's loaded, and every search's loaded
+//! pages are kept, so going back to an earlier query doesn't fetch anything again. Card images aren't shown.
+//!
+//! Keys: `/` edits the query and Enter searches; the arrow keys, `j` and `k`, and Page Up and Page Down move the
+//! selection; `q` or Esc quits.
+
+use crate::client::blocking::Client;
+use crate::client::PageCursor;
+use crate::types::card::Card;
+use crate::types::search::SearchParams;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use std::collections::HashMap;
+use std::io;
+
+/// How close to the end of the loaded results the selection gets before the next page is fetched.
+const LOOKAHEAD: usize = 10;
+
+/// How far Page Up and Page Down move the selection.
+const PAGE_STEP: usize = 20;
+
+/// The state of the browser: the query being typed, the searches made so far and where the selection is.
+#[derive(Debug)]
+pub struct Browser {
+    client: Client,
+    input: String,
+    editing: bool,
+    query: Option<String>,
+    searches: HashMap<String, Results>,
+    selected: usize,
+    status: String,
+}
+
+/// The pages of a search loaded so far.
+#[derive(Debug)]
+struct Results {
+    cards: Vec<Card>,
+    next: Option<PageCursor>,
+    total: Option<u32>,
+}
+
+impl Browser {
+    /// A browser searching with `client`, waiting for a query.
+    pub fn new(client: Client) -> Self {
+        Browser {
+            client,
+            input: String::new(),
+            editing: true,
+            query: None,
+            searches: HashMap::new(),
+            selected: 0,
+            status: String::new(),
+        }
+    }
+
+    /// Shows the results for `query`, fetching their first page unless it was searched for before, and selects the
+    /// first card.
+    pub fn search(&mut self, query: &str) {
+        self.input = query.to_string();
+        self.editing = false;
+        self.selected = 0;
+        self.status.clear();
+        if !self.searches.contains_key(query) {
+            match self.client.search(query, &SearchParams::default()) {
+                Ok(list) => {
+                    let results = Results {
+                        next: PageCursor::after(&list),
+                        total: list.total_cards,
+                        cards: list.data,
+                    };
+                    self.searches.insert(query.to_string(), results);
+                }
+                Err(err) => {
+                    self.query = None;
+                    self.status = err.to_string();
+                    return;
+                }
+            }
+        }
+        self.query = Some(query.to_string());
+        self.load_ahead();
+    }
+
+    /// The cards loaded so far for the current search.
+    pub fn cards(&self) -> &[Card] {
+        self.results().map_or(&[], |results| &results.cards)
+    }
+
+    /// The selected card, if there are any results.
+    pub fn selected(&self) -> Option<&Card> {
+        self.cards().get(self.selected)
+    }
+
+    /// Acts on a key press. Returns `false` once the browser should close.
+    pub fn handle(&mut self, key: KeyEvent) -> bool {
+        if self.editing {
+            match key.code {
+                KeyCode::Enter if !self.input.trim().is_empty() => {
+                    let query = self.input.trim().to_string();
+                    self.search(&query);
+                }
+                KeyCode::Esc => self.editing = false,
+                KeyCode::Backspace => {
+                    self.input.pop();
+                }
+                KeyCode::Char(c) => self.input.push(c),
+                _ => {}
+            }
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('/') => self.editing = true,
+            KeyCode::Down | KeyCode::Char('j') => self.select(self.selected + 1),
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected.saturating_sub(1)),
+            KeyCode::PageDown => self.select(self.selected + PAGE_STEP),
+            KeyCode::PageUp => self.select(self.selected.saturating_sub(PAGE_STEP)),
+            _ => {}
+        }
+        true
+    }
+
+    /// Draws the query, the results and the selected card's text onto `frame`.
+    pub fn draw(&self, frame: &mut Frame) {
+        let [input_area, main_area, status_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let [list_area, detail_area] =
+            Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)])
+                .areas(main_area);
+
+        let title = if self.editing {
+            "Search (Enter to search)"
+        } else {
+            "Search (/ to edit)"
+        };
+        frame.render_widget(
+            Paragraph::new(self.input.as_str()).block(Block::bordered().title(title)),
+            input_area,
+        );
+
+        let items: Vec<ListItem> = self
+            .cards()
+            .iter()
+            .map(|card| ListItem::new(card.name.as_str()))
+            .collect();
+        let mut state = ListState::default().with_selected(self.selected().map(|_| self.selected));
+        frame.render_stateful_widget(
+            List::new(items)
+                .block(Block::bordered().title("Results"))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            list_area,
+            &mut state,
+        );
+
+        let detail = self.selected().map(card_lines).unwrap_or_default();
+        frame.render_widget(
+            Paragraph::new(detail)
+                .block(Block::bordered())
+                .wrap(Wrap { trim: false }),
+            detail_area,
+        );
+
+        let status = match (self.status.is_empty(), self.results()) {
+            (true, Some(results)) => {
+                let total = results
+                    .total
+                    .map_or(results.cards.len(), |total| total as usize);
+                format!("{} of {}  ·  q to quit", self.selected + 1, total)
+            }
+            _ => self.status.clone(),
+        };
+        frame.render_widget(Paragraph::new(status), status_area);
+    }
+
+    /// Draws to `terminal` and handles key presses until the browser is closed.
+    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !self.handle(key) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// The current search's results.
+    fn results(&self) -> Option<&Results> {
+        self.searches.get(self.query.as_ref()?)
+    }
+
+    /// Moves the selection to `index`, or the last card loaded if it's past the end, then fetches more if needed.
+    fn select(&mut self, index: usize) {
+        self.selected = index.min(self.cards().len().saturating_sub(1));
+        self.load_ahead();
+    }
+
+    /// Fetches the current search's next page while the selection is within [`LOOKAHEAD`] cards of the end of what's
+    /// loaded.
+    fn load_ahead(&mut self) {
+        let query = match &self.query {
+            Some(query) => query,
+            None => return,
+        };
+        let results = match self.searches.get_mut(query) {
+            Some(results) => results,
+            None => return,
+        };
+        while self.selected + LOOKAHEAD >= results.cards.len() {
+            let cursor = match &results.next {
+                Some(cursor) => cursor,
+                None => return,
+            };
+            match self.client.page::<Card>(cursor) {
+                Ok(list) => {
+                    results.next = PageCursor::after(&list);
+                    results.cards.extend(list.data);
+                }
+                Err(err) => {
+                    self.status = err.to_string();
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Opens the browser in the terminal, searching for `query` first if it's given, and restores the terminal once it's
+/// closed.
+pub fn run(client: Client, query: Option<&str>) -> io::Result<()> {
+    let mut browser = Browser::new(client);
+    if let Some(query) = query {
+        browser.search(query);
+    }
+
+    let mut terminal = ratatui::init();
+    let result = browser.run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+/// The text of `card` as shown beside the results, a face at a time for cards with several, then where it's printed.
+fn card_lines(card: &Card) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    match &card.card_faces {
+        Some(faces) => {
+            for (i, face) in faces.iter().enumerate() {
+                if i > 0 {
+                    lines.push(Line::from(""));
+                }
+                face_lines(
+                    &mut lines,
+                    Face {
+                        name: &face.name,
+                        mana_cost: &face.mana_cost,
+                        type_line: &face.type_line,
+                        oracle_text: face.oracle_text.as_deref(),
+                        flavor_text: face.flavor_text.as_deref(),
+                        power: face.power.as_deref(),
+                        toughness: face.toughness.as_deref(),
+                        loyalty: face.loyalty.as_deref(),
+                        defense: face.defense.as_deref(),
+                    },
+                );
+            }
+        }
+        None => face_lines(
+            &mut lines,
+            Face {
+                name: &card.name,
+                mana_cost: card.mana_cost.as_deref().unwrap_or(""),
+                type_line: &card.type_line,
+                oracle_text: card.oracle_text.as_deref(),
+                flavor_text: card.flavor_text.as_deref(),
+                power: card.power.as_deref(),
+                toughness: card.toughness.as_deref(),
+                loyalty: card.loyalty.as_deref(),
+                defense: card.defense.as_deref(),
+            },
+        ),
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!(
+        "{} #{}, {}",
+        card.set_name, card.collector_number, card.rarity
+    )));
+    lines
+}
+
+/// The parts of a card or one of its faces shown in the detail pane.
+struct Face<'a> {
+    name: &'a str,
+    mana_cost: &'a str,
+    type_line: &'a str,
+    oracle_text: Option<&'a str>,
+    flavor_text: Option<&'a str>,
+    power: Option<&'a str>,
+    toughness: Option<&'a str>,
+    loyalty: Option<&'a str>,
+    defense: Option<&'a str>,
+}
+
+/// Adds the lines for `face` to `lines`: its name and cost, its type line, its text, then its stats.
+fn face_lines(lines: &mut Vec<Line<'static>>, face: Face) {
+    lines.push(Line::styled(
+        format!("{} {}", face.name, face.mana_cost),
+        Style::default().add_modifier(Modifier::BOLD),
+    ));
+    lines.push(Line::from(face.type_line.to_string()));
+    if let Some(text) = face.oracle_text.filter(|text| !text.is_empty()) {
+        lines.push(Line::from(""));
+        lines.extend(text.lines().map(|line| Line::from(line.to_string())));
+    }
+    if let Some(text) = face.flavor_text.filter(|text| !text.is_empty()) {
+        lines.push(Line::from(""));
+        lines.extend(text.lines().map(|line| {
+            Line::styled(
+                line.to_string(),
+                Style::default().add_modifier(Modifier::ITALIC),
+            )
+        }));
+    }
+
+    let stats = match (face.power, face.toughness, face.loyalty, face.defense) {
+        (Some(power), Some(toughness), _, _) => Some(format!("{}/{}", power, toughness)),
+        (_, _, Some(loyalty), _) => Some(format!("Loyalty: {}", loyalty)),
+        (_, _, _, Some(defense)) => Some(format!("Defense: {}", defense)),
+        _ => None,
+    };
+    if let Some(stats) = stats {
+        lines.push(Line::from(""));
+        lines.push(Line::from(stats));
+    }
+}
+
+mod tests {
+    /// The list of `count` copies of Lightning Bolt, linking to `next_page` if it's given.
+    #[cfg(test)]
+    fn page(count: usize, next_page: Option<String>) -> serde_json::Value {
+        let bolt: serde_json::Value =
+            serde_json::from_str(include_str!("../testdata/cards/lightning_bolt.json")).unwrap();
+        serde_json::json!({
+            "object": "list",
+            "total_cards": 30,
+            "has_more": next_page.is_some(),
+            "next_page": next_page,
+            "data": vec![bolt; count],
+        })
+    }
+
+    #[cfg(test)]
+    fn press(code: ratatui::crossterm::event::KeyCode) -> ratatui::crossterm::event::KeyEvent {
+        ratatui::crossterm::event::KeyEvent::from(code)
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_pagination() {
+        use super::Browser;
+        use crate::client::blocking::Client;
+        use ratatui::crossterm::event::KeyCode;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(15, None)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "t:instant"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(
+                15,
+                Some(format!(
+                    "{}/cards/search?q=t%3Ainstant&page=2",
+                    server.uri()
+                )),
+            )))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        tokio::task::spawn_blocking(move || {
+            let mut browser = Browser::new(Client::with_base_url(&uri));
+            for c in "t:instant".chars() {
+                assert!(browser.handle(press(KeyCode::Char(c))));
+            }
+            browser.handle(press(KeyCode::Enter));
+            assert_eq!(15, browser.cards().len());
+
+            for _ in 0..5 {
+                browser.handle(press(KeyCode::Down));
+            }
+            assert_eq!(30, browser.cards().len());
+            browser.handle(press(KeyCode::PageDown));
+            browser.handle(press(KeyCode::PageDown));
+            assert_eq!(29, browser.selected);
+
+            browser.search("t:instant");
+            assert_eq!(0, browser.selected);
+            assert_eq!(30, browser.cards().len());
+            assert!(!browser.handle(press(KeyCode::Char('q'))));
+        })
+        .await
+        .unwrap();
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_draw() {
+        use super::Browser;
+        use crate::client::blocking::Client;
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(1, None)))
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        let screen = tokio::task::spawn_blocking(move || {
+            let mut browser = Browser::new(Client::with_base_url(&uri));
+            browser.search("bolt");
+            let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+            terminal.draw(|frame| browser.draw(frame)).unwrap();
+
+            let buffer = terminal.backend().buffer();
+            (0..buffer.area.height)
+                .map(|y| {
+                    (0..buffer.area.width)
+                        .map(|x| buffer[(x, y)].symbol())
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        })
+        .await
+        .unwrap();
+
+        assert!(screen[1].contains("bolt"));
+        assert!(screen[4].contains("│Lightning Bolt"));
+        assert!(screen[4].contains("│Lightning Bolt {R}"));
+        assert!(screen[5].contains("Instant"));
+        assert!(screen[7].contains("Lightning Bolt deals 3 damage to any target."));
+        assert!(screen[11].starts_with("1 of 30"));
+    }
+
+    #[cfg(test)]
+    #[tokio::test]
+    async fn test_search_error() {
+        use super::Browser;
+        use crate::client::blocking::Client;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "object": "error",
+                "code": "not_found",
+                "status": 404,
+                "details": "Your query didn't match any cards.",
+            })))
+            .mount(&server)
+            .await;
+
+        let uri = server.uri();
+        tokio::task::spawn_blocking(move || {
+            let mut browser = Browser::new(Client::with_base_url(&uri));
+            browser.search("t:nothing");
+            assert!(browser.cards().is_empty());
+            assert!(browser.selected().is_none());
+            assert!(browser.status.contains("didn't match any cards"));
+        })
+        .await
+        .unwrap();
+    }
+}
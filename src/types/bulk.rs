@@ -0,0 +1,67 @@
+use super::uri::Uri;
+use super::uuid::Uuid;
+use crate::bulk::BulkKind;
+use serde::Deserialize;
+
+/// Bulk Data objects: a description of one of the files Scryfall exports daily, and where to download it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct BulkData {
+    /// A unique ID for this bulk item.
+    pub id: Uuid,
+
+    /// Which bulk file this is.
+    #[serde(rename = "type")]
+    pub kind: BulkKind,
+
+    /// The time when this file was last updated, as an ISO 8601 timestamp.
+    pub updated_at: String,
+
+    /// The Scryfall API URI for this file.
+    pub uri: Uri,
+
+    /// A human-readable name for this file.
+    pub name: String,
+
+    /// A human-readable description for this file.
+    pub description: String,
+
+    /// The size of this file in bytes.
+    pub size: u64,
+
+    /// The URI that hosts this bulk file for fetching.
+    pub download_uri: Uri,
+
+    /// The MIME type of this file.
+    pub content_type: String,
+
+    /// The Content-Encoding encoding that will be used to transmit this file when you download it.
+    pub content_encoding: String,
+}
+
+mod tests {
+    #[test]
+    fn test_parse_bulk_data() {
+        use super::BulkData;
+        use crate::bulk::BulkKind;
+
+        let bulk_data: BulkData = serde_json::from_str(
+            r#"{
+                "object": "bulk_data",
+                "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+                "type": "oracle_cards",
+                "updated_at": "2024-08-01T09:02:49.367+00:00",
+                "uri": "https://api.scryfall.com/bulk-data/27bf3214-1271-490b-bdfe-c0be6c23d02e",
+                "name": "Oracle Cards",
+                "description": "A JSON file containing one Scryfall card object for each Oracle ID on Scryfall.",
+                "size": 160955958,
+                "download_uri": "https://data.scryfall.io/oracle-cards/oracle-cards-20240801090249.json",
+                "content_type": "application/json",
+                "content_encoding": "gzip"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(BulkKind::OracleCards, bulk_data.kind);
+        assert_eq!(160955958, bulk_data.size);
+        assert_eq!("gzip", bulk_data.content_encoding);
+    }
+}
@@ -1,13 +1,17 @@
+use super::date::Date;
+use super::link::{Link, PrintsSearchLink, RulingsLink, SetLink, SetSearchLink};
+use super::parse::{self, Lenient, ParseError};
 use super::price::Price;
 use super::uri::Uri;
 use super::uuid::Uuid;
-use serde::Deserialize;
+use crate::export::flat::FlatRecord;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::time::SystemTime;
+use std::io::Read;
 
 /// Possible colors that a card can be. Note that cards who do not have a color are not automatically colorless, e.g.
 /// conspiracies.
-#[derive(Debug, Deserialize, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Color {
     #[serde(rename = "W")]
@@ -26,17 +30,32 @@ pub enum Color {
     Green,
 }
 
+string_enum!(Color {
+    White => "W",
+    Blue => "U",
+    Black => "B",
+    Red => "R",
+    Green => "G",
+});
+
 /// The kind of card, e.g. normal / split / etc.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Layout {
     Normal,
     Split,
     Flip,
     Transform,
+    ModalDfc,
     Meld,
     Leveler,
+    Class,
+    Case,
     Saga,
+    Adventure,
+    Mutate,
+    Prototype,
+    Battle,
     Planar,
     Scheme,
     Vanguard,
@@ -45,10 +64,71 @@ pub enum Layout {
     Emblem,
     Augment,
     Host,
+    ArtSeries,
+    ReversibleCard,
+}
+
+string_enum!(Layout {
+    Normal => "normal",
+    Split => "split",
+    Flip => "flip",
+    Transform => "transform",
+    ModalDfc => "modal_dfc",
+    Meld => "meld",
+    Leveler => "leveler",
+    Class => "class",
+    Case => "case",
+    Saga => "saga",
+    Adventure => "adventure",
+    Mutate => "mutate",
+    Prototype => "prototype",
+    Battle => "battle",
+    Planar => "planar",
+    Scheme => "scheme",
+    Vanguard => "vanguard",
+    Token => "token",
+    DoubleFacedToken => "double_faced_token",
+    Emblem => "emblem",
+    Augment => "augment",
+    Host => "host",
+    ArtSeries => "art_series",
+    ReversibleCard => "reversible_card",
+});
+
+/// What sort of object a card is, for sorting real cards from the tokens, art cards and other objects Scryfall also
+/// catalogs.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CardKind {
+    /// A card that can be put in a deck, including cards for casual variants like Planechase.
+    Playable,
+
+    /// A token or double-faced token.
+    Token,
+
+    /// An emblem.
+    Emblem,
+
+    /// An art series card: a collectible print of a card's art, with no game text.
+    ArtCard,
+
+    /// A card from a minigame insert, such as a Jumpstart front card.
+    Minigame,
+
+    /// Memorabilia, such as a checklist, oversized card or non-game collectible.
+    Memorabilia,
 }
 
+string_enum!(CardKind {
+    Playable => "playable",
+    Token => "token",
+    Emblem => "emblem",
+    ArtCard => "art_card",
+    Minigame => "minigame",
+    Memorabilia => "memorabilia",
+});
+
 /// Frame effects that are applied over the primary Frame kinds.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum FrameEffect {
     Legendary,
@@ -64,8 +144,22 @@ pub enum FrameEffect {
     MoonEldraziDfc,
 }
 
+string_enum!(FrameEffect {
+    Legendary => "legendary",
+    Miracle => "miracle",
+    NyxTouched => "nyxtouched",
+    Draft => "draft",
+    Devoid => "devoid",
+    Tombstone => "tombstone",
+    ColorShifted => "colorshifted",
+    SunMoonDfc => "sunmoondfc",
+    CompassLandDfc => "compasslanddfc",
+    OriginPwDfc => "originpwdfc",
+    MoonEldraziDfc => "mooneldrazidfc",
+});
+
 /// Main Frame kind, e.g. '93, '97, etc.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum Frame {
     #[serde(rename = "1993")]
     Year1993,
@@ -83,8 +177,16 @@ pub enum Frame {
     Future,
 }
 
+string_enum!(Frame {
+    Year1993 => "1993",
+    Year1997 => "1997",
+    Year2003 => "2003",
+    Year2015 => "2015",
+    Future => "future",
+});
+
 /// The different kinds of MTG this can be played on. E.g. paper MTG, Arena, and MTG online.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Game {
     Paper,
@@ -92,18 +194,89 @@ pub enum Game {
     Mtgo,
 }
 
+string_enum!(Game {
+    Paper => "paper",
+    Arena => "arena",
+    Mtgo => "mtgo",
+});
+
+/// The physical finishes a printing can come in.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Finish {
+    Nonfoil,
+    Foil,
+    Etched,
+}
+
+string_enum!(Finish {
+    Nonfoil => "nonfoil",
+    Foil => "foil",
+    Etched => "etched",
+});
+
+/// How good the images Scryfall has for a card are.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageStatus {
+    Missing,
+    Placeholder,
+    Lowres,
+    HighresScan,
+}
+
+string_enum!(ImageStatus {
+    Missing => "missing",
+    Placeholder => "placeholder",
+    Lowres => "lowres",
+    HighresScan => "highres_scan",
+});
+
+/// The security stamps printed on cards.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecurityStamp {
+    Oval,
+    Triangle,
+    Acorn,
+    Circle,
+    Arena,
+    Heart,
+}
+
+string_enum!(SecurityStamp {
+    Oval => "oval",
+    Triangle => "triangle",
+    Acorn => "acorn",
+    Circle => "circle",
+    Arena => "arena",
+    Heart => "heart",
+});
+
 /// Rarity levels that a card can be.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 #[serde(rename_all = "lowercase")]
 pub enum Rarity {
     Common,
     Uncommon,
     Rare,
     Mythic,
+    Special,
+    Bonus,
 }
 
+string_enum!(Rarity {
+    Common => "common",
+    Uncommon => "uncommon",
+    Rare => "rare",
+    Mythic => "mythic",
+    Special => "special",
+    Bonus => "bonus",
+});
+
 /// The legality status of this card in different formats.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Legality {
     NotLegal,
@@ -112,8 +285,69 @@ pub enum Legality {
     Restricted,
 }
 
+string_enum!(Legality {
+    NotLegal => "not_legal",
+    Legal => "legal",
+    Banned => "banned",
+    Restricted => "restricted",
+});
+
+/// The formats Scryfall reports legalities for.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Standard,
+    Future,
+    Modern,
+    Legacy,
+    Pauper,
+    Vintage,
+    Penny,
+    Commander,
+    Brawl,
+    Duel,
+    Oldschool,
+    Historic,
+    Timeless,
+    Gladiator,
+    Pioneer,
+    Explorer,
+    Oathbreaker,
+    Standardbrawl,
+    Alchemy,
+    Paupercommander,
+    Premodern,
+    Predh,
+}
+
+string_enum!(Format {
+    Standard => "standard",
+    Future => "future",
+    Modern => "modern",
+    Legacy => "legacy",
+    Pauper => "pauper",
+    Vintage => "vintage",
+    Penny => "penny",
+    Commander => "commander",
+    Brawl => "brawl",
+    Duel => "duel",
+    Oldschool => "oldschool",
+    Historic => "historic",
+    Timeless => "timeless",
+    Gladiator => "gladiator",
+    Pioneer => "pioneer",
+    Explorer => "explorer",
+    Oathbreaker => "oathbreaker",
+    Standardbrawl => "standardbrawl",
+    Alchemy => "alchemy",
+    Paupercommander => "paupercommander",
+    Premodern => "premodern",
+    Predh => "predh",
+});
+
 /// Primary card object
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Card {
     /// This card’s Arena ID, if any. A large percentage of cards are not available on Arena and do not have this ID.
     pub arena_id: Option<u32>,
@@ -139,9 +373,13 @@ pub struct Card {
     /// This card’s ID on TCGplayer’s API, also known as the productId.
     pub tcgplayer_id: Option<u32>,
 
+    /// This card’s ID on Cardmarket’s API, also known as the idProduct.
+    pub cardmarket_id: Option<u32>,
+
     /// A unique ID for this card’s oracle identity. This value is consistent across reprinted card editions, and unique
-    /// among different cards with the same name (tokens, Unstable variants, etc).
-    pub oracle_id: Uuid,
+    /// among different cards with the same name (tokens, Unstable variants, etc). Missing for reversible cards, whose
+    /// faces each carry their own; [`oracle_identity`](Card::oracle_identity) covers both.
+    pub oracle_id: Option<Uuid>,
 
     /// A link to where you can begin paginating all re/prints for this card on Scryfall’s API.
     pub prints_search_uri: Uri,
@@ -157,13 +395,14 @@ pub struct Card {
 
     /// Gameplay fields
     /// If this card is closely related to other cards, this property will be an array with Related Card Objects.
-    pub all_parts: Option<Vec<CardFace>>,
+    pub all_parts: Option<Vec<RelatedCard>>,
 
     /// An array of Card Face objects, if this card is multifaced.
-    pub card_face: Option<Vec<RelatedCard>>,
+    pub card_faces: Option<Vec<CardFace>>,
 
-    /// The card’s converted mana cost. Note that some funny cards have fractional mana costs.
-    pub cmc: u32,
+    /// The card’s converted mana cost. Note that some funny cards have fractional mana costs. Missing for reversible
+    /// cards, whose faces each carry their own; [`mana_value`](Card::mana_value) covers both.
+    pub cmc: Option<f64>,
 
     /// This card’s colors, if the overall card has colors defined by the rules. Otherwise the colors will be on the
     /// card_faces objects, see below.
@@ -176,12 +415,21 @@ pub struct Card {
     /// one.
     pub color_indicator: Option<HashSet<Color>>,
 
+    /// This card’s defense, if any. Battles have defense.
+    pub defense: Option<String>,
+
     /// This card’s overall rank/popularity on EDHREC. Not all cards are ranked.
     pub edhrec_rank: Option<u32>,
 
     /// True if this printing exists in a foil version.
     pub foil: bool,
 
+    /// The finishes this printing exists in: any of nonfoil, foil, and etched.
+    pub finishes: Vec<Finish>,
+
+    /// Keywords that this card uses, such as `Flying` and `Cumulative upkeep`.
+    pub keywords: Vec<String>,
+
     /// A code for this card’s layout.
     pub layout: Layout,
 
@@ -218,20 +466,27 @@ pub struct Card {
     /// This card’s toughness, if any. Note that some cards have toughnesses that are not numeric, such as *.
     pub toughness: Option<String>,
 
-    /// The type line of this card.
+    /// The type line of this card. Empty for reversible cards, whose faces each carry their own.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub type_line: String,
 
     /// The name of the illustrator of this card. Newly spoiled cards may not have this field yet.
     pub artist: Option<String>,
 
+    /// The IDs of the artists that illustrated this card. Newly spoiled cards may not have this field yet.
+    pub artist_ids: Option<Vec<Uuid>>,
+
+    /// The lit Unfinity Attraction lights on this card, if any.
+    pub attraction_lights: Option<Vec<u8>>,
+
     /// Whether this card is found in boosters.
     pub booster: bool,
 
     /// This card’s border color: black, borderless, gold, silver, or white.
     pub border_color: String,
 
-    /// The Scryfall ID for the card back design present on this card.
-    pub card_back_id: Uuid,
+    /// The Scryfall ID for the card back design present on this card. Double-faced cards have no card back.
+    pub card_back_id: Option<Uuid>,
 
     /// This card’s collector number. Note that collector numbers can contain non-numeric characters, such as letters
     /// or ★.
@@ -262,9 +517,15 @@ pub struct Card {
     /// have this field yet.
     pub illustration_id: Option<Uuid>,
 
+    /// How good this card's images are.
+    pub image_status: ImageStatus,
+
     /// An object listing available imagery for this card. See the Card Imagery article for more information.
     pub image_uris: Option<ImageUris>,
 
+    /// Details of where and when this card was first previewed, for cards that were revealed before release.
+    pub preview: Option<Preview>,
+
     /// An object containing daily price information for this card, including usd, usd_foil, eur, and tix prices, as
     /// strings.
     pub prices: Prices,
@@ -281,20 +542,22 @@ pub struct Card {
     /// True if this card is a promotional print.
     pub promo: bool,
 
-    /// An array of strings describing what categories of promo cards this card falls into.
+    /// An array of strings describing what categories of promo cards this card falls into. Scryfall leaves it out when
+    /// there are none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub promo_types: Vec<String>,
 
     /// An object providing URIs to this card’s listing on major marketplaces.
     pub purchase_uris: PurchaseUris,
 
-    /// This card’s rarity. One of common, uncommon, rare, or mythic.
+    /// This card’s rarity. One of common, uncommon, rare, mythic, special, or bonus.
     pub rarity: Rarity,
 
     /// An object providing URIs to this card’s listing on other Magic: The Gathering online resources.
     pub related_uris: RelatedUris,
 
     /// The date this card was first released.
-    pub released_at: SystemTime,
+    pub released_at: Date,
 
     /// True if this card is a reprint.
     pub reprint: bool,
@@ -302,6 +565,12 @@ pub struct Card {
     /// A link to this card’s set on Scryfall’s website.
     pub scryfall_set_uri: Uri,
 
+    /// The security stamp on this card, if any.
+    pub security_stamp: Option<SecurityStamp>,
+
+    /// This card’s set’s ID in Scryfall’s database.
+    pub set_id: Uuid,
+
     /// This card’s full set name.
     pub set_name: String,
 
@@ -333,18 +602,243 @@ pub struct Card {
     pub watermark: Option<String>,
 }
 
+impl Card {
+    /// Parses a card from Scryfall's JSON, such as a response saved from the API or one entry of a bulk file. Errors
+    /// say which field failed, e.g. `card_faces[1].mana_cost`.
+    pub fn from_json_slice(bytes: &[u8]) -> Result<Card, ParseError> {
+        parse::from_slice(bytes)
+    }
+
+    /// Parses a card from the JSON read from `reader`, like [`from_json_slice`](Card::from_json_slice).
+    pub fn from_reader<R: Read>(reader: R) -> Result<Card, ParseError> {
+        parse::from_reader(reader)
+    }
+
+    /// Like [`from_json_slice`](Card::from_json_slice), but fields that fail to parse are treated as absent when the
+    /// card can do without them, e.g. a frame effect added to Scryfall after this version of the crate. The dropped
+    /// fields are reported alongside the card.
+    pub fn from_json_slice_lenient(bytes: &[u8]) -> Result<Lenient<Card>, ParseError> {
+        parse::from_slice_lenient(bytes)
+    }
+
+    /// A link to this card on the API, from [`uri`](Card::uri).
+    pub fn link(&self) -> Link<Card> {
+        Link::new(self.uri.clone())
+    }
+
+    /// A link to this card's set on the API, from [`set_uri`](Card::set_uri), or `None` if that isn't a valid URI.
+    pub fn set_link(&self) -> Option<SetLink> {
+        self.set_uri.parse().ok().map(SetLink::new)
+    }
+
+    /// A link to every printing of this card, from [`prints_search_uri`](Card::prints_search_uri).
+    pub fn prints_search_link(&self) -> PrintsSearchLink {
+        PrintsSearchLink::new(self.prints_search_uri.clone())
+    }
+
+    /// A link to every card in this card's set, from [`set_search_uri`](Card::set_search_uri).
+    pub fn set_search_link(&self) -> SetSearchLink {
+        SetSearchLink::new(self.set_search_uri.clone())
+    }
+
+    /// A link to this card's rulings, from [`rulings_uri`](Card::rulings_uri).
+    pub fn rulings_link(&self) -> RulingsLink {
+        RulingsLink::new(self.rulings_uri.clone())
+    }
+
+    /// The face of a multi-faced card on `face`'s side, or `None` for a single-faced card or a missing face.
+    pub fn face(&self, face: Face) -> Option<&CardFace> {
+        self.card_faces.as_ref()?.get(face as usize)
+    }
+
+    /// The emblems this card creates, as listed among its related cards.
+    pub fn related_emblems(&self) -> Vec<&RelatedCard> {
+        self.all_parts
+            .iter()
+            .flatten()
+            .filter(|part| part.type_line.starts_with("Emblem"))
+            .collect()
+    }
+
+    /// Whether this is a dungeon, such as those venturing into the dungeon explores in Adventures in the Forgotten
+    /// Realms.
+    pub fn is_dungeon(&self) -> bool {
+        self.type_line
+            .split_whitespace()
+            .any(|word| word == "Dungeon")
+    }
+
+    /// Whether this is an Unfinity Attraction. Search for these with `t:attraction`.
+    pub fn is_attraction(&self) -> bool {
+        self.combined_type_line()
+            .split_whitespace()
+            .any(|word| word == "Attraction")
+    }
+
+    /// Whether this Attraction's light `number` is lit, so it's visited when a roll to visit Attractions comes up
+    /// `number`. Always false for cards that aren't Attractions.
+    pub fn lights_up(&self, number: u8) -> bool {
+        self.attraction_lights
+            .as_ref()
+            .is_some_and(|lights| lights.contains(&number))
+    }
+
+    /// Whether this is an Unfinity sticker sheet.
+    pub fn is_sticker_sheet(&self) -> bool {
+        self.type_line == "Stickers"
+    }
+
+    /// The sticker sheets that come with this card, as listed among its related cards.
+    pub fn sticker_sheets(&self) -> Vec<&RelatedCard> {
+        self.all_parts
+            .iter()
+            .flatten()
+            .filter(|part| part.type_line == "Stickers")
+            .collect()
+    }
+
+    /// What sort of object this is, from its layout and then its set's type.
+    pub fn kind(&self) -> CardKind {
+        match self.layout {
+            Layout::ArtSeries => return CardKind::ArtCard,
+            Layout::Emblem => return CardKind::Emblem,
+            Layout::Token | Layout::DoubleFacedToken => return CardKind::Token,
+            _ => {}
+        }
+
+        match self.set_type.as_str() {
+            "minigame" => CardKind::Minigame,
+            "memorabilia" => CardKind::Memorabilia,
+            "token" => CardKind::Token,
+            _ => CardKind::Playable,
+        }
+    }
+
+    /// Whether this is a funny card that isn't legal in sanctioned play: one from an Un-set or other funny set,
+    /// printed with a silver border or an acorn security stamp, or with fractional stats or mana value. This mirrors
+    /// Scryfall's `is:funny`, for filtering local card lists the way [`SearchParams::exclude_funny`] filters searches.
+    ///
+    /// [`SearchParams::exclude_funny`]: crate::types::search::SearchParams::exclude_funny
+    pub fn is_funny(&self) -> bool {
+        let fractional = |stat: &Option<String>| {
+            stat.as_deref()
+                .is_some_and(|stat| stat.contains('.') || stat.contains('½'))
+        };
+        let stats = std::iter::once((&self.power, &self.toughness)).chain(
+            self.card_faces
+                .iter()
+                .flatten()
+                .map(|face| (&face.power, &face.toughness)),
+        );
+
+        self.set_type == "funny"
+            || self.border_color == "silver"
+            || self.security_stamp == Some(SecurityStamp::Acorn)
+            || self.mana_value().fract() != 0.0
+            || stats
+                .into_iter()
+                .any(|(power, toughness)| fractional(power) || fractional(toughness))
+    }
+
+    /// The card's oracle ID, or for reversible cards the oracle ID of the front face. Use this rather than `oracle_id`
+    /// to group printings of the same card.
+    pub fn oracle_identity(&self) -> Option<&str> {
+        self.oracle_id
+            .as_deref()
+            .or_else(|| self.card_faces.as_ref()?.first()?.oracle_id.as_deref())
+    }
+
+    /// The card's mana value, or for reversible cards the mana value of the front face.
+    pub fn mana_value(&self) -> f64 {
+        self.cmc
+            .or_else(|| self.card_faces.as_ref()?.first()?.cmc)
+            .unwrap_or_default()
+    }
+
+    /// The oracle text of every face, separated by a `//` line, or the card's own oracle text if it has one face.
+    pub fn combined_oracle_text(&self) -> Option<String> {
+        match &self.card_faces {
+            Some(faces) => {
+                let texts: Vec<&str> = faces
+                    .iter()
+                    .filter_map(|face| face.oracle_text.as_deref())
+                    .collect();
+                Some(texts.join("\n//\n")).filter(|text| !text.is_empty())
+            }
+            None => self.oracle_text.clone(),
+        }
+    }
+
+    /// The mana cost of every face with one, joined by ` // `, e.g. `{1}{U} // {3}{U}{U}`. Single-faced cards and cards
+    /// whose combined cost Scryfall already gives, like split cards, return that cost.
+    pub fn combined_mana_cost(&self) -> Option<String> {
+        if let Some(cost) = self.mana_cost.as_ref().filter(|cost| !cost.is_empty()) {
+            return Some(cost.clone());
+        }
+
+        let costs: Vec<&str> = self
+            .card_faces
+            .iter()
+            .flatten()
+            .map(|face| face.mana_cost.as_str())
+            .filter(|cost| !cost.is_empty())
+            .collect();
+        Some(costs.join(" // ")).filter(|cost| !cost.is_empty())
+    }
+
+    /// This card as a flat record of scalar columns, for CSV files and dataframes. The columns are listed in
+    /// [`COLUMNS`](crate::export::flat::COLUMNS).
+    pub fn to_flat_record(&self) -> FlatRecord {
+        FlatRecord::from(self)
+    }
+
+    /// The type line of every face, joined by ` // `. Scryfall already gives multi-faced cards a combined type line,
+    /// so this only joins faces itself when that's missing.
+    pub fn combined_type_line(&self) -> String {
+        if !self.type_line.is_empty() {
+            return self.type_line.clone();
+        }
+
+        self.card_faces
+            .iter()
+            .flatten()
+            .map(|face| face.type_line.as_str())
+            .collect::<Vec<_>>()
+            .join(" // ")
+    }
+}
+
+/// A side of a multi-faced card.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Face {
+    /// The first face: the front of a double-faced card, or the left half of a split card.
+    Front = 0,
+
+    /// The second face: the back of a double-faced card, or the right half of a split card.
+    Back = 1,
+}
+
 /// Card face object, used within the card object in the card_faces field.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CardFace {
     /// The name of the illustrator of this card face. Newly spoiled cards may not have this field yet.
     pub artist: Option<String>,
 
+    /// The ID of the illustrator of this card face. Newly spoiled cards may not have this field yet.
+    pub artist_id: Option<Uuid>,
+
+    /// The face’s own converted mana cost, given only on the faces of reversible cards.
+    pub cmc: Option<f64>,
+
     /// The colors in this face’s color indicator, if any.
     pub color_indicator: Option<HashSet<Color>>,
 
     /// This face’s colors, if the game defines colors for the individual face of this card.
     pub colors: Option<HashSet<Color>>,
 
+    /// This face’s defense, if any.
+    pub defense: Option<String>,
+
     /// The flavor text printed on this face, if any.
     pub flavor_text: Option<String>,
 
@@ -356,6 +850,9 @@ pub struct CardFace {
     /// double-sided, then the image_uris property will be part of the parent object instead.
     pub image_uris: Option<ImageUris>,
 
+    /// The face’s own layout, given only on the faces of reversible cards.
+    pub layout: Option<Layout>,
+
     /// This face’s loyalty, if any.
     pub loyalty: Option<String>,
 
@@ -366,6 +863,9 @@ pub struct CardFace {
     /// The name of this particular face.
     pub name: String,
 
+    /// The face’s own oracle ID, given only on the faces of reversible cards.
+    pub oracle_id: Option<Uuid>,
+
     /// The Oracle text for this face, if any.
     pub oracle_text: Option<String>,
 
@@ -392,7 +892,7 @@ pub struct CardFace {
 }
 
 /// Related card object, used within the card object in the all_parts field.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RelatedCard {
     /// An unique ID for this card in Scryfall’s database.
     pub id: Uuid,
@@ -412,7 +912,7 @@ pub struct RelatedCard {
 }
 
 /// Contains legalities for this card in each format.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Legalities {
     pub standard: Legality,
     pub future: Legality,
@@ -425,10 +925,51 @@ pub struct Legalities {
     pub brawl: Legality,
     pub duel: Legality,
     pub oldschool: Legality,
+    pub historic: Legality,
+    pub timeless: Legality,
+    pub gladiator: Legality,
+    pub pioneer: Legality,
+    pub explorer: Legality,
+    pub oathbreaker: Legality,
+    pub standardbrawl: Legality,
+    pub alchemy: Legality,
+    pub paupercommander: Legality,
+    pub premodern: Legality,
+    pub predh: Legality,
+}
+
+impl Legalities {
+    /// This card's legality in `format`.
+    pub fn get(&self, format: Format) -> &Legality {
+        match format {
+            Format::Standard => &self.standard,
+            Format::Future => &self.future,
+            Format::Modern => &self.modern,
+            Format::Legacy => &self.legacy,
+            Format::Pauper => &self.pauper,
+            Format::Vintage => &self.vintage,
+            Format::Penny => &self.penny,
+            Format::Commander => &self.commander,
+            Format::Brawl => &self.brawl,
+            Format::Duel => &self.duel,
+            Format::Oldschool => &self.oldschool,
+            Format::Historic => &self.historic,
+            Format::Timeless => &self.timeless,
+            Format::Gladiator => &self.gladiator,
+            Format::Pioneer => &self.pioneer,
+            Format::Explorer => &self.explorer,
+            Format::Oathbreaker => &self.oathbreaker,
+            Format::Standardbrawl => &self.standardbrawl,
+            Format::Alchemy => &self.alchemy,
+            Format::Paupercommander => &self.paupercommander,
+            Format::Premodern => &self.premodern,
+            Format::Predh => &self.predh,
+        }
+    }
 }
 
 /// Contains all of the possible URIs for each kind of image Scryfall stores.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ImageUris {
     pub small: Option<Uri>,
     pub normal: Option<Uri>,
@@ -438,27 +979,94 @@ pub struct ImageUris {
     pub border_crop: Option<Uri>,
 }
 
+impl ImageUris {
+    /// The URI of the image in `version`, if Scryfall has one.
+    pub fn get(&self, version: ImageVersion) -> Option<&Uri> {
+        match version {
+            ImageVersion::Small => self.small.as_ref(),
+            ImageVersion::Normal => self.normal.as_ref(),
+            ImageVersion::Large => self.large.as_ref(),
+            ImageVersion::Png => self.png.as_ref(),
+            ImageVersion::ArtCrop => self.art_crop.as_ref(),
+            ImageVersion::BorderCrop => self.border_crop.as_ref(),
+        }
+    }
+}
+
+/// The sizes and crops Scryfall stores card images in.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "snake_case")]
+pub enum ImageVersion {
+    /// A small full card image, 146 × 204 JPG.
+    Small,
+
+    /// A medium-sized full card image, 488 × 680 JPG.
+    Normal,
+
+    /// A large full card image, 672 × 936 JPG.
+    Large,
+
+    /// A transparent, rounded full card PNG, 745 × 1040.
+    Png,
+
+    /// A rectangular crop of the card's art only.
+    #[cfg_attr(feature = "cli", value(name = "art_crop"))]
+    ArtCrop,
+
+    /// A full card image with the rounded corners and the majority of the border cropped off, 480 × 680 JPG.
+    #[cfg_attr(feature = "cli", value(name = "border_crop"))]
+    BorderCrop,
+}
+
+string_enum!(ImageVersion {
+    Small => "small",
+    Normal => "normal",
+    Large => "large",
+    Png => "png",
+    ArtCrop => "art_crop",
+    BorderCrop => "border_crop",
+});
+
 /// Contains prices in different markets for this card.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Prices {
     pub usd: Option<Price>,
     pub usd_foil: Option<Price>,
+    pub usd_etched: Option<Price>,
     pub eur: Option<Price>,
+    pub eur_foil: Option<Price>,
     pub tix: Option<Price>,
 }
 
 /// Contains URIs to this card on sites where you can purchase this card
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct PurchaseUris {
     pub tcgplayer: Option<Uri>,
     pub cardmarket: Option<Uri>,
     pub cardhoarder: Option<Uri>,
 }
 
+/// Where and when a card was first previewed.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Preview {
+    /// The date this card was previewed.
+    pub previewed_at: Option<Date>,
+
+    /// A link to the preview for this card. Scryfall sometimes reports an empty string here.
+    pub source_uri: Option<String>,
+
+    /// The name of the source that previewed this card.
+    pub source: Option<String>,
+}
+
 /// Contains URIs to this card on related sites.
-#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct RelatedUris {
+    pub gatherer: Option<Uri>,
     pub tcgplayer_decks: Option<Uri>,
+    pub tcgplayer_infinite_articles: Option<Uri>,
+    pub tcgplayer_infinite_decks: Option<Uri>,
     pub edhrec: Option<Uri>,
     pub mtgtop8: Option<Uri>,
 }
@@ -502,6 +1110,11 @@ mod tests {
             ("\"emblem\"", Layout::Emblem),
             ("\"augment\"", Layout::Augment),
             ("\"host\"", Layout::Host),
+            ("\"modal_dfc\"", Layout::ModalDfc),
+            ("\"class\"", Layout::Class),
+            ("\"adventure\"", Layout::Adventure),
+            ("\"art_series\"", Layout::ArtSeries),
+            ("\"reversible_card\"", Layout::ReversibleCard),
         ];
 
         for layout_pair in layout_pairs {
@@ -568,6 +1181,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_finish() {
+        use super::Finish;
+
+        let finish_pairs = vec![
+            ("\"nonfoil\"", Finish::Nonfoil),
+            ("\"foil\"", Finish::Foil),
+            ("\"etched\"", Finish::Etched),
+        ];
+
+        for finish_pair in finish_pairs {
+            let val: Finish = serde_json::from_str(finish_pair.0).unwrap();
+            assert_eq!(finish_pair.1, val);
+        }
+    }
+
     #[test]
     fn test_rarity() {
         use super::Rarity;
@@ -577,12 +1206,82 @@ mod tests {
             ("\"uncommon\"", Rarity::Uncommon),
             ("\"rare\"", Rarity::Rare),
             ("\"mythic\"", Rarity::Mythic),
+            ("\"special\"", Rarity::Special),
+            ("\"bonus\"", Rarity::Bonus),
         ];
 
         for rarity_pair in rarity_pairs {
             let val: Rarity = serde_json::from_str(rarity_pair.0).unwrap();
             assert_eq!(rarity_pair.1, val);
         }
+
+        // A Time Spiral timeshifted card.
+        let card = crate::testing::card_with(serde_json::json!({ "rarity": "special" }));
+        assert_eq!(Rarity::Special, card.rarity);
+    }
+
+    #[test]
+    fn test_string_forms() {
+        use super::{Color, Format, Frame, Game, ImageVersion, Layout, Legality, Rarity};
+        use serde::de::DeserializeOwned;
+        use std::fmt::Debug;
+        use std::str::FromStr;
+
+        // Every string form must round-trip through FromStr and agree with the serde name.
+        fn check<T>(values: &[T])
+        where
+            T: Debug + PartialEq + DeserializeOwned + FromStr + ToString,
+            T::Err: Debug,
+        {
+            for value in values {
+                let text = value.to_string();
+                assert_eq!(value, &text.parse::<T>().unwrap());
+                assert_eq!(
+                    value,
+                    &serde_json::from_value::<T>(serde_json::Value::String(text)).unwrap()
+                );
+            }
+        }
+
+        check(Color::ALL);
+        check(Layout::ALL);
+        check(Frame::ALL);
+        check(Game::ALL);
+        check(Rarity::ALL);
+        check(Legality::ALL);
+        check(Format::ALL);
+        check(ImageVersion::ALL);
+
+        assert_eq!("double_faced_token", Layout::DoubleFacedToken.to_string());
+        assert_eq!("U", Color::Blue.to_string());
+        let err = "mythic rare".parse::<Rarity>().unwrap_err();
+        assert_eq!("unknown Rarity: mythic rare", err.to_string());
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_value_enums() {
+        use super::{Format, ImageVersion, Rarity};
+        use clap::ValueEnum;
+
+        assert_eq!(Ok(Rarity::Mythic), Rarity::from_str("mythic", false));
+        assert_eq!(Ok(Format::Oldschool), Format::from_str("OldSchool", true));
+        for version in ImageVersion::ALL {
+            assert_eq!(
+                Ok(*version),
+                ImageVersion::from_str(version.as_str(), false)
+            );
+        }
+    }
+
+    #[test]
+    fn test_legalities_get() {
+        use super::{Format, Legality};
+        use crate::testing;
+
+        let card = testing::card();
+        assert_eq!(&Legality::Legal, card.legalities.get(Format::Modern));
+        assert_eq!(&Legality::NotLegal, card.legalities.get(Format::Standard));
     }
 
     #[test]
@@ -603,7 +1302,44 @@ mod tests {
     }
 
     #[test]
-    fn test_card_face() {}
+    fn test_card_face() {
+        use super::Face;
+        use crate::testing;
+
+        let card = testing::double_faced_card();
+        let front = card.face(Face::Front).unwrap();
+        assert_eq!("Delver of Secrets", front.name);
+        assert_eq!("{U}", front.mana_cost);
+        assert_eq!("Insectile Aberration", card.face(Face::Back).unwrap().name);
+        assert_eq!(None, testing::card().face(Face::Front));
+    }
+
+    #[test]
+    fn test_combined_text() {
+        use crate::testing;
+
+        let card = testing::double_faced_card();
+        assert_eq!(
+            Some("Flying"),
+            card.combined_oracle_text().unwrap().split("\n//\n").nth(1)
+        );
+        assert_eq!(Some("{U}"), card.combined_mana_cost().as_deref());
+        assert_eq!(
+            "Creature — Human Wizard // Creature — Human Insect",
+            card.combined_type_line()
+        );
+
+        let split = testing::card_with(serde_json::json!({
+            "mana_cost": "{R} // {U}",
+            "type_line": "Instant // Instant",
+        }));
+        assert_eq!(Some("{R} // {U}"), split.combined_mana_cost().as_deref());
+        assert_eq!("Instant // Instant", split.combined_type_line());
+
+        let bolt = testing::card();
+        assert_eq!(bolt.oracle_text, bolt.combined_oracle_text());
+        assert_eq!(Some("{R}"), bolt.combined_mana_cost().as_deref());
+    }
 
     #[test]
     fn test_related_card() {}
@@ -615,18 +1351,11 @@ mod tests {
     fn test_image_uris() {
         use super::ImageUris;
         use super::Uri;
-        use http;
 
         let target_image_uris = ImageUris {
-            small: Some(Uri("https://testuri.com/path/1"
-                .parse::<http::Uri>()
-                .unwrap())),
-            normal: Some(Uri("https://testuri.com/path/2"
-                .parse::<http::Uri>()
-                .unwrap())),
-            large: Some(Uri("https://testuri.com/path/3"
-                .parse::<http::Uri>()
-                .unwrap())),
+            small: Some("https://testuri.com/path/1".parse::<Uri>().unwrap()),
+            normal: Some("https://testuri.com/path/2".parse::<Uri>().unwrap()),
+            large: Some("https://testuri.com/path/3".parse::<Uri>().unwrap()),
             png: None,
             art_crop: None,
             border_crop: None,
@@ -654,7 +1383,9 @@ mod tests {
         let target_prices = Prices {
             usd: Some(Price(15.44)),
             usd_foil: Some(Price(37.12)),
+            usd_etched: None,
             eur: None,
+            eur_foil: None,
             tix: None,
         };
 
@@ -671,16 +1402,32 @@ mod tests {
         assert_eq!(target_prices, prices);
     }
 
+    #[test]
+    fn test_preview() {
+        use super::Preview;
+
+        let preview: Preview = serde_json::from_str(
+            r#"
+            {
+                "source": "Wizards of the Coast",
+                "source_uri": "https://magic.wizards.com/",
+                "previewed_at": "2024-07-09"
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(Some("Wizards of the Coast".to_string()), preview.source);
+        assert_eq!("2024-07-09", preview.previewed_at.unwrap().to_string());
+    }
+
     #[test]
     fn test_purchase_uris() {
         use super::PurchaseUris;
         use super::Uri;
-        use http;
 
         let target_purchase_uris = PurchaseUris {
-            tcgplayer: Some(Uri("https://testuri.com/path/1"
-                .parse::<http::Uri>()
-                .unwrap())),
+            tcgplayer: Some("https://testuri.com/path/1".parse::<Uri>().unwrap()),
             cardmarket: None,
             cardhoarder: None,
         };
@@ -701,12 +1448,12 @@ mod tests {
     fn test_related_uris() {
         use super::RelatedUris;
         use super::Uri;
-        use http;
 
         let target_related_uris = RelatedUris {
-            tcgplayer_decks: Some(Uri("https://testuri.com/path/1"
-                .parse::<http::Uri>()
-                .unwrap())),
+            gatherer: None,
+            tcgplayer_decks: Some("https://testuri.com/path/1".parse::<Uri>().unwrap()),
+            tcgplayer_infinite_articles: None,
+            tcgplayer_infinite_decks: None,
             edhrec: None,
             mtgtop8: None,
         };
@@ -722,4 +1469,177 @@ mod tests {
 
         assert_eq!(target_related_uris, related_uris);
     }
+
+    #[test]
+    fn test_is_funny() {
+        use crate::testing;
+        use serde_json::json;
+
+        assert!(!testing::card().is_funny());
+        assert!(testing::card_with(json!({"set_type": "funny"})).is_funny());
+        assert!(testing::card_with(json!({"security_stamp": "acorn"})).is_funny());
+        assert!(testing::card_with(json!({"border_color": "silver"})).is_funny());
+        assert!(testing::card_with(json!({"cmc": 0.5})).is_funny());
+        assert!(testing::card_with(json!({"power": "3.5", "toughness": "3"})).is_funny());
+        assert!(!testing::card_with(json!({"security_stamp": "oval"})).is_funny());
+    }
+
+    #[test]
+    fn test_kind() {
+        use super::CardKind;
+        use crate::testing;
+        use serde_json::json;
+
+        assert_eq!(CardKind::Playable, testing::card().kind());
+        assert_eq!(
+            CardKind::ArtCard,
+            testing::card_with(json!({"layout": "art_series", "set_type": "memorabilia"})).kind()
+        );
+        assert_eq!(
+            CardKind::Emblem,
+            testing::card_with(json!({"layout": "emblem", "set_type": "token"})).kind()
+        );
+        assert_eq!(
+            CardKind::Token,
+            testing::card_with(json!({"layout": "token", "set_type": "token"})).kind()
+        );
+        assert_eq!(
+            CardKind::Minigame,
+            testing::card_with(json!({"set_type": "minigame"})).kind()
+        );
+        assert_eq!(
+            CardKind::Memorabilia,
+            testing::card_with(json!({"set_type": "memorabilia"})).kind()
+        );
+        assert_eq!(
+            CardKind::Playable,
+            testing::card_with(json!({"layout": "planar"})).kind()
+        );
+    }
+
+    #[test]
+    fn test_related_emblems() {
+        use crate::testing;
+        use serde_json::json;
+
+        let part = |name: &str, type_line: &str| {
+            json!({
+                "object": "related_card",
+                "id": "00000000-0000-0000-0000-000000000000",
+                "component": "combo_piece",
+                "name": name,
+                "type_line": type_line,
+                "uri": "https://api.scryfall.com/cards/00000000-0000-0000-0000-000000000000",
+            })
+        };
+        let elspeth = testing::card_with(json!({
+            "name": "Elspeth, Knight-Errant",
+            "type_line": "Legendary Planeswalker — Elspeth",
+            "all_parts": [
+                part("Elspeth, Knight-Errant", "Legendary Planeswalker — Elspeth"),
+                part("Soldier", "Token Creature — Soldier"),
+                part("Elspeth, Knight-Errant Emblem", "Emblem — Elspeth"),
+            ],
+        }));
+
+        let emblems = elspeth.related_emblems();
+        assert_eq!(1, emblems.len());
+        assert_eq!("Elspeth, Knight-Errant Emblem", emblems[0].name);
+        assert!(testing::card().related_emblems().is_empty());
+        assert!(testing::card_with(json!({"type_line": "Dungeon"})).is_dungeon());
+        assert!(!testing::card().is_dungeon());
+    }
+
+    #[test]
+    fn test_unfinity() {
+        use crate::testing;
+        use serde_json::json;
+
+        let attraction = testing::card_with(json!({
+            "name": "Balloon Stand",
+            "type_line": "Artifact — Attraction",
+            "attraction_lights": [2, 6],
+            "all_parts": [{
+                "object": "related_card",
+                "id": "00000000-0000-0000-0000-000000000000",
+                "component": "combo_piece",
+                "name": "Aerialephant",
+                "type_line": "Stickers",
+                "uri": "https://api.scryfall.com/cards/00000000-0000-0000-0000-000000000000",
+            }],
+        }));
+        assert!(attraction.is_attraction());
+        assert!(attraction.lights_up(6));
+        assert!(!attraction.lights_up(3));
+        assert_eq!("Aerialephant", attraction.sticker_sheets()[0].name);
+
+        let bolt = testing::card();
+        assert!(!bolt.is_attraction());
+        assert!(!bolt.lights_up(1));
+        assert!(bolt.sticker_sheets().is_empty());
+        assert!(testing::card_with(json!({"type_line": "Stickers"})).is_sticker_sheet());
+    }
+
+    #[test]
+    fn test_from_json_slice() {
+        use super::Card;
+        use crate::testing::card_json;
+
+        let json = card_json(serde_json::json!({})).to_string();
+        assert_eq!(
+            "Lightning Bolt",
+            Card::from_json_slice(json.as_bytes()).unwrap().name
+        );
+        assert_eq!(
+            "Lightning Bolt",
+            Card::from_reader(json.as_bytes()).unwrap().name
+        );
+
+        let json = card_json(serde_json::json!({"card_faces": [{"name": 7}]})).to_string();
+        let err = Card::from_json_slice(json.as_bytes()).unwrap_err();
+        assert_eq!("card_faces[0].name", err.path());
+        assert!(err
+            .to_string()
+            .starts_with("card_faces[0].name: invalid type"));
+
+        let err = Card::from_json_slice(b"{").unwrap_err();
+        assert_eq!(".", err.path());
+    }
+
+    #[test]
+    fn test_from_json_slice_lenient() {
+        use super::Card;
+        use crate::testing::card_json;
+
+        let json = card_json(serde_json::json!({
+            "security_stamp": "star",
+            "frame_effect": "glowing",
+        }))
+        .to_string();
+        assert!(Card::from_json_slice(json.as_bytes()).is_err());
+        let lenient = Card::from_json_slice_lenient(json.as_bytes()).unwrap();
+        assert_eq!("Lightning Bolt", lenient.value.name);
+        assert_eq!(None, lenient.value.security_stamp);
+        assert_eq!(2, lenient.dropped.len());
+        assert!(lenient.dropped.contains(&"security_stamp".to_string()));
+
+        let json = card_json(serde_json::json!({"name": 7})).to_string();
+        let err = Card::from_json_slice_lenient(json.as_bytes()).unwrap_err();
+        assert_eq!("name", err.path());
+    }
+
+    #[test]
+    fn test_oracle_identity() {
+        use crate::testing;
+
+        let bolt = testing::card();
+        assert_eq!(bolt.oracle_id.as_deref(), bolt.oracle_identity());
+
+        let sol_ring = testing::reversible_card();
+        assert_eq!(None, sol_ring.oracle_id);
+        assert_eq!(
+            Some("3ac8bdcc-3712-573d-837a-894f9a029618"),
+            sol_ring.oracle_identity()
+        );
+    }
 }
@@ -0,0 +1,131 @@
+use super::uri::Uri;
+use serde::Deserialize;
+
+/// Catalog objects: lists of Magic data points, such as all card names or all creature types, used for things like
+/// autocomplete and validating search terms.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Catalog {
+    /// A link to the current catalog on Scryfall’s API, if it has one.
+    pub uri: Option<Uri>,
+
+    /// The number of items in the `data` array.
+    pub total_values: u32,
+
+    /// An array of datapoints, as strings.
+    pub data: Vec<String>,
+}
+
+/// The catalogs Scryfall publishes under `/catalog`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "kebab-case")]
+pub enum CatalogKind {
+    /// Every English card name, including tokens and split card halves.
+    CardNames,
+
+    /// Every artist name credited on a card.
+    ArtistNames,
+
+    /// Every English word of two or more letters that appears in a card name.
+    WordBank,
+
+    /// Every supertype, such as `Legendary` and `Basic`.
+    Supertypes,
+
+    /// Every card type, such as `Creature` and `Instant`.
+    CardTypes,
+
+    /// Every artifact type, such as `Equipment`.
+    ArtifactTypes,
+
+    /// Every battle type, such as `Siege`.
+    BattleTypes,
+
+    /// Every creature type.
+    CreatureTypes,
+
+    /// Every enchantment type, such as `Aura` and `Saga`.
+    EnchantmentTypes,
+
+    /// Every land type, such as `Forest` and `Gate`.
+    LandTypes,
+
+    /// Every planeswalker type, such as `Jace`.
+    PlaneswalkerTypes,
+
+    /// Every instant and sorcery type, such as `Adventure` and `Arcane`.
+    SpellTypes,
+
+    /// Every distinct power value, including non-numeric ones like `*`.
+    Powers,
+
+    /// Every distinct toughness value.
+    Toughnesses,
+
+    /// Every distinct starting loyalty value.
+    Loyalties,
+
+    /// Every watermark.
+    Watermarks,
+
+    /// Every keyword ability, such as `Flying`.
+    KeywordAbilities,
+
+    /// Every keyword action, such as `Scry`.
+    KeywordActions,
+
+    /// Every ability word, such as `Landfall`.
+    AbilityWords,
+
+    /// Every flavor word, such as `Ring-bearer`.
+    FlavorWords,
+}
+
+string_enum!(CatalogKind {
+    CardNames => "card-names",
+    ArtistNames => "artist-names",
+    WordBank => "word-bank",
+    Supertypes => "supertypes",
+    CardTypes => "card-types",
+    ArtifactTypes => "artifact-types",
+    BattleTypes => "battle-types",
+    CreatureTypes => "creature-types",
+    EnchantmentTypes => "enchantment-types",
+    LandTypes => "land-types",
+    PlaneswalkerTypes => "planeswalker-types",
+    SpellTypes => "spell-types",
+    Powers => "powers",
+    Toughnesses => "toughnesses",
+    Loyalties => "loyalties",
+    Watermarks => "watermarks",
+    KeywordAbilities => "keyword-abilities",
+    KeywordActions => "keyword-actions",
+    AbilityWords => "ability-words",
+    FlavorWords => "flavor-words",
+});
+
+mod tests {
+    #[test]
+    fn test_parse_catalog() {
+        use super::Catalog;
+
+        let catalog: Catalog = serde_json::from_value(serde_json::json!({
+            "object": "catalog",
+            "total_values": 2,
+            "data": ["Lightning Bolt", "Lightning Helix"],
+        }))
+        .unwrap();
+        assert_eq!(None, catalog.uri);
+        assert_eq!(2, catalog.total_values);
+        assert_eq!("Lightning Helix", catalog.data[1]);
+    }
+
+    #[test]
+    fn test_catalog_kind() {
+        use super::CatalogKind;
+
+        assert_eq!("keyword-abilities", CatalogKind::KeywordAbilities.as_str());
+        assert_eq!(Ok(CatalogKind::WordBank), "word-bank".parse());
+        assert_eq!(20, CatalogKind::ALL.len());
+    }
+}
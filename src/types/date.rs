@@ -0,0 +1,77 @@
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+struct DateVisitor;
+
+impl<'de> Visitor<'de> for DateVisitor {
+    type Value = Date;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a date in the form YYYY-MM-DD")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let mut parts = value.splitn(3, '-');
+        let year = parts.next().and_then(|part| part.parse::<i32>().ok());
+        let month = parts.next().and_then(|part| part.parse::<u8>().ok());
+        let day = parts.next().and_then(|part| part.parse::<u8>().ok());
+
+        match (year, month, day) {
+            (Some(year), Some(month @ 1..=12), Some(day @ 1..=31)) => Ok(Date { year, month, day }),
+            _ => Err(E::custom("failed to parse date".to_string())),
+        }
+    }
+}
+
+/// A calendar date as Scryfall reports it, e.g. a card's release date. Scryfall dates carry no time or timezone.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+mod tests {
+    #[test]
+    fn test_parse_date() {
+        use super::Date;
+
+        let date: Date = serde_json::from_str("\"2019-10-04\"").unwrap();
+        assert_eq!(
+            Date {
+                year: 2019,
+                month: 10,
+                day: 4
+            },
+            date
+        );
+        assert_eq!("2019-10-04", date.to_string());
+    }
+
+    #[test]
+    fn test_parse_invalid_date() {
+        use super::Date;
+
+        assert!(serde_json::from_str::<Date>("\"2019-13-04\"").is_err());
+        assert!(serde_json::from_str::<Date>("\"yesterday\"").is_err());
+    }
+}
@@ -0,0 +1,60 @@
+//! String forms for the API's enumerations, shared by `Display` and `FromStr`.
+
+use std::error;
+use std::fmt;
+
+/// The error returned when parsing a string that isn't one of an enumeration's values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseEnumError {
+    /// The name of the type being parsed, e.g. `Rarity`.
+    pub kind: &'static str,
+
+    /// The string that failed to parse.
+    pub value: String,
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown {}: {}", self.kind, self.value)
+    }
+}
+
+impl error::Error for ParseEnumError {}
+
+/// Implements `as_str`, `Display`, and `FromStr` for a fieldless enum, given each variant's string form. The forms
+/// should match the enum's serde names so that the crate speaks the same vocabulary as the API.
+macro_rules! string_enum {
+    ($name:ident { $($variant:ident => $text:literal),* $(,)? }) => {
+        impl $name {
+            /// The string Scryfall uses for this value.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $text,)*
+                }
+            }
+
+            /// Every value, in declaration order.
+            pub const ALL: &'static [$name] = &[$($name::$variant,)*];
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::types::enums::ParseEnumError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value {
+                    $($text => Ok($name::$variant),)*
+                    _ => Err($crate::types::enums::ParseEnumError {
+                        kind: stringify!($name),
+                        value: value.to_string(),
+                    }),
+                }
+            }
+        }
+    };
+}
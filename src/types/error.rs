@@ -1,10 +1,12 @@
 use serde::Deserialize;
+use std::error;
+use std::fmt;
 
 /// The error object that accompanies a 4xx or a 5xx from the server.
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 pub struct Error {
     /// An integer HTTP status code for this error.
-    pub error: u16,
+    pub status: u16,
 
     /// A computer-friendly string representing the appropriate HTTP status code.
     pub code: String,
@@ -21,3 +23,36 @@ pub struct Error {
     /// array.
     pub warnings: Option<Vec<String>>,
 }
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.code, self.status, self.details)
+    }
+}
+
+impl error::Error for Error {}
+
+mod tests {
+    #[test]
+    fn test_parse_error() {
+        use super::Error;
+
+        let error: Error = serde_json::from_str(
+            r#"
+            {
+                "object": "error",
+                "code": "not_found",
+                "status": 404,
+                "details": "No card found with the given ID."
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(404, error.status);
+        assert_eq!(
+            "not_found (404): No card found with the given ID.",
+            error.to_string()
+        );
+    }
+}
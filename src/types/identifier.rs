@@ -0,0 +1,246 @@
+use super::card::Card;
+use super::uuid::Uuid;
+use crate::names;
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A way to identify a card in a `/cards/collection` request.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CardIdentifier {
+    /// A card's Scryfall ID.
+    Id(Uuid),
+
+    /// A card's Magic Online ID.
+    MtgoId(u32),
+
+    /// A card's Gatherer multiverse ID.
+    MultiverseId(u32),
+
+    /// A card's Oracle ID. Matches the preferred printing of that card.
+    OracleId(Uuid),
+
+    /// A card's illustration ID. Matches the preferred printing with that art.
+    IllustrationId(Uuid),
+
+    /// A card's name. Matches the newest printing.
+    Name(String),
+
+    /// A card's name and the code of the set to take the printing from.
+    NameSet { name: String, set: String },
+
+    /// A set code and collector number.
+    SetNumber {
+        set: String,
+        collector_number: String,
+    },
+}
+
+impl CardIdentifier {
+    /// Whether `card` is one this identifier matches. Names are compared as [`names::normalize`] folds them, against
+    /// the card's full name and each of its faces' names.
+    pub fn matches(&self, card: &Card) -> bool {
+        let name_matches = |name: &str| {
+            let name = names::normalize(name);
+            names::normalize(&card.name) == name
+                || card
+                    .card_faces
+                    .iter()
+                    .flatten()
+                    .any(|face| names::normalize(&face.name) == name)
+        };
+
+        match self {
+            CardIdentifier::Id(id) => card.id.eq_ignore_ascii_case(id),
+            CardIdentifier::MtgoId(id) => card.mtgo_id == Some(*id),
+            CardIdentifier::MultiverseId(id) => card
+                .multiverse_ids
+                .as_ref()
+                .is_some_and(|ids| ids.contains(id)),
+            CardIdentifier::OracleId(id) => card
+                .oracle_identity()
+                .is_some_and(|oracle| oracle.eq_ignore_ascii_case(id)),
+            CardIdentifier::IllustrationId(id) => card
+                .illustration_id
+                .as_ref()
+                .is_some_and(|illustration| illustration.eq_ignore_ascii_case(id)),
+            CardIdentifier::Name(name) => name_matches(name),
+            CardIdentifier::NameSet { name, set } => {
+                name_matches(name) && card.set.eq_ignore_ascii_case(set)
+            }
+            CardIdentifier::SetNumber {
+                set,
+                collector_number,
+            } => card.set.eq_ignore_ascii_case(set) && card.collector_number == *collector_number,
+        }
+    }
+
+    /// The JSON object Scryfall expects for this identifier.
+    fn to_value(&self) -> Value {
+        let fields: Vec<(&str, Value)> = match self {
+            CardIdentifier::Id(id) => vec![("id", id.as_str().into())],
+            CardIdentifier::MtgoId(id) => vec![("mtgo_id", (*id).into())],
+            CardIdentifier::MultiverseId(id) => vec![("multiverse_id", (*id).into())],
+            CardIdentifier::OracleId(id) => vec![("oracle_id", id.as_str().into())],
+            CardIdentifier::IllustrationId(id) => vec![("illustration_id", id.as_str().into())],
+            CardIdentifier::Name(name) => vec![("name", name.as_str().into())],
+            CardIdentifier::NameSet { name, set } => {
+                vec![("name", name.as_str().into()), ("set", set.as_str().into())]
+            }
+            CardIdentifier::SetNumber {
+                set,
+                collector_number,
+            } => vec![
+                ("set", set.as_str().into()),
+                ("collector_number", collector_number.as_str().into()),
+            ],
+        };
+
+        Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+
+    /// The identifier described by a JSON object from Scryfall, if it's one this type knows.
+    fn from_object(object: &Map<String, Value>) -> Option<Self> {
+        let string = |key: &str| object.get(key).and_then(Value::as_str).map(str::to_string);
+        let number = |key: &str| object.get(key).and_then(Value::as_u64).map(|id| id as u32);
+
+        if let Some(id) = string("id") {
+            Some(CardIdentifier::Id(id))
+        } else if let Some(id) = number("mtgo_id") {
+            Some(CardIdentifier::MtgoId(id))
+        } else if let Some(id) = number("multiverse_id") {
+            Some(CardIdentifier::MultiverseId(id))
+        } else if let Some(id) = string("oracle_id") {
+            Some(CardIdentifier::OracleId(id))
+        } else if let Some(id) = string("illustration_id") {
+            Some(CardIdentifier::IllustrationId(id))
+        } else if let (Some(set), Some(collector_number)) =
+            (string("set"), string("collector_number"))
+        {
+            Some(CardIdentifier::SetNumber {
+                set,
+                collector_number,
+            })
+        } else if let (Some(name), Some(set)) = (string("name"), string("set")) {
+            Some(CardIdentifier::NameSet { name, set })
+        } else {
+            string("name").map(CardIdentifier::Name)
+        }
+    }
+}
+
+impl Serialize for CardIdentifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CardIdentifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let object = Map::deserialize(deserializer)?;
+        CardIdentifier::from_object(&object).ok_or_else(|| {
+            de::Error::custom(format!(
+                "unrecognized card identifier: {}",
+                Value::Object(object)
+            ))
+        })
+    }
+}
+
+/// The result of a `/cards/collection` request.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Collection {
+    /// The cards that were found, in the order they were asked for.
+    #[serde(rename = "data")]
+    pub cards: Vec<Card>,
+
+    /// The identifiers that matched no card.
+    pub not_found: Vec<CardIdentifier>,
+}
+
+mod tests {
+    #[test]
+    fn test_round_trip() {
+        use super::CardIdentifier;
+
+        let identifiers = vec![
+            CardIdentifier::Id("e3285e6b-3e79-4d7c-bf96-d920f973b80d".to_string()),
+            CardIdentifier::MtgoId(35192),
+            CardIdentifier::MultiverseId(191089),
+            CardIdentifier::OracleId("4457ed35-7c10-48c8-9776-456485fdf070".to_string()),
+            CardIdentifier::IllustrationId("4bae9c3b-cd63-4f2a-b6c0-d0ae2a5cbd18".to_string()),
+            CardIdentifier::Name("Lightning Bolt".to_string()),
+            CardIdentifier::NameSet {
+                name: "Lightning Bolt".to_string(),
+                set: "m10".to_string(),
+            },
+            CardIdentifier::SetNumber {
+                set: "m10".to_string(),
+                collector_number: "146".to_string(),
+            },
+        ];
+
+        let json = serde_json::to_value(&identifiers).unwrap();
+        assert_eq!(
+            serde_json::json!({"set": "m10", "collector_number": "146"}),
+            json[7]
+        );
+        assert_eq!(
+            identifiers,
+            serde_json::from_value::<Vec<CardIdentifier>>(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_collection() {
+        use super::{CardIdentifier, Collection};
+        use crate::testing;
+
+        let collection: Collection = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "not_found": [{"name": "Lightning Blot"}],
+            "data": [testing::card_json(serde_json::json!({}))],
+        }))
+        .unwrap();
+        assert_eq!("Lightning Bolt", collection.cards[0].name);
+        assert_eq!(
+            vec![CardIdentifier::Name("Lightning Blot".to_string())],
+            collection.not_found
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        use super::CardIdentifier;
+        use crate::testing;
+
+        let bolt = testing::card();
+        assert!(
+            CardIdentifier::Id("E3285E6B-3E79-4D7C-BF96-D920F973B80D".to_string()).matches(&bolt)
+        );
+        assert!(CardIdentifier::Name("lightning bolt".to_string()).matches(&bolt));
+        assert!(!CardIdentifier::Name("Lightning Blot".to_string()).matches(&bolt));
+        assert!(CardIdentifier::SetNumber {
+            set: "M10".to_string(),
+            collector_number: "146".to_string(),
+        }
+        .matches(&bolt));
+
+        let delver = testing::double_faced_card();
+        let front = delver.card_faces.as_ref().unwrap()[0].name.clone();
+        assert!(CardIdentifier::Name(front).matches(&delver));
+
+        let sol_ring = testing::reversible_card();
+        assert!(
+            CardIdentifier::OracleId("3ac8bdcc-3712-573d-837a-894f9a029618".to_string())
+                .matches(&sol_ring)
+        );
+        assert!(!CardIdentifier::OracleId(String::new()).matches(&sol_ring));
+    }
+}
@@ -0,0 +1,185 @@
+//! Links to other API objects, typed by what they lead to, so that following one can't decode the wrong kind of
+//! object. [`Card`] hands them out for its API links, e.g. [`Card::rulings_link`].
+
+use super::card::Card;
+#[cfg(feature = "client")]
+use super::list::List;
+use super::ruling::Ruling;
+use super::set::Set;
+use super::uri::Uri;
+#[cfg(feature = "client")]
+use crate::client::{Client, Error};
+#[cfg(feature = "client")]
+use futures_util::stream::Stream;
+#[cfg(feature = "client")]
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A link to a single `T`.
+pub struct Link<T> {
+    uri: Uri,
+    target: PhantomData<fn() -> T>,
+}
+
+/// A link to a paginated list of `T`s.
+pub struct ListLink<T> {
+    uri: Uri,
+    target: PhantomData<fn() -> T>,
+}
+
+/// A link to a set on the API, such as [`Card::set_uri`].
+pub type SetLink = Link<Set>;
+
+/// A link to a search for every printing of a card, [`Card::prints_search_uri`].
+pub type PrintsSearchLink = ListLink<Card>;
+
+/// A link to a search for every card in a set, such as [`Card::set_search_uri`].
+pub type SetSearchLink = ListLink<Card>;
+
+/// A link to a card's rulings, [`Card::rulings_uri`].
+pub type RulingsLink = ListLink<Ruling>;
+
+impl<T> Link<T> {
+    /// A link to the `T` at `uri`. Nothing checks that `uri` really leads to one.
+    pub fn new(uri: Uri) -> Self {
+        Link {
+            uri,
+            target: PhantomData,
+        }
+    }
+
+    /// The link's URI.
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+}
+
+#[cfg(feature = "client")]
+impl<T: DeserializeOwned> Link<T> {
+    /// Fetches the object the link leads to.
+    pub async fn fetch(&self, client: &Client) -> Result<T, Error> {
+        client.fetch(&self.uri).await
+    }
+}
+
+impl<T> ListLink<T> {
+    /// A link to the list of `T`s at `uri`. Nothing checks that `uri` really leads to one.
+    pub fn new(uri: Uri) -> Self {
+        ListLink {
+            uri,
+            target: PhantomData,
+        }
+    }
+
+    /// The link's URI.
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+}
+
+#[cfg(feature = "client")]
+impl<T: DeserializeOwned + Send + 'static> ListLink<T> {
+    /// Fetches the first page of the list.
+    pub async fn fetch(&self, client: &Client) -> Result<List<T>, Error> {
+        client.fetch(&self.uri).await
+    }
+
+    /// Every item of the list, fetching each page only once the previous one has been consumed.
+    pub fn fetch_all(&self, client: &Client) -> impl Stream<Item = Result<T, Error>> {
+        client.fetch_all(&self.uri)
+    }
+}
+
+// Implemented by hand, as derives would needlessly require `T` to implement each trait too.
+macro_rules! link_impls {
+    ($link:ident) => {
+        impl<T> Clone for $link<T> {
+            fn clone(&self) -> Self {
+                $link::new(self.uri.clone())
+            }
+        }
+
+        impl<T> fmt::Debug for $link<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($link)).field(&self.uri).finish()
+            }
+        }
+
+        impl<T> PartialEq for $link<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.uri == other.uri
+            }
+        }
+
+        impl<T> Eq for $link<T> {}
+
+        impl<T> fmt::Display for $link<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.uri, f)
+            }
+        }
+    };
+}
+
+link_impls!(Link);
+link_impls!(ListLink);
+
+mod tests {
+    #[cfg(test)]
+    #[tokio::test]
+    #[cfg(feature = "client")]
+    async fn test_fetch() {
+        use crate::client::Client;
+        use crate::testing;
+        use futures_util::TryStreamExt;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../testdata/cards/lightning_bolt.json")),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80d/rulings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "object": "list",
+                "has_more": false,
+                "data": [],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder().base_url(&server.uri()).build();
+        let card = testing::card();
+        assert_eq!(
+            "Lightning Bolt",
+            card.link().fetch(&client).await.unwrap().name
+        );
+        let rulings: Vec<_> = card
+            .rulings_link()
+            .fetch_all(&client)
+            .try_collect()
+            .await
+            .unwrap();
+        assert!(rulings.is_empty());
+    }
+
+    #[test]
+    fn test_links() {
+        use crate::testing;
+
+        let card = testing::card();
+        assert_eq!(card.rulings_uri, *card.rulings_link().uri());
+        assert_eq!(
+            "https://api.scryfall.com/sets/m10",
+            card.set_link().unwrap().to_string()
+        );
+        assert_eq!(card.prints_search_link(), card.prints_search_link().clone());
+    }
+}
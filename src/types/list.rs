@@ -4,7 +4,7 @@ use super::uri::Uri;
 use serde::Deserialize;
 
 /// A type-generic List object
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct List<T> {
     /// An array of the requested objects, in a specific order.
     pub data: Vec<T>,
@@ -17,7 +17,7 @@ pub struct List<T> {
     pub next_page: Option<Uri>,
 
     /// If this is a list of Card objects, this field will contain the total number of cards found across all pages.
-    pub total_card: Option<u32>,
+    pub total_cards: Option<u32>,
 
     /// An array of human-readable warnings issued when generating this list, as strings. Warnings are non-fatal
     /// issues that the API discovered with your input. In general, they indicate that the List will not contain the
@@ -30,3 +30,23 @@ pub type CardList = List<Card>;
 
 /// A set-specific List object.
 pub type SetList = List<Set>;
+
+mod tests {
+    #[test]
+    fn test_parse_card_list() {
+        use super::CardList;
+        use crate::testing;
+
+        let list: CardList = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "total_cards": 1,
+            "has_more": false,
+            "data": [testing::card_json(serde_json::json!({}))],
+            "warnings": ["Invalid expression “is:bolt” was ignored."],
+        }))
+        .unwrap();
+        assert_eq!(Some(1), list.total_cards);
+        assert_eq!("Lightning Bolt", list.data[0].name);
+        assert_eq!(1, list.warnings.unwrap().len());
+    }
+}
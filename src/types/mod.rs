@@ -1,7 +1,19 @@
+#[macro_use]
+pub mod enums;
+
+pub mod bulk;
 pub mod card;
+pub mod catalog;
+pub mod date;
 pub mod error;
+pub mod identifier;
+pub mod link;
 pub mod list;
+pub mod parse;
 pub mod price;
+pub mod ruling;
+pub mod search;
 pub mod set;
+pub mod symbol;
 pub mod uri;
 pub mod uuid;
@@ -0,0 +1,145 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::{Path, Segment};
+use std::error;
+use std::fmt;
+use std::io::Read;
+
+/// A failure to parse Scryfall JSON, along with where in the document it happened.
+#[derive(Debug)]
+pub struct ParseError {
+    path: String,
+    inner: serde_json::Error,
+}
+
+impl ParseError {
+    /// Where the error happened, e.g. `card_faces[1].mana_cost`, or `.` for the document as a whole.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The underlying serde error.
+    pub fn inner(&self) -> &serde_json::Error {
+        &self.inner
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.path == "." {
+            write!(f, "{}", self.inner)
+        } else {
+            write!(f, "{}: {}", self.path, self.inner)
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for ParseError {
+    fn from(err: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        // Syntax errors have no useful path, and serde_path_to_error shows them as `?`.
+        let path = if err
+            .path()
+            .iter()
+            .all(|segment| matches!(segment, Segment::Unknown))
+        {
+            ".".to_string()
+        } else {
+            err.path().to_string()
+        };
+        ParseError {
+            path,
+            inner: err.into_inner(),
+        }
+    }
+}
+
+impl From<serde_json::Error> for ParseError {
+    fn from(err: serde_json::Error) -> Self {
+        ParseError {
+            path: ".".to_string(),
+            inner: err,
+        }
+    }
+}
+
+/// The result of a lenient parse: the value, and the fields that were dropped to get it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lenient<T> {
+    /// The parsed value.
+    pub value: T,
+
+    /// The paths of the fields that failed to parse and were treated as absent, in the order they were found.
+    pub dropped: Vec<String>,
+}
+
+/// Parses `bytes` as a `T`, with errors that say where they happened.
+pub(crate) fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ParseError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let value = serde_path_to_error::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Parses the JSON read from `reader` as a `T`, with errors that say where they happened.
+pub(crate) fn from_reader<T: DeserializeOwned, R: Read>(reader: R) -> Result<T, ParseError> {
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let value = serde_path_to_error::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Decodes `value` as a `T`, with errors that say where they happened.
+pub(crate) fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T, ParseError> {
+    Ok(serde_path_to_error::deserialize(value)?)
+}
+
+/// Parses `bytes` as a `T`, treating each field that fails to parse as absent and trying again. Only fields the type
+/// can do without are dropped: if parsing still fails at the same place, that error is returned.
+pub(crate) fn from_slice_lenient<T: DeserializeOwned>(
+    bytes: &[u8],
+) -> Result<Lenient<T>, ParseError> {
+    let mut document: Value = from_slice(bytes)?;
+    let mut dropped = Vec::new();
+    loop {
+        let err = match serde_path_to_error::deserialize(&document) {
+            Ok(value) => return Ok(Lenient { value, dropped }),
+            Err(err) => err,
+        };
+
+        let path = err.path().to_string();
+        if dropped.contains(&path) || !clear(&mut document, err.path()) {
+            return Err(err.into());
+        }
+        dropped.push(path);
+    }
+}
+
+/// Replaces the value at `path` in `document` with `null`, returning whether there was a value to replace.
+fn clear(document: &mut Value, path: &Path) -> bool {
+    let mut target = document;
+    let mut depth = 0;
+    for segment in path.iter() {
+        let next = match segment {
+            Segment::Seq { index } => target.get_mut(*index),
+            Segment::Map { key } => target.get_mut(key.as_str()),
+            _ => None,
+        };
+        target = match next {
+            Some(next) => next,
+            None => return false,
+        };
+        depth += 1;
+    }
+
+    if depth == 0 || target.is_null() {
+        return false;
+    }
+    *target = Value::Null;
+    true
+}
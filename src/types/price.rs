@@ -1,12 +1,12 @@
 use serde::de::{self, Visitor};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 struct PriceVisitor;
 
 impl<'de> Visitor<'de> for PriceVisitor {
     type Value = Price;
-    
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a valid price")
     }
@@ -19,7 +19,7 @@ impl<'de> Visitor<'de> for PriceVisitor {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Price(pub f64);
 
 impl<'de> Deserialize<'de> for Price {
@@ -28,6 +28,13 @@ impl<'de> Deserialize<'de> for Price {
     }
 }
 
+impl Serialize for Price {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Scryfall always gives prices to the cent.
+        serializer.serialize_str(&format!("{:.2}", self.0))
+    }
+}
+
 mod tests {
     #[test]
     fn test_parse_price() {
@@ -36,4 +43,12 @@ mod tests {
         let price: Price = serde_json::from_str("\"15.44\"").unwrap();
         assert_eq!(Price(15.44), price);
     }
+
+    #[test]
+    fn test_serialize_price() {
+        use super::Price;
+
+        assert_eq!("\"2.10\"", serde_json::to_string(&Price(2.1)).unwrap());
+        assert_eq!("\"12.00\"", serde_json::to_string(&Price(12.0)).unwrap());
+    }
 }
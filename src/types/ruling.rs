@@ -0,0 +1,51 @@
+use super::date::Date;
+use super::uuid::Uuid;
+use serde::Deserialize;
+
+/// Who published a ruling.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RulingSource {
+    /// Wizards of the Coast, e.g. in a set's release notes.
+    Wotc,
+
+    /// Scryfall's own rules team.
+    Scryfall,
+}
+
+/// Rulings object, an official note about how a card works. Rulings apply to every printing of a card.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct Ruling {
+    /// The Oracle ID of the card this ruling is about.
+    pub oracle_id: Uuid,
+
+    /// Who published this ruling.
+    pub source: RulingSource,
+
+    /// The date when the ruling or note was published.
+    pub published_at: Date,
+
+    /// The text of the ruling.
+    pub comment: String,
+}
+
+mod tests {
+    #[test]
+    fn test_parse_ruling() {
+        use super::{Ruling, RulingSource};
+
+        let ruling: Ruling = serde_json::from_str(
+            r#"{
+                "object": "ruling",
+                "oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070",
+                "source": "wotc",
+                "published_at": "2004-10-04",
+                "comment": "It can target a creature, a player, a planeswalker, or a battle."
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(RulingSource::Wotc, ruling.source);
+        assert_eq!("2004-10-04", ruling.published_at.to_string());
+    }
+}
@@ -0,0 +1,182 @@
+use serde::Deserialize;
+
+/// The orders Scryfall can sort card search results in.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// Sort cards by name, A → Z.
+    #[default]
+    Name,
+
+    /// Sort cards by their set and collector number: AAA/#1 → ZZZ/#999.
+    Set,
+
+    /// Sort cards by their release date: Newest → Oldest.
+    Released,
+
+    /// Sort cards by their rarity: Common → Mythic.
+    Rarity,
+
+    /// Sort cards by their color and color identity: WUBRG → multicolor → colorless.
+    Color,
+
+    /// Sort cards by their lowest known U.S. Dollar price: 0.01 → highest, null last.
+    Usd,
+
+    /// Sort cards by their lowest known TIX price: 0.01 → highest, null last.
+    Tix,
+
+    /// Sort cards by their lowest known Euro price: 0.01 → highest, null last.
+    Eur,
+
+    /// Sort cards by their mana value: 0 → highest.
+    Cmc,
+
+    /// Sort cards by their power: null → highest.
+    Power,
+
+    /// Sort cards by their toughness: null → highest.
+    Toughness,
+
+    /// Sort cards by their EDHREC ranking: lowest → highest.
+    Edhrec,
+
+    /// Sort cards by their Penny Dreadful ranking: lowest → highest.
+    Penny,
+
+    /// Sort cards by their front-side artist name: A → Z.
+    Artist,
+
+    /// Sort cards how podcasts review sets, usually color and mana value, but with some nuance.
+    Review,
+
+    /// Sort cards by when they were first previewed: Newest → Oldest.
+    Spoiled,
+}
+
+string_enum!(SortOrder {
+    Name => "name",
+    Set => "set",
+    Released => "released",
+    Rarity => "rarity",
+    Color => "color",
+    Usd => "usd",
+    Tix => "tix",
+    Eur => "eur",
+    Cmc => "cmc",
+    Power => "power",
+    Toughness => "toughness",
+    Edhrec => "edhrec",
+    Penny => "penny",
+    Artist => "artist",
+    Review => "review",
+    Spoiled => "spoiled",
+});
+
+/// How Scryfall collapses search results that are different printings of the same thing.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum UniqueMode {
+    /// Return one printing of each card, removing reprints.
+    #[default]
+    Cards,
+
+    /// Return one printing of each piece of art, so reprints with new art are included.
+    Art,
+
+    /// Return every printing of every card.
+    Prints,
+}
+
+string_enum!(UniqueMode {
+    Cards => "cards",
+    Art => "art",
+    Prints => "prints",
+});
+
+/// Which way to sort card search results.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    /// Let Scryfall pick the natural direction for the sort order.
+    #[default]
+    Auto,
+
+    /// Ascending.
+    Asc,
+
+    /// Descending.
+    Desc,
+}
+
+string_enum!(SortDirection {
+    Auto => "auto",
+    Asc => "asc",
+    Desc => "desc",
+});
+
+/// The options for a card search. Anything left as `None` or `false` uses Scryfall's default.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SearchParams {
+    /// How to collapse duplicate printings.
+    pub unique: Option<UniqueMode>,
+
+    /// How to sort the results.
+    pub order: Option<SortOrder>,
+
+    /// Which way to sort the results.
+    pub dir: Option<SortDirection>,
+
+    /// Whether to include extras like tokens, emblems and art cards.
+    pub include_extras: bool,
+
+    /// Whether to include printings in languages other than English.
+    pub include_multilingual: bool,
+
+    /// Whether to include rare variants, such as misprints and oversized cards.
+    pub include_variations: bool,
+
+    /// Whether to leave out funny cards, like those from Un-sets, by adding `-is:funny` to the query.
+    pub exclude_funny: bool,
+
+    /// Which page of results to return, starting from 1.
+    pub page: Option<u32>,
+}
+
+mod tests {
+    #[test]
+    fn test_sort_order() {
+        use super::SortOrder;
+
+        for order in SortOrder::ALL {
+            let text = order.to_string();
+            assert_eq!(*order, text.parse().unwrap());
+            assert_eq!(
+                *order,
+                serde_json::from_value(serde_json::Value::String(text)).unwrap()
+            );
+        }
+        assert_eq!(SortOrder::Name, SortOrder::default());
+    }
+
+    #[test]
+    fn test_unique_mode_and_direction() {
+        use super::{SortDirection, UniqueMode};
+
+        assert_eq!(Ok(UniqueMode::Prints), "prints".parse());
+        assert_eq!("desc", SortDirection::Desc.to_string());
+        assert_eq!(SortDirection::Auto, SortDirection::default());
+    }
+
+    #[cfg(feature = "cli")]
+    #[test]
+    fn test_sort_order_value_enum() {
+        use super::SortOrder;
+        use clap::ValueEnum;
+
+        assert_eq!(Ok(SortOrder::Edhrec), SortOrder::from_str("edhrec", false));
+    }
+}
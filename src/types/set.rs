@@ -1,10 +1,16 @@
+use super::date::Date;
 use super::uri::Uri;
 use super::uuid::Uuid;
+#[cfg(feature = "client")]
+use crate::client::{Client, Error};
+#[cfg(feature = "client")]
+use crate::types::card::Card;
+#[cfg(feature = "client")]
+use futures_util::TryStreamExt;
 use serde::Deserialize;
-use std::time::SystemTime;
 
 /// Set objects
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct Set {
     /// A unique ID for this set on Scryfall that will not change.
     pub id: Uuid,
@@ -25,7 +31,7 @@ pub struct Set {
     pub set_type: SetType,
 
     /// The date the set was released or the first card was printed in the set (in GMT-8 Pacific time).
-    pub released_at: Option<SystemTime>,
+    pub released_at: Option<Date>,
 
     /// The block code for this set, if any.
     pub block_code: Option<String>,
@@ -34,7 +40,7 @@ pub struct Set {
     pub block: Option<String>,
 
     /// The set code for the parent set, if any. promo and token sets often have a parent set.
-    pub parent_set_code: String,
+    pub parent_set_code: Option<String>,
 
     /// The number of cards in this set.
     pub card_count: u32,
@@ -60,18 +66,43 @@ pub struct Set {
     pub search_uri: Uri,
 }
 
-#[derive(Deserialize)]
+impl Set {
+    /// The tokens printed for this set, e.g. for printing token sheets. Most sets' tokens live in a child token set,
+    /// such as `tblb` for `blb`, found by its `parent_set_code`; for sets without one, the set itself is searched for
+    /// tokens.
+    #[cfg(feature = "client")]
+    pub async fn tokens(&self, client: &Client) -> Result<Vec<Card>, Error> {
+        let sets = client.sets().await?;
+        let child = sets.data.iter().find(|set| {
+            set.set_type == SetType::Token
+                && set.parent_set_code.as_deref() == Some(self.code.as_str())
+        });
+        let query = match child {
+            Some(child) => format!("set:{} unique:prints include:extras", child.code),
+            None => format!("set:{} t:token unique:prints include:extras", self.code),
+        };
+
+        client.search_all(&query).try_collect().await
+    }
+}
+
+/// A computer-readable classification for a set.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[serde(rename_all = "snake_case")]
 pub enum SetType {
     Core,
     Expansion,
     Masters,
+    Eternal,
+    Alchemy,
     Masterpiece,
+    Arsenal,
     FromTheVault,
     Spellbook,
     PremiumDeck,
     DuelDeck,
     DraftInnovation,
-    TrasureChest,
+    TreasureChest,
     Commander,
     Planechase,
     Archenemy,
@@ -82,4 +113,110 @@ pub enum SetType {
     Promo,
     Token,
     Memorabilia,
+    Minigame,
+}
+
+string_enum!(SetType {
+    Core => "core",
+    Expansion => "expansion",
+    Masters => "masters",
+    Eternal => "eternal",
+    Alchemy => "alchemy",
+    Masterpiece => "masterpiece",
+    Arsenal => "arsenal",
+    FromTheVault => "from_the_vault",
+    Spellbook => "spellbook",
+    PremiumDeck => "premium_deck",
+    DuelDeck => "duel_deck",
+    DraftInnovation => "draft_innovation",
+    TreasureChest => "treasure_chest",
+    Commander => "commander",
+    Planechase => "planechase",
+    Archenemy => "archenemy",
+    Vanguard => "vanguard",
+    Funny => "funny",
+    Starter => "starter",
+    Box => "box",
+    Promo => "promo",
+    Token => "token",
+    Memorabilia => "memorabilia",
+    Minigame => "minigame",
+});
+
+mod tests {
+    #[test]
+    fn test_parse_set() {
+        use super::SetType;
+        use crate::testing;
+
+        let set = testing::set();
+        assert_eq!("blb", set.code);
+        assert_eq!(SetType::Expansion, set.set_type);
+        assert_eq!(None, set.parent_set_code);
+        assert_eq!("2024-08-02", set.released_at.unwrap().to_string());
+    }
+
+    #[test]
+    fn test_set_type_strings() {
+        use super::SetType;
+
+        for set_type in SetType::ALL {
+            let text = set_type.to_string();
+            assert_eq!(*set_type, text.parse().unwrap());
+            assert_eq!(
+                *set_type,
+                serde_json::from_value(serde_json::Value::String(text)).unwrap()
+            );
+        }
+        assert_eq!("from_the_vault", SetType::FromTheVault.to_string());
+    }
+
+    #[cfg(all(test, feature = "client"))]
+    #[tokio::test]
+    async fn test_tokens() {
+        use crate::client::Client;
+        use crate::testing;
+        use serde_json::json;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/sets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "has_more": false,
+                "data": [
+                    testing::set_json(json!({})),
+                    testing::set_json(json!({
+                        "code": "tblb",
+                        "name": "Bloomburrow Tokens",
+                        "set_type": "token",
+                        "parent_set_code": "blb",
+                    })),
+                ],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/cards/search"))
+            .and(query_param("q", "set:tblb unique:prints include:extras"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "total_cards": 1,
+                "has_more": false,
+                "data": [testing::card_json(json!({
+                    "name": "Rabbit",
+                    "layout": "token",
+                    "set": "tblb",
+                }))],
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri());
+        let tokens = testing::set().tokens(&client).await.unwrap();
+        assert_eq!(1, tokens.len());
+        assert_eq!("Rabbit", tokens[0].name);
+    }
 }
@@ -0,0 +1,129 @@
+use super::card::Color;
+use super::uri::Uri;
+use serde::Deserialize;
+
+/// Card Symbol objects: an illustrated symbol that may appear in card costs or text, like `{T}` or `{W/U}`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct CardSymbol {
+    /// The plaintext symbol, often surrounded with curly braces {}. Note that not all symbols are ASCII text (for
+    /// example, {∞}).
+    pub symbol: String,
+
+    /// An alternate version of this symbol, if it is possible to write it without curly braces.
+    pub loose_variant: Option<String>,
+
+    /// An English snippet that describes this symbol. Appropriate for use in alt text or other accessible
+    /// communication formats.
+    pub english: String,
+
+    /// True if it is possible to write this symbol “backwards”. For example, the official symbol {U/P} is sometimes
+    /// written as {P/U} or {P\U} in informal settings.
+    pub transposable: bool,
+
+    /// True if this is a mana symbol.
+    pub represents_mana: bool,
+
+    /// A decimal number representing this symbol’s mana value (also knowns as the converted mana cost). Note that
+    /// mana symbols from funny sets can have fractional mana values.
+    pub mana_value: Option<f64>,
+
+    /// The older name for `mana_value`, which Scryfall still sends.
+    pub cmc: Option<f64>,
+
+    /// True if this symbol appears in a mana cost on any Magic card.
+    pub appears_in_mana_costs: bool,
+
+    /// True if this symbol is only used on funny cards or Un-cards.
+    pub funny: bool,
+
+    /// An array of colors representing this symbol.
+    pub colors: Vec<Color>,
+
+    /// True if the symbol is a hybrid mana symbol.
+    #[serde(default)]
+    pub hybrid: bool,
+
+    /// True if the symbol is a Phyrexian mana symbol.
+    #[serde(default)]
+    pub phyrexian: bool,
+
+    /// An array of plaintext versions of this symbol that Gatherer uses on old cards to describe original printed
+    /// text. For example: {W} has ["oW", "ooW"] as alternates.
+    pub gatherer_alternates: Option<Vec<String>>,
+
+    /// A URI to an SVG image of this symbol on Scryfall’s CDNs.
+    pub svg_uri: Option<Uri>,
+}
+
+/// Mana Cost objects: Scryfall's normalized reading of a mana cost string, from `/symbology/parse-mana`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ManaCostInfo {
+    /// The normalized cost, with correctly-ordered and wrapped mana symbols.
+    pub cost: String,
+
+    /// The mana value. If you submit Un-set mana symbols, this decimal could include fractional parts.
+    pub cmc: f64,
+
+    /// The colors of the given cost.
+    pub colors: Vec<Color>,
+
+    /// True if the cost is colorless.
+    pub colorless: bool,
+
+    /// True if the cost is monocolored.
+    pub monocolored: bool,
+
+    /// True if the cost is multicolored.
+    pub multicolored: bool,
+}
+
+mod tests {
+    #[test]
+    fn test_parse_card_symbol() {
+        use super::CardSymbol;
+        use crate::types::card::Color;
+
+        let symbol: CardSymbol = serde_json::from_value(serde_json::json!({
+            "object": "card_symbol",
+            "symbol": "{W/U}",
+            "svg_uri": "https://svgs.scryfall.io/card-symbols/WU.svg",
+            "loose_variant": null,
+            "english": "one white or blue mana",
+            "transposable": false,
+            "represents_mana": true,
+            "appears_in_mana_costs": true,
+            "mana_value": 1.0,
+            "hybrid": true,
+            "phyrexian": false,
+            "cmc": 1.0,
+            "funny": false,
+            "colors": ["W", "U"],
+            "gatherer_alternates": ["(w/u)"],
+        }))
+        .unwrap();
+        assert_eq!("{W/U}", symbol.symbol);
+        assert!(symbol.hybrid);
+        assert_eq!(Some(1.0), symbol.mana_value);
+        assert_eq!(vec![Color::White, Color::Blue], symbol.colors);
+    }
+
+    #[test]
+    fn test_parse_mana_cost_info() {
+        use super::ManaCostInfo;
+        use crate::types::card::Color;
+
+        let info: ManaCostInfo = serde_json::from_value(serde_json::json!({
+            "object": "mana_cost",
+            "cost": "{X}{2}{R}{R}",
+            "colors": ["R"],
+            "cmc": 4.0,
+            "colorless": false,
+            "monocolored": true,
+            "multicolored": false,
+        }))
+        .unwrap();
+        assert_eq!("{X}{2}{R}{R}", info.cost);
+        assert_eq!(vec![Color::Red], info.colors);
+        assert!(info.monocolored);
+    }
+}